@@ -0,0 +1,18 @@
+use bevy_reflect::Reflect;
+
+// Reason: Trait implementations cannot be detected on generic types
+#[derive(Reflect, Debug)]
+#[reflect(auto)]
+//~^ ERROR: `auto` cannot detect trait implementations on generic types
+struct Foo<T> {
+    value: T,
+}
+
+// Reason: Traits are detected on concrete types
+#[derive(Reflect, Debug)]
+#[reflect(auto)]
+struct Bar {
+    value: f32,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use bevy_reflect::attributes::MinMax;
+use bevy_reflect::Reflect;
+
+// Reason: `min` must not be greater than `max`
+#[derive(Reflect)]
+struct InvalidStruct {
+    #[reflect(@bevy_reflect::attributes::MinMax { min: 1.0, max: -1.0 })]
+    //~^ ERROR: invalid `MinMax` range
+    value: f32,
+}
+
+// Reason: `min` must not be greater than `max`
+#[derive(Reflect)]
+struct InvalidConstructor(#[reflect(@bevy_reflect::attributes::MinMax::new(10.0, 5.0))] f32);
+//~^ ERROR: invalid `MinMax` range
+
+// Reason: `min` must not be greater than `max`
+#[derive(Reflect)]
+struct InvalidImported {
+    #[reflect(@MinMax { min: 1.0, max: -1.0 })]
+    //~^ ERROR: invalid `MinMax` range
+    value: f32,
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+//@check-pass
+use bevy_reflect::attributes::MinMax;
+use bevy_reflect::Reflect;
+
+const MAX: f64 = 100.0;
+
+#[derive(Reflect)]
+struct Valid {
+    #[reflect(@MinMax { min: -1.0, max: 1.0 })]
+    a: f32,
+    #[reflect(@MinMax::new(0.0, 0.0))]
+    b: f32,
+    // Non-literal bounds cannot be checked at compile time
+    #[reflect(@MinMax::new(200.0, MAX))]
+    c: f32,
+}
+
+mod unrelated {
+    use bevy_reflect::Reflect;
+
+    #[derive(Reflect)]
+    pub struct MinMax {
+        pub min: f32,
+        pub max: f32,
+    }
+}
+
+// Types which are not `bevy_reflect::attributes::MinMax` are not validated, even if named `MinMax`
+#[derive(Reflect)]
+struct Unrelated {
+    #[reflect(@unrelated::MinMax { min: 1.0, max: -1.0 })]
+    a: f32,
+}
+
+fn main() {}
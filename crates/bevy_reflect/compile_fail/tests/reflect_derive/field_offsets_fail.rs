@@ -0,0 +1,19 @@
+use bevy_reflect::Reflect;
+
+// Reason: Only structs are supported
+#[derive(Reflect)]
+#[reflect(field_offsets)]
+//~^ ERROR: `field_offsets` can only be used on structs
+enum Enum {
+    A { value: f32 },
+}
+
+// Reason: Only structs are supported
+#[derive(Reflect)]
+#[reflect_value(field_offsets)]
+//~^ ERROR: `field_offsets` can only be used on structs
+struct Value {
+    value: f32,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use bevy_reflect::Reflect;
+
+// Reason: Only structs with named fields are supported
+#[derive(Reflect)]
+#[reflect(generate_paths)]
+//~^ ERROR: `generate_paths` can only be used on structs with named fields
+struct TupleStruct(f32);
+
+// Reason: Only structs with named fields are supported
+#[derive(Reflect)]
+#[reflect(generate_paths)]
+//~^ ERROR: `generate_paths` can only be used on structs with named fields
+enum Enum {
+    A { value: f32 },
+}
+
+// Reason: Generic types are not supported
+#[derive(Reflect)]
+#[reflect(generate_paths)]
+//~^ ERROR: `generate_paths` cannot be used on generic types
+struct Generic<T> {
+    value: T,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use bevy_reflect::Reflect;
+
+// Reason: Reflected fields would only ever be able to borrow `'static` data
+#[derive(Reflect)]
+struct Foo<'a> {
+    value: &'a str,
+    //~^ ERROR: reflected fields cannot borrow data for the type's lifetimes
+}
+
+// Reason: Enum variant fields are checked as well
+#[derive(Reflect)]
+enum Bar<'a> {
+    A(Vec<&'a u32>),
+    //~^ ERROR: reflected fields cannot borrow data for the type's lifetimes
+}
+
+// Reason: Explicit `'static` borrows are allowed
+#[derive(Reflect)]
+struct Baz<'a> {
+    value: &'static str,
+    #[reflect(ignore)]
+    _borrowed: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+use bevy_reflect::Reflect;
+
+// Reason: `String` is not plain-old-data
+#[derive(Reflect)]
+#[reflect(pod)]
+struct Named {
+    id: u32,
+    name: String,
+    //~^ ERROR: `pod` requires every field to be a primitive, or an array or tuple of primitives
+}
+
+// Reason: Ignored fields would be overwritten
+#[derive(Reflect)]
+#[reflect(pod)]
+struct Ignored {
+    id: u32,
+    #[reflect(ignore)]
+    //~^ ERROR: `pod` cannot be used on structs with ignored fields
+    cache: u32,
+}
+
+// Reason: Only structs are supported
+#[derive(Reflect)]
+#[reflect(pod)]
+//~^ ERROR: `pod` can only be used on structs
+enum Enum {
+    A(u32),
+}
+
+// Reason: Primitives, arrays, and tuples are allowed
+#[derive(Reflect)]
+#[reflect(pod)]
+struct Allowed {
+    position: [f32; 3],
+    range: (u8, u8),
+    flags: [[bool; 2]; 2],
+}
+
+fn main() {}
@@ -0,0 +1,67 @@
+use bevy_reflect::Reflect;
+
+// Reason: `Hash` must be registered for fields to be skipped from the hash
+#[derive(Reflect, Hash)]
+struct NoHash {
+    #[reflect(skip_hash)]
+    //~^ ERROR: requires `Hash` to be registered
+    a: u32,
+}
+
+// Reason: custom `Hash` functions are used as-is
+#[derive(Reflect)]
+#[reflect(Hash(custom_hash))]
+struct CustomHash {
+    #[reflect(skip_hash)]
+    //~^ ERROR: cannot be used when `Hash` is registered with a custom function
+    a: u32,
+}
+
+// Reason: custom `PartialEq` functions are used as-is
+#[derive(Reflect)]
+#[reflect(PartialEq(custom_partial_eq))]
+struct CustomPartialEq {
+    #[reflect(skip_partial_eq)]
+    //~^ ERROR: cannot be used when `PartialEq` is registered with a custom function
+    a: u32,
+}
+
+// Reason: enums always delegate to their registered implementations
+#[derive(Reflect, Hash, PartialEq)]
+#[reflect(Hash, PartialEq)]
+enum Enum {
+    A(#[reflect(skip_hash)] u32),
+    //~^ ERROR: cannot be used on enum variant fields
+    B {
+        #[reflect(skip_partial_eq)]
+        //~^ ERROR: cannot be used on enum variant fields
+        b: u32,
+    },
+}
+
+// Reason: ignored fields are not reflected and cannot be skipped
+#[derive(Reflect, Hash)]
+#[reflect(Hash)]
+struct IgnoredHash {
+    #[reflect(ignore, skip_hash)]
+    //~^ ERROR: `#[reflect(skip_hash)]` cannot be used on ignored fields
+    a: u32,
+}
+
+#[derive(Reflect, PartialEq)]
+#[reflect(PartialEq)]
+struct IgnoredPartialEq {
+    #[reflect(ignore, skip_partial_eq)]
+    //~^ ERROR: `#[reflect(skip_partial_eq)]` cannot be used on ignored fields
+    a: u32,
+}
+
+fn custom_hash(_: &CustomHash) -> Option<u64> {
+    None
+}
+
+fn custom_partial_eq(_: &CustomPartialEq, _: &dyn Reflect) -> Option<bool> {
+    None
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+use bevy_reflect::Reflect;
+
+// Reason: stable indices must be unique
+#[derive(Reflect)]
+struct Duplicate {
+    #[reflect(index = 0)]
+    a: u32,
+    #[reflect(index = 0)]
+    //~^ ERROR: duplicate stable index `0`
+    b: u32,
+}
+
+// Reason: stable indices are only supported on named struct fields
+#[derive(Reflect)]
+struct Tuple(#[reflect(index = 0)] u32);
+//~^ ERROR: can only be used on fields of named structs
+
+// Reason: ignored fields are not reflected and cannot be indexed
+#[derive(Reflect)]
+struct Ignored {
+    #[reflect(ignore, index = 1)]
+    //~^ ERROR: cannot be used on ignored fields
+    a: u32,
+}
+
+fn main() {}
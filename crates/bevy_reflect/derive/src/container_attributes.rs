@@ -11,7 +11,7 @@ use crate::utility;
 use crate::utility::terminated_parser;
 use bevy_macro_utils::fq_std::{FQAny, FQOption};
 use proc_macro2::{Ident, Span};
-use quote::quote_spanned;
+use quote::{quote, quote_spanned, ToTokens};
 use syn::ext::IdentExt;
 use syn::parse::ParseStream;
 use syn::spanned::Spanned;
@@ -22,14 +22,22 @@ mod kw {
     syn::custom_keyword!(type_path);
     syn::custom_keyword!(Debug);
     syn::custom_keyword!(PartialEq);
+    syn::custom_keyword!(PartialOrd);
     syn::custom_keyword!(Hash);
     syn::custom_keyword!(no_field_bounds);
+    syn::custom_keyword!(transparent);
+    syn::custom_keyword!(opaque);
+    syn::custom_keyword!(generate_paths);
+    syn::custom_keyword!(field_offsets);
+    syn::custom_keyword!(auto);
+    syn::custom_keyword!(pod);
 }
 
 // The "special" trait idents that are used internally for reflection.
 // Received via attributes like `#[reflect(PartialEq, Hash, ...)]`
 const DEBUG_ATTR: &str = "Debug";
 const PARTIAL_EQ_ATTR: &str = "PartialEq";
+const PARTIAL_ORD_ATTR: &str = "PartialOrd";
 const HASH_ATTR: &str = "Hash";
 
 // The traits listed below are not considered "special" (i.e. they use the `ReflectMyTrait` syntax)
@@ -129,6 +137,7 @@ impl TypePathAttrs {
 /// * `Debug`
 /// * `Hash`
 /// * `PartialEq`
+/// * `PartialOrd`
 ///
 /// When registering a trait, there are a few things to keep in mind:
 /// * Traits must have a valid `Reflect{}` struct in scope. For example, `Default`
@@ -184,10 +193,17 @@ pub(crate) struct ContainerAttributes {
     debug: TraitImpl,
     hash: TraitImpl,
     partial_eq: TraitImpl,
+    partial_ord: TraitImpl,
     from_reflect_attrs: FromReflectAttrs,
     type_path_attrs: TypePathAttrs,
     custom_where: Option<WhereClause>,
     no_field_bounds: bool,
+    transparent: Option<Span>,
+    opaque: Option<Span>,
+    generate_paths: Option<Span>,
+    field_offsets: Option<Span>,
+    auto: Option<Span>,
+    pod: Option<Span>,
     custom_attributes: CustomAttributes,
     idents: Vec<Ident>,
 }
@@ -239,10 +255,24 @@ impl ContainerAttributes {
             self.parse_type_path(input, trait_)
         } else if lookahead.peek(kw::no_field_bounds) {
             self.parse_no_field_bounds(input)
+        } else if lookahead.peek(kw::transparent) {
+            self.parse_transparent(input)
+        } else if lookahead.peek(kw::opaque) {
+            self.parse_opaque(input)
+        } else if lookahead.peek(kw::generate_paths) {
+            self.parse_generate_paths(input)
+        } else if lookahead.peek(kw::field_offsets) {
+            self.parse_field_offsets(input)
+        } else if lookahead.peek(kw::auto) {
+            self.parse_auto(input)
+        } else if lookahead.peek(kw::pod) {
+            self.parse_pod(input)
         } else if lookahead.peek(kw::Debug) {
             self.parse_debug(input)
         } else if lookahead.peek(kw::PartialEq) {
             self.parse_partial_eq(input)
+        } else if lookahead.peek(kw::PartialOrd) {
+            self.parse_partial_ord(input)
         } else if lookahead.peek(kw::Hash) {
             self.parse_hash(input)
         } else if lookahead.peek(Ident::peek_any) {
@@ -261,7 +291,7 @@ impl ContainerAttributes {
 
         if input.peek(token::Paren) {
             return Err(syn::Error::new(ident.span(), format!(
-                "only [{DEBUG_ATTR:?}, {PARTIAL_EQ_ATTR:?}, {HASH_ATTR:?}] may specify custom functions",
+                "only [{DEBUG_ATTR:?}, {PARTIAL_EQ_ATTR:?}, {PARTIAL_ORD_ATTR:?}, {HASH_ATTR:?}] may specify custom functions",
             )));
         }
 
@@ -317,6 +347,27 @@ impl ContainerAttributes {
         Ok(())
     }
 
+    /// Parse special `PartialOrd` registration.
+    ///
+    /// Examples:
+    /// - `#[reflect(PartialOrd)]`
+    /// - `#[reflect(PartialOrd(custom_partial_cmp_fn))]`
+    fn parse_partial_ord(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::PartialOrd>()?;
+
+        if input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            let path = content.parse::<Path>()?;
+            self.partial_ord
+                .merge(TraitImpl::Custom(path, ident.span))?;
+        } else {
+            self.partial_ord = TraitImpl::Implemented(ident.span);
+        }
+
+        Ok(())
+    }
+
     /// Parse special `Hash` registration.
     ///
     /// Examples:
@@ -347,6 +398,66 @@ impl ContainerAttributes {
         Ok(())
     }
 
+    /// Parse `transparent` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect_value(transparent)]`
+    fn parse_transparent(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::transparent>()?;
+        self.transparent = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `opaque` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect_value(opaque)]`
+    fn parse_opaque(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::opaque>()?;
+        self.opaque = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `generate_paths` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(generate_paths)]`
+    fn parse_generate_paths(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::generate_paths>()?;
+        self.generate_paths = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `field_offsets` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(field_offsets)]`
+    fn parse_field_offsets(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::field_offsets>()?;
+        self.field_offsets = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `auto` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(auto)]`
+    fn parse_auto(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::auto>()?;
+        self.auto = Some(ident.span);
+        Ok(())
+    }
+
+    /// Parse `pod` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(pod)]`
+    fn parse_pod(&mut self, input: ParseStream) -> syn::Result<()> {
+        let ident = input.parse::<kw::pod>()?;
+        self.pod = Some(ident.span);
+        Ok(())
+    }
+
     /// Parse `where` attribute.
     ///
     /// Examples:
@@ -494,6 +605,50 @@ impl ContainerAttributes {
         }
     }
 
+    /// Returns the implementation of `Reflect::reflect_partial_cmp` as a `TokenStream`.
+    ///
+    /// If `PartialOrd` was not registered, returns `None`.
+    pub fn get_partial_ord_impl(
+        &self,
+        bevy_reflect_path: &Path,
+    ) -> Option<proc_macro2::TokenStream> {
+        match &self.partial_ord {
+            &TraitImpl::Implemented(span) => Some(quote_spanned! {span=>
+                fn reflect_partial_cmp(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<::core::cmp::Ordering> {
+                    let value = <dyn #bevy_reflect_path::Reflect>::as_any(value);
+                    <dyn #FQAny>::downcast_ref::<Self>(value)
+                        .and_then(|value| ::core::cmp::PartialOrd::partial_cmp(self, value))
+                }
+            }),
+            &TraitImpl::Custom(ref impl_fn, span) => Some(quote_spanned! {span=>
+                fn reflect_partial_cmp(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<::core::cmp::Ordering> {
+                    #impl_fn(self, value)
+                }
+            }),
+            TraitImpl::NotImplemented => None,
+        }
+    }
+
+    /// Returns true if `Hash` was registered as implemented (without a custom function).
+    pub fn has_hash(&self) -> bool {
+        matches!(self.hash, TraitImpl::Implemented(_))
+    }
+
+    /// Returns true if `Hash` was registered with a custom function.
+    pub fn has_custom_hash(&self) -> bool {
+        matches!(self.hash, TraitImpl::Custom(..))
+    }
+
+    /// Returns true if `PartialEq` was registered as implemented (without a custom function).
+    pub fn has_partial_eq(&self) -> bool {
+        matches!(self.partial_eq, TraitImpl::Implemented(_))
+    }
+
+    /// Returns true if `PartialEq` was registered with a custom function.
+    pub fn has_custom_partial_eq(&self) -> bool {
+        matches!(self.partial_eq, TraitImpl::Custom(..))
+    }
+
     /// Returns the implementation of `Reflect::debug` as a `TokenStream`.
     ///
     /// If `Debug` was not registered, returns `None`.
@@ -526,6 +681,161 @@ impl ContainerAttributes {
     pub fn no_field_bounds(&self) -> bool {
         self.no_field_bounds
     }
+
+    /// Returns the span of the `transparent` attribute, if it was found on this type.
+    pub fn transparent(&self) -> Option<Span> {
+        self.transparent
+    }
+
+    /// Returns the span of the `opaque` attribute, if it was found on this type.
+    pub fn opaque(&self) -> Option<Span> {
+        self.opaque
+    }
+
+    /// Returns the span of the `generate_paths` attribute, if it was found on this type.
+    pub fn generate_paths(&self) -> Option<Span> {
+        self.generate_paths
+    }
+
+    /// Returns the span of the `field_offsets` attribute, if it was found on this type.
+    pub fn field_offsets(&self) -> Option<Span> {
+        self.field_offsets
+    }
+
+    /// Returns the span of the `auto` attribute, if it was found on this type.
+    pub fn auto(&self) -> Option<Span> {
+        self.auto
+    }
+
+    /// Returns the span of the `pod` attribute, if it was found on this type.
+    pub fn pod(&self) -> Option<Span> {
+        self.pod
+    }
+
+    /// Returns the implementation of the `Reflect` method for the given trait as a `TokenStream`,
+    /// accounting for `#[reflect(auto)]`.
+    ///
+    /// If `auto` was used and the trait was not registered explicitly,
+    /// the returned method uses the type's implementation of the trait when it has one,
+    /// and falls back to `reflect_fn` (or the default `Reflect` method if `None`) otherwise.
+    /// Otherwise, `reflect_fn` is returned as is.
+    pub fn get_auto_impl(
+        &self,
+        trait_: AutoTrait,
+        reflect_fn: Option<proc_macro2::TokenStream>,
+        type_path: &impl ToTokens,
+        bevy_reflect_path: &Path,
+    ) -> Option<proc_macro2::TokenStream> {
+        let registered = match trait_ {
+            AutoTrait::Debug => &self.debug,
+            AutoTrait::PartialEq => &self.partial_eq,
+            AutoTrait::PartialOrd => &self.partial_ord,
+            AutoTrait::Hash => &self.hash,
+        };
+
+        let Some(span) = self.auto else {
+            return reflect_fn;
+        };
+
+        // Opaque values cannot be hashed or compared
+        let is_opaque_unsupported =
+            self.opaque.is_some() && matches!(trait_, AutoTrait::PartialEq | AutoTrait::Hash);
+        if !matches!(registered, TraitImpl::NotImplemented) || is_opaque_unsupported {
+            return reflect_fn;
+        }
+
+        let auto = quote!(#bevy_reflect_path::__macro_exports::auto);
+        let (signature, call, auto_body, default_body) = match trait_ {
+            AutoTrait::Debug => (
+                quote! {
+                    fn debug(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result
+                },
+                quote!(debug(self, f)),
+                quote! {
+                    if let #FQOption::Some(debug_fn) = (&&#auto::AutoImpl::<Self>::new()).debug_fn() {
+                        return debug_fn(self, f);
+                    }
+                },
+                quote!(#auto::reflect_debug(self, f)),
+            ),
+            AutoTrait::PartialEq => (
+                quote! {
+                    fn reflect_partial_eq(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<bool>
+                },
+                quote!(reflect_partial_eq(self, value)),
+                quote! {
+                    if let #FQOption::Some(partial_eq_fn) = (&&#auto::AutoImpl::<Self>::new()).partial_eq_fn() {
+                        let value = <dyn #bevy_reflect_path::Reflect>::as_any(value);
+                        return #FQOption::Some(
+                            <dyn #FQAny>::downcast_ref::<Self>(value)
+                                .is_some_and(|value| partial_eq_fn(self, value))
+                        );
+                    }
+                },
+                quote!(#FQOption::None),
+            ),
+            AutoTrait::PartialOrd => (
+                quote! {
+                    fn reflect_partial_cmp(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<::core::cmp::Ordering>
+                },
+                quote!(reflect_partial_cmp(self, value)),
+                quote! {
+                    if let #FQOption::Some(partial_cmp_fn) = (&&#auto::AutoImpl::<Self>::new()).partial_cmp_fn() {
+                        let value = <dyn #bevy_reflect_path::Reflect>::as_any(value);
+                        return <dyn #FQAny>::downcast_ref::<Self>(value)
+                            .and_then(|value| partial_cmp_fn(self, value));
+                    }
+                },
+                quote!(#FQOption::None),
+            ),
+            AutoTrait::Hash => (
+                quote! {
+                    fn reflect_hash(&self) -> #FQOption<u64>
+                },
+                quote!(reflect_hash(self)),
+                quote! {
+                    if let #FQOption::Some(hash_fn) = (&&#auto::AutoImpl::<Self>::new()).hash_fn() {
+                        return #FQOption::Some(hash_fn(self));
+                    }
+                },
+                quote!(#FQOption::None),
+            ),
+        };
+
+        // The fallback is defined on a local trait so that it can keep its original signature
+        let fallback_body = match reflect_fn {
+            Some(reflect_fn) => quote! {
+                trait __ReflectAutoFallback {
+                    #signature;
+                }
+
+                impl __ReflectAutoFallback for #type_path {
+                    #reflect_fn
+                }
+
+                <Self as __ReflectAutoFallback>::#call
+            },
+            None => default_body,
+        };
+
+        Some(quote_spanned! {span=>
+            #signature {
+                #[allow(unused_imports)]
+                use #auto::*;
+                #auto_body
+                #fallback_body
+            }
+        })
+    }
+}
+
+/// A standard trait whose implementation may be detected with `#[reflect(auto)]`.
+#[derive(Clone, Copy)]
+pub(crate) enum AutoTrait {
+    Debug,
+    PartialEq,
+    PartialOrd,
+    Hash,
 }
 
 /// Adds an identifier to a vector of identifiers if it is not already present.
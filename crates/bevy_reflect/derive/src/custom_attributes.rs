@@ -1,7 +1,8 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::parse::ParseStream;
-use syn::{Expr, Path, Token};
+use syn::spanned::Spanned;
+use syn::{Expr, ExprLit, ExprParen, ExprUnary, Lit, Member, Path, Token, UnOp};
 
 #[derive(Default, Clone)]
 pub(crate) struct CustomAttributes {
@@ -17,10 +18,17 @@ impl CustomAttributes {
             }
         });
 
-        quote! {
+        let assertions = self
+            .attributes
+            .iter()
+            .filter_map(|value| min_max_assertion(value, bevy_reflect_path));
+
+        quote! {{
+            #(#assertions)*
+
             #bevy_reflect_path::attributes::CustomAttributes::default()
                 #(#attributes)*
-        }
+        }}
     }
 
     /// Inserts a custom attribute into the list.
@@ -37,6 +45,86 @@ impl CustomAttributes {
     /// - `#[reflect(@0..256u8)]`
     pub fn parse_custom_attribute(&mut self, input: ParseStream) -> syn::Result<()> {
         input.parse::<Token![@]>()?;
-        self.push(input.parse()?)
+        let value = input.parse()?;
+        self.push(value)
+    }
+}
+
+/// Generates a compile-time check for a `MinMax` attribute whose bounds are given as literals.
+///
+/// Both the struct form (`MinMax { min: 0.0, max: 1.0 }`) and the constructor form
+/// (`MinMax::new(0.0, 1.0)`) are checked, through any path ending in `MinMax`.
+/// Bounds which are not numeric literals cannot be checked at compile time and are ignored.
+///
+/// Imports can't be resolved from within the macro, so the generated check defers to the
+/// type system to determine whether the path actually refers to `bevy_reflect::attributes::MinMax`.
+/// This avoids rejecting unrelated types which happen to be named `MinMax`.
+fn min_max_assertion(value: &Expr, bevy_reflect_path: &Path) -> Option<TokenStream> {
+    let is_min_max = |path: &Path| {
+        path.segments
+            .last()
+            .is_some_and(|segment| segment.ident == "MinMax")
+    };
+
+    let (ty, min, max) = match value {
+        Expr::Struct(value) if is_min_max(&value.path) => {
+            let field = |name: &str| {
+                value.fields.iter().find_map(|field| match &field.member {
+                    Member::Named(ident) if ident == name => Some(&field.expr),
+                    _ => None,
+                })
+            };
+            (value.path.clone(), field("min")?, field("max")?)
+        }
+        Expr::Call(call) if call.args.len() == 2 => {
+            let Expr::Path(func) = &*call.func else {
+                return None;
+            };
+            let mut path = func.path.clone();
+            let is_new = path
+                .segments
+                .pop()
+                .is_some_and(|segment| segment.value().ident == "new");
+            if !is_new || !is_min_max(&path) {
+                return None;
+            }
+            path.segments.pop_punct();
+            (path, call.args.first()?, call.args.last()?)
+        }
+        _ => return None,
+    };
+
+    let (min, max) = (literal_value(min)?, literal_value(max)?);
+    if min <= max {
+        return None;
+    }
+
+    let message = format!("invalid `MinMax` range: `min` ({min}) is greater than `max` ({max})");
+    Some(quote_spanned! {value.span()=>
+        const _: () = {
+            #[allow(unused_imports)]
+            use #bevy_reflect_path::__macro_exports::min_max::NotMinMax as _;
+            ::core::assert!(!<#bevy_reflect_path::__macro_exports::min_max::IsMinMax<#ty>>::IS_MIN_MAX, #message);
+        };
+    })
+}
+
+/// Returns the value of a (possibly negated) numeric literal.
+fn literal_value(expr: &Expr) -> Option<f64> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(lit),
+            ..
+        }) => lit.base10_parse().ok(),
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => literal_value(expr).map(|value| -value),
+        Expr::Paren(ExprParen { expr, .. }) => literal_value(expr),
+        _ => None,
     }
 }
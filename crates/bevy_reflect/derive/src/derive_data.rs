@@ -1,14 +1,20 @@
 use core::fmt;
 use proc_macro2::Span;
+use std::collections::HashSet;
 
-use crate::container_attributes::{ContainerAttributes, FromReflectAttrs, TypePathAttrs};
-use crate::field_attributes::FieldAttributes;
+use crate::container_attributes::{
+    AutoTrait, ContainerAttributes, FromReflectAttrs, TypePathAttrs,
+};
+use crate::field_attributes::{
+    FieldAttributes, REDACT_ATTR, SKIP_HASH_ATTR, SKIP_PARTIAL_EQ_ATTR, STABLE_INDEX_ATTR,
+};
 use crate::type_path::parse_path_no_leading_colon;
-use crate::utility::{StringExpr, WhereClauseOptions};
+use crate::utility::{ident_name, StringExpr, WhereClauseOptions};
+use bevy_macro_utils::fq_std::{FQAny, FQOption, FQResult};
 use quote::{quote, ToTokens};
 use syn::token::Comma;
 
-use crate::serialization::SerializationDataDef;
+use crate::serialization::{RedactionDataDef, SerializationDataDef};
 use crate::{
     utility, REFLECT_ATTRIBUTE_NAME, REFLECT_VALUE_ATTRIBUTE_NAME, TYPE_NAME_ATTRIBUTE_NAME,
     TYPE_PATH_ATTRIBUTE_NAME,
@@ -16,8 +22,8 @@ use crate::{
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
-    parse_str, Data, DeriveInput, Field, Fields, GenericParam, Generics, Ident, LitStr, Meta, Path,
-    PathSegment, Type, TypeParam, Variant,
+    parse_str, Data, DataStruct, DeriveInput, Field, Fields, GenericParam, Generics, Ident, LitStr,
+    Meta, Path, PathSegment, Type, TypeParam, Variant,
 };
 
 pub(crate) enum ReflectDerive<'a> {
@@ -48,6 +54,8 @@ pub(crate) struct ReflectMeta<'a> {
     type_path: ReflectTypePath<'a>,
     /// A cached instance of the path to the `bevy_reflect` crate.
     bevy_reflect_path: Path,
+    /// The type of the wrapped field for `#[reflect_value(transparent)]` types.
+    transparent_field: Option<&'a Type>,
     /// The documentation for this type, if any
     #[cfg(feature = "documentation")]
     docs: crate::documentation::Documentation,
@@ -69,6 +77,7 @@ pub(crate) struct ReflectMeta<'a> {
 pub(crate) struct ReflectStruct<'a> {
     meta: ReflectMeta<'a>,
     serialization_data: Option<SerializationDataDef>,
+    redaction_data: Option<RedactionDataDef>,
     fields: Vec<StructField<'a>>,
 }
 
@@ -311,6 +320,153 @@ impl<'a> ReflectDerive<'a> {
         // Use normal reflection if unspecified
         let reflect_mode = reflect_mode.unwrap_or(ReflectMode::Normal);
 
+        if let Some(span) = meta.attrs().opaque() {
+            if reflect_mode != ReflectMode::Value {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!(
+                        "`opaque` can only be used with `#[{REFLECT_VALUE_ATTRIBUTE_NAME}]`"
+                    ),
+                ));
+            }
+
+            if meta.attrs().transparent().is_some() {
+                return Err(syn::Error::new(
+                    span,
+                    "`opaque` cannot be used with `transparent`",
+                ));
+            }
+
+            let bevy_reflect_path = meta.bevy_reflect_path();
+            if meta.attrs().get_hash_impl(bevy_reflect_path).is_some()
+                || meta
+                    .attrs()
+                    .get_partial_eq_impl(bevy_reflect_path)
+                    .is_some()
+            {
+                return Err(syn::Error::new(
+                    span,
+                    "`opaque` values cannot register `Hash` or `PartialEq`",
+                ));
+            }
+        }
+
+        if let Some(span) = meta.attrs().transparent() {
+            if reflect_mode != ReflectMode::Value {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!(
+                        "`transparent` can only be used with `#[{REFLECT_VALUE_ATTRIBUTE_NAME}]`"
+                    ),
+                ));
+            }
+
+            return match &input.data {
+                Data::Struct(DataStruct {
+                    fields: Fields::Unnamed(fields),
+                    ..
+                }) if fields.unnamed.len() == 1 => Ok(Self::Value(
+                    meta.with_transparent_field(&fields.unnamed[0].ty),
+                )),
+                _ => Err(syn::Error::new(
+                    span,
+                    "`transparent` can only be used on tuple structs with exactly one field",
+                )),
+            };
+        }
+
+        let generate_paths = meta
+            .attrs()
+            .generate_paths()
+            .filter(|_| provenance.trait_ == ReflectTraitToImpl::Reflect);
+        if let Some(span) = generate_paths {
+            if provenance.source != ReflectImplSource::DeriveLocalType {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!("`generate_paths` cannot be used with {provenance}"),
+                ));
+            }
+
+            let is_named_struct = matches!(
+                input.data,
+                Data::Struct(DataStruct {
+                    fields: Fields::Named(..),
+                    ..
+                })
+            );
+            if reflect_mode != ReflectMode::Normal || !is_named_struct {
+                return Err(syn::Error::new(
+                    span,
+                    "`generate_paths` can only be used on structs with named fields",
+                ));
+            }
+
+            if !input.generics.params.is_empty() {
+                return Err(syn::Error::new(
+                    span,
+                    "`generate_paths` cannot be used on generic types",
+                ));
+            }
+        }
+
+        let auto = meta
+            .attrs()
+            .auto()
+            .filter(|_| provenance.trait_ == ReflectTraitToImpl::Reflect);
+        if let Some(span) = auto {
+            if provenance.source != ReflectImplSource::DeriveLocalType {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!("`auto` cannot be used with {provenance}"),
+                ));
+            }
+
+            if !input.generics.params.is_empty() {
+                return Err(syn::Error::new(
+                    span,
+                    "`auto` cannot detect trait implementations on generic types\n\
+                    note: register the traits explicitly instead, such as `#[reflect(Debug, PartialEq)]`",
+                ));
+            }
+        }
+
+        let field_offsets = meta
+            .attrs()
+            .field_offsets()
+            .filter(|_| provenance.trait_ == ReflectTraitToImpl::Reflect);
+        if let Some(span) = field_offsets {
+            if provenance.source != ReflectImplSource::DeriveLocalType {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!("`field_offsets` cannot be used with {provenance}"),
+                ));
+            }
+
+            if reflect_mode != ReflectMode::Normal || !matches!(input.data, Data::Struct(..)) {
+                return Err(syn::Error::new(
+                    span,
+                    "`field_offsets` can only be used on structs",
+                ));
+            }
+        }
+
+        let pod = meta
+            .attrs()
+            .pod()
+            .filter(|_| provenance.trait_ == ReflectTraitToImpl::Reflect);
+        if let Some(span) = pod {
+            if provenance.source != ReflectImplSource::DeriveLocalType {
+                return Err(syn::Error::new(
+                    span,
+                    format_args!("`pod` cannot be used with {provenance}"),
+                ));
+            }
+
+            if reflect_mode != ReflectMode::Normal || !matches!(input.data, Data::Struct(..)) {
+                return Err(syn::Error::new(span, "`pod` can only be used on structs"));
+            }
+        }
+
         if reflect_mode == ReflectMode::Value {
             return Ok(Self::Value(meta));
         }
@@ -318,9 +474,18 @@ impl<'a> ReflectDerive<'a> {
         return match &input.data {
             Data::Struct(data) => {
                 let fields = Self::collect_struct_fields(&data.fields)?;
+                Self::validate_stable_indices(&fields, matches!(data.fields, Fields::Named(..)))?;
+                Self::validate_skipped_fields(&fields, meta.attrs())?;
+                if pod.is_some() {
+                    Self::validate_pod_fields(&fields)?;
+                }
+                if provenance.trait_ == ReflectTraitToImpl::Reflect {
+                    Self::validate_no_borrowed_fields(&fields, &input.generics)?;
+                }
                 let reflect_struct = ReflectStruct {
                     meta,
                     serialization_data: SerializationDataDef::new(&fields)?,
+                    redaction_data: RedactionDataDef::new(&fields)?,
                     fields,
                 };
 
@@ -332,6 +497,11 @@ impl<'a> ReflectDerive<'a> {
             }
             Data::Enum(data) => {
                 let variants = Self::collect_enum_variants(&data.variants)?;
+                if provenance.trait_ == ReflectTraitToImpl::Reflect {
+                    for variant in &variants {
+                        Self::validate_no_borrowed_fields(variant.fields(), &input.generics)?;
+                    }
+                }
 
                 let reflect_enum = ReflectEnum { meta, variants };
                 Ok(Self::Enum(reflect_enum))
@@ -387,6 +557,180 @@ impl<'a> ReflectDerive<'a> {
         sifter.finish()
     }
 
+    /// Validates the `#[reflect(index = N)]` attributes on the given fields.
+    ///
+    /// Stable indices are only allowed on the active fields of named structs
+    /// and must be unique within the struct.
+    fn validate_stable_indices(fields: &[StructField], is_named: bool) -> Result<(), syn::Error> {
+        let mut seen = HashSet::new();
+        for field in fields {
+            let Some(lit) = &field.attrs.stable_index else {
+                continue;
+            };
+
+            if !is_named {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!("`#[reflect({STABLE_INDEX_ATTR} = ...)]` can only be used on fields of named structs"),
+                ));
+            }
+
+            if field.attrs.ignore.is_ignored() {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!(
+                        "`#[reflect({STABLE_INDEX_ATTR} = ...)]` cannot be used on ignored fields"
+                    ),
+                ));
+            }
+
+            let index = lit.base10_parse::<usize>()?;
+            if !seen.insert(index) {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!(
+                        "duplicate stable index `{index}`: each field must have a unique index"
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates the fields of a `#[reflect(pod)]` struct.
+    ///
+    /// Every field must be reflected and have a plain-old-data type,
+    /// so that applying the struct can be done with a single assignment.
+    fn validate_pod_fields(fields: &[StructField]) -> Result<(), syn::Error> {
+        for field in fields {
+            if field.attrs.ignore.is_ignored() {
+                return Err(syn::Error::new(
+                    field.data.span(),
+                    "`pod` cannot be used on structs with ignored fields",
+                ));
+            }
+
+            if !utility::is_pod_type(&field.data.ty) {
+                return Err(syn::Error::new(
+                    field.data.ty.span(),
+                    "`pod` requires every field to be a primitive, or an array or tuple of primitives",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates that none of the given active fields borrow data for one of the type's lifetimes.
+    ///
+    /// Reflected types must be `'static`, so any lifetime parameters are required to be `'static` as well.
+    /// A field borrowing for such a lifetime could then only ever hold `'static` data,
+    /// which is almost certainly not what was intended.
+    fn validate_no_borrowed_fields(
+        fields: &[StructField],
+        generics: &Generics,
+    ) -> Result<(), syn::Error> {
+        let lifetimes = generics
+            .lifetimes()
+            .map(|param| &param.lifetime.ident)
+            .collect::<Vec<_>>();
+        if lifetimes.is_empty() {
+            return Ok(());
+        }
+
+        for field in fields.iter().filter(|field| field.attrs.ignore.is_active()) {
+            if let Some(span) = utility::find_lifetime(field.data.ty.to_token_stream(), &lifetimes)
+            {
+                return Err(syn::Error::new(
+                    span,
+                    "reflected fields cannot borrow data for the type's lifetimes, as reflected types must be `'static`\n\
+                    note: use an owned type instead, or mark the field with `#[reflect(ignore)]`",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates the `#[reflect(skip_hash)]` and `#[reflect(skip_partial_eq)]` attributes on the given struct fields.
+    ///
+    /// Skipping a field only takes effect when the derive generates a field-wise implementation,
+    /// so each attribute is rejected wherever it would otherwise be silently ignored.
+    fn validate_skipped_fields(
+        fields: &[StructField],
+        attrs: &ContainerAttributes,
+    ) -> Result<(), syn::Error> {
+        for field in fields {
+            for (skipped, name) in [
+                (field.attrs.skip_hash, SKIP_HASH_ATTR),
+                (field.attrs.skip_partial_eq, SKIP_PARTIAL_EQ_ATTR),
+            ] {
+                if skipped && field.attrs.ignore.is_ignored() {
+                    return Err(syn::Error::new(
+                        field.data.span(),
+                        format!("`#[reflect({name})]` cannot be used on ignored fields"),
+                    ));
+                }
+            }
+
+            if field.attrs.skip_hash && !attrs.has_hash() {
+                return Err(syn::Error::new(
+                    field.data.span(),
+                    if attrs.has_custom_hash() {
+                        format!("`#[reflect({SKIP_HASH_ATTR})]` cannot be used when `Hash` is registered with a custom function")
+                    } else {
+                        format!("`#[reflect({SKIP_HASH_ATTR})]` requires `Hash` to be registered, such as with `#[reflect(Hash)]`")
+                    },
+                ));
+            }
+
+            if field.attrs.skip_partial_eq && attrs.has_custom_partial_eq() {
+                return Err(syn::Error::new(
+                    field.data.span(),
+                    format!("`#[reflect({SKIP_PARTIAL_EQ_ATTR})]` cannot be used when `PartialEq` is registered with a custom function"),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates that none of the given enum variant fields are marked
+    /// `#[reflect(skip_hash)]` or `#[reflect(skip_partial_eq)]`.
+    ///
+    /// Enums always delegate to their registered `Hash` and `PartialEq` implementations.
+    fn validate_no_skipped_fields(fields: &[StructField]) -> Result<(), syn::Error> {
+        for field in fields {
+            for (skipped, name) in [
+                (field.attrs.skip_hash, SKIP_HASH_ATTR),
+                (field.attrs.skip_partial_eq, SKIP_PARTIAL_EQ_ATTR),
+            ] {
+                if skipped {
+                    return Err(syn::Error::new(
+                        field.data.span(),
+                        format!("`#[reflect({name})]` cannot be used on enum variant fields"),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates that none of the given enum variant fields are marked `#[reflect(redact)]`.
+    ///
+    /// Redaction is currently only supported on the fields of structs and tuple structs.
+    fn validate_no_redacted_fields(fields: &[StructField]) -> Result<(), syn::Error> {
+        match fields.iter().find(|field| field.attrs.redact) {
+            Some(field) => Err(syn::Error::new(
+                field.data.span(),
+                format!("`#[reflect({REDACT_ATTR})]` cannot be used on enum variant fields"),
+            )),
+            None => Ok(()),
+        }
+    }
+
     fn collect_enum_variants(
         variants: &'a Punctuated<Variant, Comma>,
     ) -> Result<Vec<EnumVariant<'a>>, syn::Error> {
@@ -395,6 +739,9 @@ impl<'a> ReflectDerive<'a> {
             .enumerate()
             .map(|(index, variant)| -> Result<EnumVariant, syn::Error> {
                 let fields = Self::collect_struct_fields(&variant.fields)?;
+                Self::validate_stable_indices(&fields, false)?;
+                Self::validate_no_redacted_fields(&fields)?;
+                Self::validate_no_skipped_fields(&fields)?;
 
                 let fields = match variant.fields {
                     Fields::Named(..) => EnumVariantFields::Named(fields),
@@ -425,11 +772,20 @@ impl<'a> ReflectMeta<'a> {
             attrs,
             type_path,
             bevy_reflect_path: utility::get_bevy_reflect_path(),
+            transparent_field: None,
             #[cfg(feature = "documentation")]
             docs: Default::default(),
         }
     }
 
+    /// Sets the type of the field wrapped by a `#[reflect_value(transparent)]` type.
+    pub fn with_transparent_field(self, transparent_field: &'a Type) -> Self {
+        Self {
+            transparent_field: Some(transparent_field),
+            ..self
+        }
+    }
+
     /// Sets the documentation for this type.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: crate::documentation::Documentation) -> Self {
@@ -462,6 +818,28 @@ impl<'a> ReflectMeta<'a> {
         &self.bevy_reflect_path
     }
 
+    /// Returns the implementation of the `Reflect` method for the given trait,
+    /// accounting for `#[reflect(auto)]`.
+    ///
+    /// See [`ContainerAttributes::get_auto_impl`] for details.
+    pub fn get_auto_impl(
+        &self,
+        trait_: AutoTrait,
+        reflect_fn: Option<proc_macro2::TokenStream>,
+    ) -> Option<proc_macro2::TokenStream> {
+        self.attrs.get_auto_impl(
+            trait_,
+            reflect_fn,
+            self.type_path(),
+            self.bevy_reflect_path(),
+        )
+    }
+
+    /// The type of the wrapped field, if this is a `#[reflect_value(transparent)]` type.
+    pub fn transparent_field(&self) -> Option<&'a Type> {
+        self.transparent_field
+    }
+
     /// Returns the `GetTypeRegistration` impl as a `TokenStream`.
     pub fn get_type_registration(
         &self,
@@ -471,7 +849,8 @@ impl<'a> ReflectMeta<'a> {
             self,
             where_clause_options,
             None,
-            Option::<std::iter::Empty<&Type>>::None,
+            None,
+            self.transparent_field.map(std::iter::once),
         )
     }
 
@@ -486,7 +865,7 @@ impl<'a> StructField<'a> {
     /// Generates a `TokenStream` for `NamedField` or `UnnamedField` construction.
     pub fn to_info_tokens(&self, bevy_reflect_path: &Path) -> proc_macro2::TokenStream {
         let name = match &self.data.ident {
-            Some(ident) => ident.to_string().to_token_stream(),
+            Some(ident) => ident_name(ident).to_token_stream(),
             None => self.reflection_index.to_token_stream(),
         };
 
@@ -508,6 +887,26 @@ impl<'a> StructField<'a> {
             #field_info::new::<#ty>(#name).with_custom_attributes(#custom_attributes)
         };
 
+        if let Some(stable_index) = &self.attrs.stable_index {
+            // Already validated when parsing the attribute
+            let stable_index = stable_index.base10_parse::<usize>().unwrap();
+            info.extend(quote! {
+                .with_stable_index(#stable_index)
+            });
+        }
+
+        if self.attrs.skip_hash {
+            info.extend(quote!(.with_skip_hash()));
+        }
+
+        if self.attrs.skip_partial_eq {
+            info.extend(quote!(.with_skip_partial_eq()));
+        }
+
+        if self.attrs.redact {
+            info.extend(quote!(.with_redacted()));
+        }
+
         #[cfg(feature = "documentation")]
         {
             let docs = &self.doc;
@@ -531,6 +930,11 @@ impl<'a> ReflectStruct<'a> {
         self.serialization_data.as_ref()
     }
 
+    /// Returns the [`RedactionDataDef`] for this struct.
+    pub fn redaction_data(&self) -> Option<&RedactionDataDef> {
+        self.redaction_data.as_ref()
+    }
+
     /// Returns the `GetTypeRegistration` impl as a `TokenStream`.
     ///
     /// Returns a specific implementation for structs and this method should be preferred over the generic [`get_type_registration`](ReflectMeta) method
@@ -542,6 +946,7 @@ impl<'a> ReflectStruct<'a> {
             self.meta(),
             where_clause_options,
             self.serialization_data(),
+            self.redaction_data(),
             Some(self.active_types().iter()),
         )
     }
@@ -576,6 +981,166 @@ impl<'a> ReflectStruct<'a> {
         WhereClauseOptions::new_with_fields(self.meta(), self.active_types().into_boxed_slice())
     }
 
+    /// Returns the `PodStruct` implementation as a `TokenStream`.
+    ///
+    /// If `pod` was not used, returns `None`.
+    pub fn get_pod_impl(
+        &self,
+        where_clause_options: &WhereClauseOptions,
+    ) -> Option<proc_macro2::TokenStream> {
+        self.meta().attrs().pod()?;
+
+        let bevy_reflect_path = self.meta().bevy_reflect_path();
+        let type_path = self.meta().type_path();
+        let (impl_generics, ty_generics, where_clause) = type_path.generics().split_for_impl();
+        let where_reflect_clause = where_clause_options.extend_where_clause(where_clause);
+
+        let members = self.fields().iter().map(|field| {
+            utility::ident_or_index(field.data.ident.as_ref(), field.declaration_index)
+        });
+
+        Some(quote! {
+            impl #impl_generics #bevy_reflect_path::PodStruct for #type_path #ty_generics #where_reflect_clause {
+                #[inline]
+                fn pod_apply(&mut self, other: &Self) {
+                    #(self.#members = other.#members;)*
+                }
+            }
+        })
+    }
+
+    /// Returns the fast path for `Reflect::try_apply` as a `TokenStream`.
+    ///
+    /// If `pod` was not used, returns `None`.
+    pub fn get_pod_apply(&self) -> Option<proc_macro2::TokenStream> {
+        self.meta().attrs().pod()?;
+
+        let bevy_reflect_path = self.meta().bevy_reflect_path();
+        Some(quote! {
+            if #bevy_reflect_path::__macro_exports::try_apply_pod(self, value) {
+                return #FQResult::Ok(());
+            }
+        })
+    }
+
+    /// Returns the implementation of `Reflect::reflect_hash` as a `TokenStream`.
+    ///
+    /// If any field is marked `#[reflect(skip_hash)]` and `Hash` was registered,
+    /// the hash is computed field-wise over the remaining fields
+    /// instead of delegating to the type's `Hash` implementation.
+    pub fn get_hash_impl(&self) -> Option<proc_macro2::TokenStream> {
+        let bevy_reflect_path = self.meta().bevy_reflect_path();
+        let attrs = self.meta().attrs();
+
+        if !attrs.has_hash() || !self.fields().iter().any(|field| field.attrs.skip_hash) {
+            return attrs.get_hash_impl(bevy_reflect_path);
+        }
+
+        let members = self
+            .fields()
+            .iter()
+            .filter(|field| !field.attrs.skip_hash)
+            .map(|field| {
+                utility::ident_or_index(field.data.ident.as_ref(), field.declaration_index)
+            });
+
+        Some(quote! {
+            fn reflect_hash(&self) -> #FQOption<u64> {
+                use ::core::hash::{Hash, Hasher};
+                let mut hasher = #bevy_reflect_path::utility::reflect_hasher();
+                Hash::hash(&#FQAny::type_id(self), &mut hasher);
+                #(Hash::hash(&self.#members, &mut hasher);)*
+                #FQOption::Some(Hasher::finish(&hasher))
+            }
+        })
+    }
+
+    /// Returns the implementation of `Reflect::reflect_partial_eq` as a `TokenStream`.
+    ///
+    /// If any field is marked `#[reflect(skip_partial_eq)]` and no custom `PartialEq` function was registered,
+    /// the comparison is done field-wise over the remaining fields.
+    /// Otherwise, returns `None` if `PartialEq` was not registered.
+    pub fn get_partial_eq_impl(&self, is_tuple: bool) -> Option<proc_macro2::TokenStream> {
+        let bevy_reflect_path = self.meta().bevy_reflect_path();
+        let attrs = self.meta().attrs();
+
+        if attrs.has_custom_partial_eq()
+            || !self
+                .fields()
+                .iter()
+                .any(|field| field.attrs.skip_partial_eq)
+        {
+            return attrs.get_partial_eq_impl(bevy_reflect_path);
+        }
+
+        if attrs.has_partial_eq() {
+            // Compare the concrete fields using their `PartialEq` implementations
+            let members = self
+                .fields()
+                .iter()
+                .filter(|field| !field.attrs.skip_partial_eq)
+                .map(|field| {
+                    utility::ident_or_index(field.data.ident.as_ref(), field.declaration_index)
+                });
+
+            return Some(quote! {
+                fn reflect_partial_eq(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<bool> {
+                    let value = <dyn #bevy_reflect_path::Reflect>::as_any(value);
+                    if let #FQOption::Some(value) = <dyn #FQAny>::downcast_ref::<Self>(value) {
+                        #FQOption::Some(true #(&& ::core::cmp::PartialEq::eq(&self.#members, &value.#members))*)
+                    } else {
+                        #FQOption::Some(false)
+                    }
+                }
+            });
+        }
+
+        // Compare the remaining reflected fields, similar to `struct_partial_eq`/`tuple_struct_partial_eq`
+        let (ref_variant, trait_path) = if is_tuple {
+            (quote!(TupleStruct), quote!(#bevy_reflect_path::TupleStruct))
+        } else {
+            (quote!(Struct), quote!(#bevy_reflect_path::Struct))
+        };
+
+        let field_checks = self
+            .active_fields()
+            .filter(|field| !field.attrs.skip_partial_eq)
+            .map(|field| {
+                let member =
+                    utility::ident_or_index(field.data.ident.as_ref(), field.declaration_index);
+                let accessor = match &field.data.ident {
+                    Some(ident) => ident_name(ident).to_token_stream(),
+                    None => field.reflection_index.to_token_stream(),
+                };
+                quote! {
+                    let #FQOption::Some(field) = #trait_path::field(value, #accessor) else {
+                        return #FQOption::Some(false);
+                    };
+                    if let failed @ (#FQOption::Some(false) | #FQOption::None) =
+                        #bevy_reflect_path::Reflect::reflect_partial_eq(&self.#member, field)
+                    {
+                        return failed;
+                    }
+                }
+            });
+
+        Some(quote! {
+            fn reflect_partial_eq(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<bool> {
+                let #bevy_reflect_path::ReflectRef::#ref_variant(value) = #bevy_reflect_path::Reflect::reflect_ref(value) else {
+                    return #FQOption::Some(false);
+                };
+
+                if #trait_path::field_len(self) != #trait_path::field_len(value) {
+                    return #FQOption::Some(false);
+                }
+
+                #(#field_checks)*
+
+                #FQOption::Some(true)
+            }
+        })
+    }
+
     /// Generates a `TokenStream` for `TypeInfo::Struct` or `TypeInfo::TupleStruct` construction.
     pub fn to_info_tokens(&self, is_tuple: bool) -> proc_macro2::TokenStream {
         let bevy_reflect_path = self.meta().bevy_reflect_path();
@@ -592,9 +1157,25 @@ impl<'a> ReflectStruct<'a> {
             )
         };
 
-        let field_infos = self
-            .active_fields()
-            .map(|field| field.to_info_tokens(bevy_reflect_path));
+        let with_offsets = self.meta.attrs.field_offsets().is_some();
+        let field_infos = self.active_fields().map(|field| {
+            let info = field.to_info_tokens(bevy_reflect_path);
+            if !with_offsets {
+                return info;
+            }
+
+            let member =
+                utility::ident_or_index(field.data.ident.as_ref(), field.declaration_index);
+            quote! {
+                {
+                    let info = #info;
+                    #[allow(unsafe_code)]
+                    // SAFETY: The offset is computed for this field of `Self`
+                    let info = unsafe { info.with_offset(::core::mem::offset_of!(Self, #member)) };
+                    info
+                }
+            }
+        });
 
         let custom_attributes = self
             .meta
@@ -672,6 +1253,7 @@ impl<'a> ReflectEnum<'a> {
             self.meta(),
             where_clause_options,
             None,
+            None,
             Some(self.active_fields().map(|field| &field.data.ty)),
         )
     }
@@ -680,10 +1262,19 @@ impl<'a> ReflectEnum<'a> {
     pub fn to_info_tokens(&self) -> proc_macro2::TokenStream {
         let bevy_reflect_path = self.meta().bevy_reflect_path();
 
-        let variants = self
+        // Discriminants can only be cast to integers when every variant is a unit variant
+        let is_fieldless = self
             .variants
             .iter()
-            .map(|variant| variant.to_info_tokens(bevy_reflect_path));
+            .all(|variant| matches!(variant.fields, EnumVariantFields::Unit));
+
+        let variants = self.variants.iter().map(|variant| {
+            let discriminant = is_fieldless.then(|| {
+                let unit = self.get_unit(&variant.data.ident);
+                quote!(#unit as i128)
+            });
+            variant.to_info_tokens(bevy_reflect_path, discriminant)
+        });
 
         let custom_attributes = self
             .meta
@@ -731,8 +1322,14 @@ impl<'a> EnumVariant<'a> {
     }
 
     /// Generates a `TokenStream` for `VariantInfo` construction.
-    pub fn to_info_tokens(&self, bevy_reflect_path: &Path) -> proc_macro2::TokenStream {
-        let variant_name = &self.data.ident.to_string();
+    ///
+    /// The `discriminant` expression is only used for unit variants.
+    pub fn to_info_tokens(
+        &self,
+        bevy_reflect_path: &Path,
+        discriminant: Option<proc_macro2::TokenStream>,
+    ) -> proc_macro2::TokenStream {
+        let variant_name = &ident_name(&self.data.ident);
 
         let (info_variant, info_struct) = match &self.fields {
             EnumVariantFields::Unit => (
@@ -762,12 +1359,17 @@ impl<'a> EnumVariant<'a> {
 
         let custom_attributes = self.attrs.custom_attributes.to_tokens(bevy_reflect_path);
 
-        #[allow(unused_mut)] // Needs mutability for the feature gate
         let mut info = quote! {
             #bevy_reflect_path::#info_struct::new(#args)
                 .with_custom_attributes(#custom_attributes)
         };
 
+        if let (EnumVariantFields::Unit, Some(discriminant)) = (&self.fields, discriminant) {
+            info.extend(quote! {
+                .with_discriminant(#discriminant)
+            });
+        }
+
         #[cfg(feature = "documentation")]
         {
             let docs = &self.doc;
@@ -1,8 +1,9 @@
+use crate::derive_data::ReflectEnum;
 use crate::derive_data::StructField;
 use crate::field_attributes::DefaultBehavior;
-use crate::{derive_data::ReflectEnum, utility::ident_or_index};
+use crate::utility::{ident_name, ident_or_index};
 use bevy_macro_utils::fq_std::{FQDefault, FQOption};
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
 
 pub(crate) struct EnumVariantOutputData {
@@ -44,7 +45,7 @@ pub(crate) trait VariantBuilder: Sized {
     fn access_field(&self, this: &Ident, field: VariantField) -> TokenStream {
         match &field.field.data.ident {
             Some(field_ident) => {
-                let name = field_ident.to_string();
+                let name = ident_name(field_ident);
                 quote!(#this.field(#name))
             }
             None => {
@@ -132,14 +133,18 @@ pub(crate) trait VariantBuilder: Sized {
 
         for variant in variants {
             let variant_ident = &variant.data.ident;
-            let variant_name = variant_ident.to_string();
+            let variant_name = ident_name(variant_ident);
             let variant_path = self.reflect_enum().get_unit(variant_ident);
 
             let fields = variant.fields();
 
             let field_constructors = fields.iter().map(|field| {
                 let member = ident_or_index(field.data.ident.as_ref(), field.declaration_index);
-                let alias = format_ident!("_{}", member);
+                let alias = format_ident!(
+                    "__field{}",
+                    field.declaration_index,
+                    span = Span::mixed_site()
+                );
 
                 let variant_field = VariantField {
                     alias: &alias,
@@ -236,7 +241,7 @@ impl<'a> VariantBuilder for TryApplyVariantBuilder<'a> {
         let bevy_reflect_path = self.reflect_enum.meta().bevy_reflect_path();
 
         let field_name = match &field.data.ident {
-            Some(ident) => format!("{ident}"),
+            Some(ident) => ident_name(ident),
             None => format!(".{}", field.declaration_index),
         };
 
@@ -8,12 +8,16 @@ use crate::custom_attributes::CustomAttributes;
 use crate::utility::terminated_parser;
 use crate::REFLECT_ATTRIBUTE_NAME;
 use syn::parse::ParseStream;
-use syn::{Attribute, LitStr, Meta, Token};
+use syn::{Attribute, LitInt, LitStr, Meta, Token};
 
 mod kw {
     syn::custom_keyword!(ignore);
     syn::custom_keyword!(skip_serializing);
     syn::custom_keyword!(default);
+    syn::custom_keyword!(index);
+    syn::custom_keyword!(skip_hash);
+    syn::custom_keyword!(skip_partial_eq);
+    syn::custom_keyword!(redact);
 }
 
 pub(crate) const IGNORE_SERIALIZATION_ATTR: &str = "skip_serializing";
@@ -21,6 +25,13 @@ pub(crate) const IGNORE_ALL_ATTR: &str = "ignore";
 
 pub(crate) const DEFAULT_ATTR: &str = "default";
 
+pub(crate) const STABLE_INDEX_ATTR: &str = "index";
+
+pub(crate) const SKIP_HASH_ATTR: &str = "skip_hash";
+pub(crate) const SKIP_PARTIAL_EQ_ATTR: &str = "skip_partial_eq";
+
+pub(crate) const REDACT_ATTR: &str = "redact";
+
 /// Stores data about if the field should be visible via the Reflect and serialization interfaces
 ///
 /// Note the relationship between serialization and reflection is such that a member must be reflected in order to be serialized.
@@ -74,6 +85,14 @@ pub(crate) struct FieldAttributes {
     pub ignore: ReflectIgnoreBehavior,
     /// Sets the default behavior of this field.
     pub default: DefaultBehavior,
+    /// The explicit stable index of this field, set via `#[reflect(index = N)]`.
+    pub stable_index: Option<LitInt>,
+    /// Whether this field is excluded from `Reflect::reflect_hash`, set via `#[reflect(skip_hash)]`.
+    pub skip_hash: bool,
+    /// Whether this field is excluded from `Reflect::reflect_partial_eq`, set via `#[reflect(skip_partial_eq)]`.
+    pub skip_partial_eq: bool,
+    /// Whether this field is hidden from debug and serialization output, set via `#[reflect(redact)]`.
+    pub redact: bool,
     /// Custom attributes created via `#[reflect(@...)]`.
     pub custom_attributes: CustomAttributes,
 }
@@ -119,6 +138,14 @@ impl FieldAttributes {
             self.parse_skip_serializing(input)
         } else if lookahead.peek(kw::default) {
             self.parse_default(input)
+        } else if lookahead.peek(kw::index) {
+            self.parse_stable_index(input)
+        } else if lookahead.peek(kw::skip_hash) {
+            self.parse_skip_hash(input)
+        } else if lookahead.peek(kw::skip_partial_eq) {
+            self.parse_skip_partial_eq(input)
+        } else if lookahead.peek(kw::redact) {
+            self.parse_redact(input)
         } else {
             Err(lookahead.error())
         }
@@ -182,6 +209,70 @@ impl FieldAttributes {
         Ok(())
     }
 
+    /// Parse `index` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(index = 3)]`
+    fn parse_stable_index(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.stable_index.is_some() {
+            return Err(input.error(format!("only one of {:?} is allowed", [STABLE_INDEX_ATTR])));
+        }
+
+        input.parse::<kw::index>()?;
+        input.parse::<Token![=]>()?;
+
+        let lit = input.parse::<LitInt>()?;
+        lit.base10_parse::<usize>()?;
+        self.stable_index = Some(lit);
+
+        Ok(())
+    }
+
+    /// Parse `skip_hash` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(skip_hash)]`
+    fn parse_skip_hash(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.skip_hash {
+            return Err(input.error(format!("only one of {:?} is allowed", [SKIP_HASH_ATTR])));
+        }
+
+        input.parse::<kw::skip_hash>()?;
+        self.skip_hash = true;
+        Ok(())
+    }
+
+    /// Parse `skip_partial_eq` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(skip_partial_eq)]`
+    fn parse_skip_partial_eq(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.skip_partial_eq {
+            return Err(input.error(format!(
+                "only one of {:?} is allowed",
+                [SKIP_PARTIAL_EQ_ATTR]
+            )));
+        }
+
+        input.parse::<kw::skip_partial_eq>()?;
+        self.skip_partial_eq = true;
+        Ok(())
+    }
+
+    /// Parse `redact` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(redact)]`
+    fn parse_redact(&mut self, input: ParseStream) -> syn::Result<()> {
+        if self.redact {
+            return Err(input.error(format!("only one of {:?} is allowed", [REDACT_ATTR])));
+        }
+
+        input.parse::<kw::redact>()?;
+        self.redact = true;
+        Ok(())
+    }
+
     /// Parse `@` (custom attribute) attribute.
     ///
     /// Examples:
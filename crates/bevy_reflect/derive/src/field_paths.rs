@@ -0,0 +1,119 @@
+use crate::derive_data::ReflectStruct;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{Ident, Visibility};
+
+/// Generates the typed path accessors for a struct with `#[reflect(generate_paths)]`.
+///
+/// For a struct `MyStruct`, this generates:
+/// * a `my_struct_fields` module containing a path constant for each reflected field,
+/// * a `MyStruct::path_{field}()` function for each reflected field, returning that constant,
+/// * a `MyStruct::fields()` function returning typed accessors used to compose nested paths.
+///
+/// The accessor type is declared in an anonymous const so that it cannot collide with the user's own types.
+/// It can still be named as `<MyStruct as GetFieldPaths>::FieldPaths`.
+pub(crate) fn impl_field_paths(
+    reflect_struct: &ReflectStruct,
+    ident: &Ident,
+    vis: &Visibility,
+) -> TokenStream {
+    let bevy_reflect_path = reflect_struct.meta().bevy_reflect_path();
+    let module_ident = format_ident!("{}_fields", to_snake_case(&ident.unraw().to_string()));
+
+    let mut constants = Vec::new();
+    let mut path_fns = Vec::new();
+    let mut accessors = Vec::new();
+
+    for field in reflect_struct.active_fields() {
+        let field_ident = field.data.ident.as_ref().unwrap();
+        let field_vis = &field.data.vis;
+        let field_ty = &field.data.ty;
+        let unraw = field_ident.unraw().to_string();
+        let const_ident = format_ident!("{}", unraw.to_uppercase());
+        let path_fn_ident = format_ident!("path_{}", unraw);
+
+        let const_doc = format!("The reflection path of the `{unraw}` field of `{ident}`.");
+        constants.push(quote! {
+            #[doc = #const_doc]
+            pub const #const_ident: &'static str = #unraw;
+        });
+
+        let path_fn_doc = format!("Returns the reflection path of the `{unraw}` field.");
+        path_fns.push(quote! {
+            #[doc = #path_fn_doc]
+            #field_vis const fn #path_fn_ident() -> &'static str {
+                #module_ident::#const_ident
+            }
+        });
+
+        let accessor_doc = format!("Returns the typed reflection path of the `{unraw}` field.");
+        accessors.push(quote! {
+            #[doc = #accessor_doc]
+            #field_vis fn #field_ident(&self) -> #bevy_reflect_path::FieldPath<#field_ty> {
+                self.prefix.field(#module_ident::#const_ident)
+            }
+        });
+    }
+
+    let module_doc = format!("Reflection path constants for the fields of `{ident}`.");
+
+    quote! {
+        #[doc = #module_doc]
+        ///
+        /// Generated by `#[reflect(generate_paths)]`.
+        #vis mod #module_ident {
+            #(#constants)*
+        }
+
+        const _: () = {
+            /// Typed reflection paths into the fields of a struct.
+            ///
+            /// Generated by `#[reflect(generate_paths)]`.
+            #[derive(Clone, Debug)]
+            pub struct FieldPaths {
+                prefix: #bevy_reflect_path::FieldPath<#ident>,
+            }
+
+            impl FieldPaths {
+                #(#accessors)*
+            }
+
+            impl #ident {
+                #(#path_fns)*
+
+                /// Returns the typed reflection paths into the fields of this type.
+                ///
+                /// Generated by `#[reflect(generate_paths)]`.
+                #vis fn fields() -> <Self as #bevy_reflect_path::GetFieldPaths>::FieldPaths {
+                    #bevy_reflect_path::FieldPath::<Self>::root().fields()
+                }
+            }
+
+            impl #bevy_reflect_path::GetFieldPaths for #ident {
+                type FieldPaths = FieldPaths;
+
+                fn field_paths_from(prefix: #bevy_reflect_path::FieldPath<Self>) -> Self::FieldPaths {
+                    FieldPaths { prefix }
+                }
+            }
+        };
+    }
+}
+
+/// Converts a type name in `UpperCamelCase` to `snake_case`.
+fn to_snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && index > 0 {
+            let prev = chars[index - 1];
+            let next_is_lower = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if prev != '_' && (prev.is_lowercase() || prev.is_numeric() || next_is_lower) {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
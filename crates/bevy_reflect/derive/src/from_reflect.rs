@@ -2,7 +2,7 @@ use crate::container_attributes::REFLECT_DEFAULT;
 use crate::derive_data::ReflectEnum;
 use crate::enum_utility::{EnumVariantOutputData, FromReflectVariantBuilder, VariantBuilder};
 use crate::field_attributes::DefaultBehavior;
-use crate::utility::{ident_or_index, WhereClauseOptions};
+use crate::utility::{generated_ident, ident_name, ident_or_index, WhereClauseOptions};
 use crate::{ReflectMeta, ReflectStruct};
 use bevy_macro_utils::fq_std::{FQAny, FQClone, FQDefault, FQOption};
 use proc_macro2::Span;
@@ -24,6 +24,22 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     let bevy_reflect_path = meta.bevy_reflect_path();
     let (impl_generics, ty_generics, where_clause) = type_path.generics().split_for_impl();
     let where_from_reflect_clause = WhereClauseOptions::new(meta).extend_where_clause(where_clause);
+
+    if let Some(inner) = meta.transparent_field() {
+        // Transparent values can be created from either themselves or their inner value
+        return quote! {
+            impl #impl_generics #bevy_reflect_path::FromReflect for #type_path #ty_generics #where_from_reflect_clause  {
+                fn from_reflect(reflect: &dyn #bevy_reflect_path::Reflect) -> #FQOption<Self> {
+                    if let #FQOption::Some(value) = <dyn #FQAny>::downcast_ref::<#type_path #ty_generics>(<dyn #bevy_reflect_path::Reflect>::as_any(reflect)) {
+                        return #FQOption::Some(#FQClone::clone(value));
+                    }
+
+                    #FQOption::Some(Self(<#inner as #bevy_reflect_path::FromReflect>::from_reflect(reflect)?))
+                }
+            }
+        };
+    }
+
     quote! {
         impl #impl_generics #bevy_reflect_path::FromReflect for #type_path #ty_generics #where_from_reflect_clause  {
             fn from_reflect(reflect: &dyn #bevy_reflect_path::Reflect) -> #FQOption<Self> {
@@ -40,7 +56,7 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
     let enum_path = reflect_enum.meta().type_path();
     let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
 
-    let ref_value = Ident::new("__param0", Span::call_site());
+    let ref_value = generated_ident("__param0");
 
     let EnumVariantOutputData {
         variant_names,
@@ -90,7 +106,7 @@ fn impl_struct_internal(
     let struct_path = reflect_struct.meta().type_path();
     let bevy_reflect_path = reflect_struct.meta().bevy_reflect_path();
 
-    let ref_struct = Ident::new("__ref_struct", Span::call_site());
+    let ref_struct = generated_ident("__ref_struct");
     let ref_struct_type = if is_tuple {
         Ident::new("TupleStruct", Span::call_site())
     } else {
@@ -102,15 +118,17 @@ fn impl_struct_internal(
 
     let is_defaultable = reflect_struct.meta().attrs().contains(REFLECT_DEFAULT);
     let constructor = if is_defaultable {
+        let this = generated_ident("__this");
+        let field = generated_ident("__field");
         quote!(
-            let mut __this: Self = #FQDefault::default();
+            let mut #this: Self = #FQDefault::default();
             #(
-                if let #fqoption::Some(__field) = #active_values() {
+                if let #fqoption::Some(#field) = #active_values() {
                     // Iff field exists -> use its value
-                    __this.#active_members = __field;
+                    #this.#active_members = #field;
                 }
             )*
-            #FQOption::Some(__this)
+            #FQOption::Some(#this)
         )
     } else {
         let MemberValuePair(ignored_members, ignored_values) = get_ignored_fields(reflect_struct);
@@ -240,7 +258,7 @@ fn get_field_accessor(field: &Field, index: usize, is_tuple: bool) -> Lit {
         field
             .ident
             .as_ref()
-            .map(|ident| Lit::Str(LitStr::new(&ident.to_string(), Span::call_site())))
+            .map(|ident| Lit::Str(LitStr::new(&ident_name(ident), Span::call_site())))
             .unwrap_or_else(|| Lit::Str(LitStr::new(&index.to_string(), Span::call_site())))
     }
 }
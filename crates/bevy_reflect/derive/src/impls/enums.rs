@@ -1,6 +1,8 @@
+use crate::container_attributes::AutoTrait;
 use crate::derive_data::{EnumVariantFields, ReflectEnum, StructField};
 use crate::enum_utility::{EnumVariantOutputData, TryApplyVariantBuilder, VariantBuilder};
 use crate::impls::{impl_type_path, impl_typed};
+use crate::utility::{generated_ident, ident_name};
 use bevy_macro_utils::fq_std::{FQAny, FQBox, FQOption, FQResult};
 use proc_macro2::{Ident, Span};
 use quote::quote;
@@ -10,9 +12,9 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
     let bevy_reflect_path = reflect_enum.meta().bevy_reflect_path();
     let enum_path = reflect_enum.meta().type_path();
 
-    let ref_name = Ident::new("__name_param", Span::call_site());
-    let ref_index = Ident::new("__index_param", Span::call_site());
-    let ref_value = Ident::new("__value_param", Span::call_site());
+    let ref_name = generated_ident("__name_param");
+    let ref_index = generated_ident("__index_param");
+    let ref_value = generated_ident("__value_param");
 
     let where_clause_options = reflect_enum.where_clause_options();
 
@@ -44,6 +46,10 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
                 }
             }
         });
+    let partial_ord_fn = reflect_enum
+        .meta()
+        .attrs()
+        .get_partial_ord_impl(bevy_reflect_path);
     let debug_fn = reflect_enum.meta().attrs().get_debug_impl();
     let partial_eq_fn = reflect_enum
         .meta()
@@ -57,6 +63,12 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
             }
         });
 
+    let meta = reflect_enum.meta();
+    let hash_fn = meta.get_auto_impl(AutoTrait::Hash, Some(hash_fn));
+    let partial_eq_fn = meta.get_auto_impl(AutoTrait::PartialEq, Some(partial_eq_fn));
+    let partial_ord_fn = meta.get_auto_impl(AutoTrait::PartialOrd, partial_ord_fn);
+    let debug_fn = meta.get_auto_impl(AutoTrait::Debug, debug_fn);
+
     let typed_impl = impl_typed(
         reflect_enum.meta(),
         &where_clause_options,
@@ -250,12 +262,8 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
                         }
                     }
                 } else {
-                    return #FQResult::Err(
-                        #bevy_reflect_path::ApplyError::MismatchedKinds {
-                            from_kind: #bevy_reflect_path::Reflect::reflect_kind(#ref_value),
-                            to_kind: #bevy_reflect_path::ReflectKind::Enum,
-                        }
-                    );
+                    // Non-enum patch -> update the fields of the current variant
+                    return #bevy_reflect_path::enum_try_apply_fields(self, #ref_value);
                 }
                 #FQResult::Ok(())
             }
@@ -280,6 +288,8 @@ pub(crate) fn impl_enum(reflect_enum: &ReflectEnum) -> proc_macro2::TokenStream
 
             #partial_eq_fn
 
+            #partial_ord_fn
+
             #debug_fn
         }
     }
@@ -310,7 +320,7 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
 
     for (variant_index, variant) in reflect_enum.variants().iter().enumerate() {
         let ident = &variant.data.ident;
-        let name = ident.to_string();
+        let name = ident_name(ident);
         let unit = reflect_enum.get_unit(ident);
 
         let variant_type_ident = match variant.data.fields {
@@ -375,16 +385,16 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
             EnumVariantFields::Named(fields) => {
                 let field_len = process_fields(fields, |field: &StructField| {
                     let field_ident = field.data.ident.as_ref().unwrap();
-                    let field_name = field_ident.to_string();
+                    let field_name = ident_name(field_ident);
                     let reflection_index = field
                         .reflection_index
                         .expect("reflection index should exist for active field");
 
                     enum_field.push(quote! {
-                        #unit{ #field_ident, .. } if #ref_name == #field_name => #FQOption::Some(#field_ident)
+                        #unit{ #field_ident: value, .. } if #ref_name == #field_name => #FQOption::Some(value)
                     });
                     enum_field_at.push(quote! {
-                        #unit{ #field_ident, .. } if #ref_index == #reflection_index => #FQOption::Some(#field_ident)
+                        #unit{ #field_ident: value, .. } if #ref_index == #reflection_index => #FQOption::Some(value)
                     });
                     enum_index_of.push(quote! {
                         #unit{ .. } if #ref_name == #field_name => #FQOption::Some(#reflection_index)
@@ -1,5 +1,6 @@
+use crate::container_attributes::AutoTrait;
 use crate::impls::{impl_type_path, impl_typed};
-use crate::utility::ident_or_index;
+use crate::utility::{ident_name, ident_or_index};
 use crate::ReflectStruct;
 use bevy_macro_utils::fq_std::{FQAny, FQBox, FQDefault, FQOption, FQResult};
 use quote::{quote, ToTokens};
@@ -18,7 +19,7 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
                 .data
                 .ident
                 .as_ref()
-                .map(|i| i.to_string())
+                .map(ident_name)
                 .unwrap_or_else(|| field.declaration_index.to_string())
         })
         .collect::<Vec<String>>();
@@ -29,14 +30,14 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
     let field_count = field_idents.len();
     let field_indices = (0..field_count).collect::<Vec<usize>>();
 
-    let hash_fn = reflect_struct
+    let hash_fn = reflect_struct.get_hash_impl();
+    let partial_ord_fn = reflect_struct
         .meta()
         .attrs()
-        .get_hash_impl(bevy_reflect_path);
+        .get_partial_ord_impl(bevy_reflect_path);
     let debug_fn = reflect_struct.meta().attrs().get_debug_impl();
-    let partial_eq_fn = reflect_struct.meta()
-        .attrs()
-        .get_partial_eq_impl(bevy_reflect_path)
+    let partial_eq_fn = reflect_struct
+        .get_partial_eq_impl(false)
         .unwrap_or_else(|| {
             quote! {
                 fn reflect_partial_eq(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<bool> {
@@ -45,6 +46,12 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
             }
         });
 
+    let meta = reflect_struct.meta();
+    let hash_fn = meta.get_auto_impl(AutoTrait::Hash, hash_fn);
+    let partial_eq_fn = meta.get_auto_impl(AutoTrait::PartialEq, Some(partial_eq_fn));
+    let partial_ord_fn = meta.get_auto_impl(AutoTrait::PartialOrd, partial_ord_fn);
+    let debug_fn = meta.get_auto_impl(AutoTrait::Debug, debug_fn);
+
     let where_clause_options = reflect_struct.where_clause_options();
     let typed_impl = impl_typed(
         reflect_struct.meta(),
@@ -64,6 +71,9 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
 
     let where_reflect_clause = where_clause_options.extend_where_clause(where_clause);
 
+    let pod_impl = reflect_struct.get_pod_impl(&where_clause_options);
+    let pod_apply = reflect_struct.get_pod_apply();
+
     quote! {
         #get_type_registration_impl
 
@@ -71,6 +81,8 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
 
         #type_path_impl
 
+        #pod_impl
+
         impl #impl_generics #bevy_reflect_path::Struct for #struct_path #ty_generics #where_reflect_clause {
             fn field(&self, name: &str) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
                 match name {
@@ -121,6 +133,10 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
                 #(dynamic.insert_boxed(#field_names, #bevy_reflect_path::Reflect::clone_value(&self.#field_idents));)*
                 dynamic
             }
+
+            fn clone_into_dynamic(&self, target: &mut #bevy_reflect_path::DynamicStruct) {
+                #bevy_reflect_path::struct_clone_into_dynamic(self, target);
+            }
         }
 
         impl #impl_generics #bevy_reflect_path::Reflect for #struct_path #ty_generics #where_reflect_clause {
@@ -172,6 +188,7 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
 
             #[inline]
             fn try_apply(&mut self, value: &dyn #bevy_reflect_path::Reflect) -> #FQResult<(), #bevy_reflect_path::ApplyError> {
+                #pod_apply
                 if let #bevy_reflect_path::ReflectRef::Struct(struct_value) = #bevy_reflect_path::Reflect::reflect_ref(value) {
                     for (i, value) in ::core::iter::Iterator::enumerate(#bevy_reflect_path::Struct::iter_fields(struct_value)) {
                         let name = #bevy_reflect_path::Struct::name_at(struct_value, i).unwrap();
@@ -210,6 +227,8 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> proc_macro2::TokenS
 
             #partial_eq_fn
 
+            #partial_ord_fn
+
             #debug_fn
         }
     }
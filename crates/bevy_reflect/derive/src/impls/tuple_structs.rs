@@ -1,3 +1,4 @@
+use crate::container_attributes::AutoTrait;
 use crate::impls::{impl_type_path, impl_typed};
 use crate::ReflectStruct;
 use bevy_macro_utils::fq_std::{FQAny, FQBox, FQDefault, FQOption, FQResult};
@@ -21,15 +22,14 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::
     let where_clause_options = reflect_struct.where_clause_options();
     let get_type_registration_impl = reflect_struct.get_type_registration(&where_clause_options);
 
-    let hash_fn = reflect_struct
+    let hash_fn = reflect_struct.get_hash_impl();
+    let partial_ord_fn = reflect_struct
         .meta()
         .attrs()
-        .get_hash_impl(bevy_reflect_path);
+        .get_partial_ord_impl(bevy_reflect_path);
     let debug_fn = reflect_struct.meta().attrs().get_debug_impl();
     let partial_eq_fn = reflect_struct
-        .meta()
-        .attrs()
-        .get_partial_eq_impl(bevy_reflect_path)
+        .get_partial_eq_impl(true)
         .unwrap_or_else(|| {
             quote! {
                 fn reflect_partial_eq(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<bool> {
@@ -38,6 +38,12 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::
             }
         });
 
+    let meta = reflect_struct.meta();
+    let hash_fn = meta.get_auto_impl(AutoTrait::Hash, hash_fn);
+    let partial_eq_fn = meta.get_auto_impl(AutoTrait::PartialEq, Some(partial_eq_fn));
+    let partial_ord_fn = meta.get_auto_impl(AutoTrait::PartialOrd, partial_ord_fn);
+    let debug_fn = meta.get_auto_impl(AutoTrait::Debug, debug_fn);
+
     let typed_impl = impl_typed(
         reflect_struct.meta(),
         &where_clause_options,
@@ -54,6 +60,9 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::
 
     let where_reflect_clause = where_clause_options.extend_where_clause(where_clause);
 
+    let pod_impl = reflect_struct.get_pod_impl(&where_clause_options);
+    let pod_apply = reflect_struct.get_pod_apply();
+
     quote! {
         #get_type_registration_impl
 
@@ -61,6 +70,8 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::
 
         #type_path_impl
 
+        #pod_impl
+
         impl #impl_generics #bevy_reflect_path::TupleStruct for #struct_path #ty_generics #where_reflect_clause {
             fn field(&self, index: usize) -> #FQOption<&dyn #bevy_reflect_path::Reflect> {
                 match index {
@@ -141,6 +152,7 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::
 
             #[inline]
             fn try_apply(&mut self, value: &dyn #bevy_reflect_path::Reflect) -> #FQResult<(), #bevy_reflect_path::ApplyError> {
+                #pod_apply
                 if let #bevy_reflect_path::ReflectRef::TupleStruct(struct_value) = #bevy_reflect_path::Reflect::reflect_ref(value) {
                     for (i, value) in ::core::iter::Iterator::enumerate(#bevy_reflect_path::TupleStruct::iter_fields(struct_value)) {
                         if let #FQOption::Some(v) = #bevy_reflect_path::TupleStruct::field_mut(self, i) {
@@ -178,6 +190,8 @@ pub(crate) fn impl_tuple_struct(reflect_struct: &ReflectStruct) -> proc_macro2::
 
             #partial_eq_fn
 
+            #partial_ord_fn
+
             #debug_fn
         }
     }
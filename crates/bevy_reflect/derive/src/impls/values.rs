@@ -1,3 +1,4 @@
+use crate::container_attributes::AutoTrait;
 use crate::impls::{impl_type_path, impl_typed};
 use crate::utility::WhereClauseOptions;
 use crate::ReflectMeta;
@@ -10,9 +11,18 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     let type_path = meta.type_path();
 
     let hash_fn = meta.attrs().get_hash_impl(bevy_reflect_path);
-    let partial_eq_fn = meta.attrs().get_partial_eq_impl(bevy_reflect_path);
+    let partial_eq_fn = match meta.transparent_field() {
+        Some(_) if !meta.attrs().has_custom_partial_eq() => impl_transparent_partial_eq(meta),
+        _ => meta.attrs().get_partial_eq_impl(bevy_reflect_path),
+    };
+    let partial_ord_fn = meta.attrs().get_partial_ord_impl(bevy_reflect_path);
     let debug_fn = meta.attrs().get_debug_impl();
 
+    let hash_fn = meta.get_auto_impl(AutoTrait::Hash, hash_fn);
+    let partial_eq_fn = meta.get_auto_impl(AutoTrait::PartialEq, partial_eq_fn);
+    let partial_ord_fn = meta.get_auto_impl(AutoTrait::PartialOrd, partial_ord_fn);
+    let debug_fn = meta.get_auto_impl(AutoTrait::Debug, debug_fn);
+
     #[cfg(feature = "documentation")]
     let with_docs = {
         let doc = quote::ToTokens::to_token_stream(meta.doc());
@@ -21,12 +31,14 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     #[cfg(not(feature = "documentation"))]
     let with_docs: Option<proc_macro2::TokenStream> = None;
 
+    let with_opaque = meta.attrs().opaque().map(|_| quote!(.with_opaque()));
+
     let where_clause_options = WhereClauseOptions::new(meta);
     let typed_impl = impl_typed(
         meta,
         &where_clause_options,
         quote! {
-            let info = #bevy_reflect_path::ValueInfo::new::<Self>() #with_docs;
+            let info = #bevy_reflect_path::ValueInfo::new::<Self>() #with_docs #with_opaque;
             #bevy_reflect_path::TypeInfo::Value(info)
         },
     );
@@ -37,6 +49,33 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> proc_macro2::TokenStream {
     let where_reflect_clause = where_clause_options.extend_where_clause(where_clause);
     let get_type_registration_impl = meta.get_type_registration(&where_clause_options);
 
+    let transparent_impl = meta.transparent_field().map(|inner| {
+        quote! {
+            impl #impl_generics #bevy_reflect_path::TransparentValue for #type_path #ty_generics #where_reflect_clause {
+                type Inner = #inner;
+
+                #[inline]
+                fn inner(&self) -> &Self::Inner {
+                    &self.0
+                }
+
+                #[inline]
+                fn from_inner(inner: Self::Inner) -> Self {
+                    Self(inner)
+                }
+            }
+        }
+    });
+
+    // Transparent values may also be applied from their inner value
+    let apply_inner = meta.transparent_field().map(|inner| {
+        quote! {
+            else if <dyn #FQAny>::is::<#inner>(any) {
+                return #bevy_reflect_path::Reflect::try_apply(&mut self.0, value);
+            }
+        }
+    });
+
     quote! {
         #get_type_registration_impl
 
@@ -44,6 +83,8 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 
         #typed_impl
 
+        #transparent_impl
+
         impl #impl_generics #bevy_reflect_path::Reflect for #type_path #ty_generics #where_reflect_clause  {
             #[inline]
             fn get_represented_type_info(&self) -> #FQOption<&'static #bevy_reflect_path::TypeInfo> {
@@ -90,7 +131,7 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> proc_macro2::TokenStream {
                 let any = #bevy_reflect_path::Reflect::as_any(value);
                 if let #FQOption::Some(value) = <dyn #FQAny>::downcast_ref::<Self>(any) {
                     *self = #FQClone::clone(value);
-                } else {
+                } #apply_inner else {
                     return #FQResult::Err(
                         #bevy_reflect_path::ApplyError::MismatchedTypes {
                             from_type: ::core::convert::Into::into(#bevy_reflect_path::DynamicTypePath::reflect_type_path(value)),
@@ -131,7 +172,32 @@ pub(crate) fn impl_value(meta: &ReflectMeta) -> proc_macro2::TokenStream {
 
             #partial_eq_fn
 
+            #partial_ord_fn
+
             #debug_fn
         }
     }
 }
+
+/// Generates `Reflect::reflect_partial_eq` for a `#[reflect_value(transparent)]` type,
+/// which compares against both the wrapper type and its inner value.
+fn impl_transparent_partial_eq(meta: &ReflectMeta) -> Option<proc_macro2::TokenStream> {
+    let bevy_reflect_path = meta.bevy_reflect_path();
+
+    let compare_self = if meta.attrs().has_partial_eq() {
+        quote!(#FQOption::Some(::core::cmp::PartialEq::eq(self, value)))
+    } else {
+        quote!(#bevy_reflect_path::Reflect::reflect_partial_eq(&self.0, &value.0))
+    };
+
+    Some(quote! {
+        fn reflect_partial_eq(&self, value: &dyn #bevy_reflect_path::Reflect) -> #FQOption<bool> {
+            let any = <dyn #bevy_reflect_path::Reflect>::as_any(value);
+            if let #FQOption::Some(value) = <dyn #FQAny>::downcast_ref::<Self>(any) {
+                #compare_self
+            } else {
+                #bevy_reflect_path::Reflect::reflect_partial_eq(&self.0, value)
+            }
+        }
+    })
+}
@@ -23,6 +23,7 @@ mod derive_data;
 mod documentation;
 mod enum_utility;
 mod field_attributes;
+mod field_paths;
 mod from_reflect;
 mod impls;
 mod reflect_value;
@@ -62,6 +63,19 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
         Err(err) => return err.into_compile_error().into(),
     };
 
+    let field_paths_impl = match &derive_data {
+        ReflectDerive::Struct(struct_data)
+            if struct_data.meta().attrs().generate_paths().is_some() =>
+        {
+            Some(field_paths::impl_field_paths(
+                struct_data,
+                &ast.ident,
+                &ast.vis,
+            ))
+        }
+        _ => None,
+    };
+
     let (reflect_impls, from_reflect_impl) = match derive_data {
         ReflectDerive::Struct(struct_data) | ReflectDerive::UnitStruct(struct_data) => (
             impls::impl_struct(&struct_data),
@@ -102,6 +116,8 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
             #reflect_impls
             #from_reflect_impl
         };
+
+        #field_paths_impl
     })
 }
 
@@ -113,6 +129,16 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 ///
 /// See the [`FromReflect`] derive macro for more information on how to customize the `FromReflect` implementation.
 ///
+/// # Lifetimes
+///
+/// Reflected types must be `'static`, so the generated implementations require
+/// every lifetime parameter of the type to be `'static` as well.
+/// Lifetimes are omitted from the type's path (i.e. `Foo<'a, T>` has the path `my_crate::Foo<T>`).
+///
+/// Since a reflected field could then only ever borrow `'static` data,
+/// fields that borrow for one of the type's lifetimes are rejected and must be ignored
+/// with `#[reflect(ignore)]` (such as a `PhantomData<&'a ()>` marker).
+///
 /// # Container Attributes
 ///
 /// This macro comes with some helper attributes that can be added to the container item
@@ -154,6 +180,11 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 ///   A custom implementation may be provided using `#[reflect(PartialEq(my_partial_eq_func))]` where
 ///   `my_partial_eq_func` is the path to a function matching the signature:
 ///   `(&self, value: &dyn #bevy_reflect_path::Reflect) -> bool`.
+/// * `#[reflect(PartialOrd)]` will implement `Reflect::reflect_partial_cmp` using
+///   the type's [`PartialOrd`] implementation, returning `None` for values of other types.
+///   A custom implementation may be provided using `#[reflect(PartialOrd(my_partial_cmp_func))]` where
+///   `my_partial_cmp_func` is the path to a function matching the signature:
+///   `(&self, value: &dyn #bevy_reflect_path::Reflect) -> Option<::core::cmp::Ordering>`.
 /// * `#[reflect(Hash)]` will force the implementation of `Reflect::reflect_hash` to rely on
 ///   the type's [`Hash`] implementation.
 ///   A custom implementation may be provided using `#[reflect(Hash(my_hash_func))]` where
@@ -176,6 +207,37 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// If planning to serialize this type using the reflection serializers,
 /// then the `Serialize` and `Deserialize` traits will need to be implemented and registered as well.
 ///
+/// ## `#[reflect_value(transparent)]`
+///
+/// For single-field tuple structs, the `transparent` attribute may be used to
+/// reflect the type as a value that wraps its inner field.
+///
+/// The type is still reflected as a value with its own type identity,
+/// but it is serialized as its inner value and can be created from either itself
+/// or its inner value via `FromReflect`.
+/// Similarly, `Reflect::reflect_partial_eq` will compare against both forms.
+///
+/// This also implements `TransparentValue` and registers `ReflectTransparent`,
+/// which gives access to the inner type.
+///
+/// ```ignore (bevy_reflect is not accessible from this crate)
+/// #[derive(Reflect, Clone)]
+/// #[reflect_value(transparent)]
+/// struct Meters(f32);
+/// ```
+///
+/// ## `#[reflect_value(opaque)]`
+///
+/// This attribute marks a value type as intentionally supporting only identity-level reflection.
+/// The type can still be downcast, cloned, and applied to itself, but it cannot be hashed, compared, or serialized.
+///
+/// This is reflected in `ValueInfo::is_opaque`.
+/// The default `Reflect::debug` output for such types is `<type_path (opaque)>`,
+/// and the reflection (de)serializers report an error suggesting to register `ReflectSerialize`
+/// (or `ReflectDeserialize`) for the type.
+///
+/// This attribute cannot be combined with `transparent`, `Hash`, or `PartialEq`.
+///
 /// ## `#[reflect(from_reflect = false)]`
 ///
 /// This attribute will opt-out of the default `FromReflect` implementation.
@@ -274,6 +336,105 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// // {/* ... */}
 /// ```
 ///
+/// ## `#[reflect(generate_paths)]`
+///
+/// This attribute generates typed reflection paths for the fields of a struct,
+/// so that renaming a field results in a compile error rather than a broken path string.
+///
+/// For a struct `MyStruct`, this generates:
+/// * a `my_struct_fields` module with an `UPPERCASE` constant holding the path of each reflected field,
+/// * a `MyStruct::path_foo()` function for each reflected field `foo`, returning the same path,
+/// * a `MyStruct::fields()` function returning an accessor for each reflected field,
+///   which produces a typed `FieldPath`.
+///
+/// Ideally the constants would live at `MyStruct::fields::FOO`,
+/// but Rust does not allow modules (or inherent associated types) to be nested inside a type,
+/// so they are placed in a dedicated module next to the struct instead.
+/// Ignored fields are skipped.
+/// Paths always match the field's identifier, with any `r#` prefix removed,
+/// since fields cannot be renamed for reflection.
+///
+/// Paths into nested types can be composed by calling `fields()` on a `FieldPath`,
+/// provided the field's type also uses this attribute.
+/// Nested paths are built at runtime, when the accessors are called.
+///
+/// This attribute is only supported on non-generic structs with named fields.
+///
+/// ### Example
+///
+/// ```ignore (bevy_reflect is not accessible from this crate)
+/// #[derive(Reflect)]
+/// #[reflect(generate_paths)]
+/// struct Transform {
+///   scale: f32,
+/// }
+///
+/// #[derive(Reflect)]
+/// #[reflect(generate_paths)]
+/// struct Player {
+///   transform: Transform,
+/// }
+///
+/// assert_eq!("transform", player_fields::TRANSFORM);
+/// assert_eq!("transform", Player::path_transform());
+///
+/// let path = Player::fields().transform().fields().scale();
+/// assert_eq!("transform.scale", path.as_str());
+/// ```
+///
+/// ## `#[reflect(field_offsets)]`
+///
+/// This attribute stores the byte offset of each reflected field on its `NamedField` or `UnnamedField` info,
+/// computed with [`core::mem::offset_of`].
+/// Offsets are available via `field.offset()` and are used by `Struct::field_ptr`
+/// and `TupleStruct::field_ptr` to access fields through a pointer to the struct.
+///
+/// Offsets are correct for any `repr`, but may change between compilations
+/// for types without a stable layout, so they should not be persisted.
+/// Ignored fields have no offset.
+///
+/// This attribute is only supported on structs and tuple structs.
+/// The generated code contains `unsafe`, so it cannot be used in crates that forbid `unsafe_code`.
+///
+/// ## `#[reflect(auto)]`
+///
+/// This attribute detects whether the type implements [`Default`], [`Debug`], [`PartialEq`],
+/// [`PartialOrd`], or [`Hash`], and uses those implementations as if they had been registered
+/// with the [special identifiers](#special-identifiers) (i.e. `#[reflect(Debug, PartialEq)]`).
+/// Implementations that are not found fall back to the usual reflection-based behavior,
+/// and traits that are registered explicitly are left as they are.
+///
+/// If the type implements [`Default`], `ReflectDefault` is registered as well.
+/// Unlike `#[reflect(Default)]`, this does not affect the `FromReflect` derive.
+///
+/// Detection happens on the concrete type, so this attribute cannot be used on generic types,
+/// whose trait implementations may depend on their type parameters.
+/// Such types must register the traits explicitly instead.
+///
+/// Note that a detected `Hash` or `PartialEq` implementation is used as a whole,
+/// ignoring any `#[reflect(skip_hash)]` or `#[reflect(skip_partial_eq)]` fields.
+///
+/// ### Example
+///
+/// ```ignore (bevy_reflect is not accessible from this crate)
+/// #[derive(Reflect, Default, Debug, PartialEq)]
+/// #[reflect(auto)]
+/// struct Foo {
+///   value: i32,
+/// }
+/// ```
+///
+/// ## `#[reflect(pod)]`
+///
+/// This attribute marks a struct as plain-old-data, implementing `PodStruct` and registering `ReflectPod`.
+/// When such a struct is applied with a value of its own type, `Reflect::try_apply` assigns
+/// every field at once instead of recursively applying each field.
+/// Values of any other type (such as dynamic types) are still applied field by field.
+///
+/// Every field must be a primitive, or an array or tuple of primitives,
+/// and no field may be ignored with `#[reflect(ignore)]`.
+/// This attribute is only supported on structs and tuple structs.
+///
 /// ## `#[reflect(@...)]`
 ///
 /// This attribute can be used to register custom attributes to the type's `TypeInfo`.
@@ -326,6 +487,50 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 ///
 /// What this does is register the `SerializationData` type within the `GetTypeRegistration` implementation,
 /// which will be used by the reflection serializers to determine whether or not the field is serializable.
+/// Note that if the type also registers `ReflectSerialize`, its serde implementation takes precedence
+/// and this attribute has no effect on serialization.
+/// `bevy_reflect::serde::verify_serialization_consistency` can be used to detect such types.
+///
+/// ## `#[reflect(index = N)]`
+///
+/// This attribute assigns an explicit stable index to a field of a named struct.
+///
+/// Positional (non-self-describing) serializers, such as binary formats, write fields in order of their stable index
+/// rather than their declaration order.
+/// This allows fields to be reordered in source code without breaking previously serialized data.
+/// Human-readable formats are unaffected.
+///
+/// Stable indices must be unique, but may be sparse.
+/// Fields without an explicit index are assigned one in declaration order, starting after the highest explicit index.
+///
+/// This attribute cannot be used on tuple structs, enum variants, or ignored fields.
+///
+/// ## `#[reflect(skip_hash)]` and `#[reflect(skip_partial_eq)]`
+///
+/// These attributes exclude a field of a struct or tuple struct from `Reflect::reflect_hash`
+/// and `Reflect::reflect_partial_eq`, respectively.
+/// The field is still reflected and serialized as normal.
+///
+/// When a field is skipped, the derive generates a field-wise implementation
+/// rather than delegating to the type's `Hash` or `PartialEq` implementation.
+///
+/// `skip_hash` requires `Hash` to be registered, such as with `#[reflect(Hash)]`.
+/// Neither attribute can be combined with a custom function, such as `#[reflect(Hash(custom_hash_fn))]`,
+/// nor used on ignored fields or the fields of enum variants.
+///
+/// These flags are also available on the field's `NamedField` or `UnnamedField` info.
+///
+/// ## `#[reflect(redact)]`
+///
+/// This attribute marks a field of a struct or tuple struct as containing sensitive data.
+/// The default `Reflect::debug` output prints `<redacted>` in place of the field's value,
+/// and the field is marked as redacted on its `NamedField` or `UnnamedField` info.
+///
+/// This also registers the `RedactionData` type within the `GetTypeRegistration` implementation.
+/// The reflection serializers use it to either replace the field with a placeholder or skip it entirely,
+/// as configured by `RedactionMode`.
+/// When deserializing, the field is always populated with its default value,
+/// so the field's type must implement `Default` unless `#[reflect(default = "...")]` is given.
 ///
 /// ## `#[reflect(@...)]`
 ///
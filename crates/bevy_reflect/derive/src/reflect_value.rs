@@ -58,6 +58,12 @@ impl ReflectValueDef {
             traits = Some({
                 let mut attrs = ContainerAttributes::default();
                 attrs.parse_terminated(&content, trait_)?;
+                if let Some(span) = attrs.transparent() {
+                    return Err(syn::Error::new(
+                        span,
+                        "`transparent` can only be used when deriving `Reflect`",
+                    ));
+                }
                 attrs
             });
         }
@@ -1,8 +1,10 @@
 //! Contains code related specifically to Bevy's type registration.
 
+use crate::container_attributes::REFLECT_DEFAULT;
 use crate::derive_data::ReflectMeta;
-use crate::serialization::SerializationDataDef;
+use crate::serialization::{RedactionDataDef, SerializationDataDef};
 use crate::utility::WhereClauseOptions;
+use bevy_macro_utils::fq_std::FQOption;
 use quote::quote;
 use syn::Type;
 
@@ -12,6 +14,7 @@ pub(crate) fn impl_get_type_registration<'a>(
     meta: &ReflectMeta,
     where_clause_options: &WhereClauseOptions,
     serialization_data: Option<&SerializationDataDef>,
+    redaction_data: Option<&RedactionDataDef>,
     type_dependencies: Option<impl Iterator<Item = &'a Type>>,
 ) -> proc_macro2::TokenStream {
     let type_path = meta.type_path();
@@ -38,6 +41,32 @@ pub(crate) fn impl_get_type_registration<'a>(
         None
     };
 
+    let auto_data = meta
+        .attrs()
+        .auto()
+        .filter(|_| !meta.attrs().contains(REFLECT_DEFAULT))
+        .map(|_| {
+            quote! {
+                #[allow(unused_imports)]
+                use #bevy_reflect_path::__macro_exports::auto::*;
+                if let #FQOption::Some(data) = (&&AutoImpl::<Self>::new()).default_data() {
+                    registration.insert::<#bevy_reflect_path::std_traits::ReflectDefault>(data);
+                }
+            }
+        });
+
+    let pod_data = meta.attrs().pod().map(|_| {
+        quote! {
+            registration.insert::<#bevy_reflect_path::ReflectPod>(#bevy_reflect_path::FromType::<Self>::from_type());
+        }
+    });
+
+    let transparent_data = meta.transparent_field().map(|_| {
+        quote! {
+            registration.insert::<#bevy_reflect_path::ReflectTransparent>(#bevy_reflect_path::FromType::<Self>::from_type());
+        }
+    });
+
     let serialization_data = serialization_data.map(|data| {
         let serialization_data = data.as_serialization_data(bevy_reflect_path);
         quote! {
@@ -45,6 +74,13 @@ pub(crate) fn impl_get_type_registration<'a>(
         }
     });
 
+    let redaction_data = redaction_data.map(|data| {
+        let redaction_data = data.as_redaction_data(bevy_reflect_path);
+        quote! {
+            registration.insert::<#bevy_reflect_path::serde::RedactionData>(#redaction_data);
+        }
+    });
+
     quote! {
         #[allow(unused_mut)]
         impl #impl_generics #bevy_reflect_path::GetTypeRegistration for #type_path #ty_generics #where_reflect_clause {
@@ -53,6 +89,10 @@ pub(crate) fn impl_get_type_registration<'a>(
                 registration.insert::<#bevy_reflect_path::ReflectFromPtr>(#bevy_reflect_path::FromType::<Self>::from_type());
                 #from_reflect_data
                 #serialization_data
+                #redaction_data
+                #transparent_data
+                #pod_data
+                #auto_data
                 #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<Self>::from_type());)*
                 registration
             }
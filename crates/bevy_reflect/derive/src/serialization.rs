@@ -1,5 +1,5 @@
 use crate::derive_data::StructField;
-use crate::field_attributes::{DefaultBehavior, ReflectIgnoreBehavior};
+use crate::field_attributes::{DefaultBehavior, ReflectIgnoreBehavior, REDACT_ATTR};
 use bevy_macro_utils::fq_std::{FQBox, FQDefault};
 use quote::quote;
 use std::collections::HashMap;
@@ -65,6 +65,59 @@ impl SerializationDataDef {
     }
 }
 
+/// Collected redaction data used to generate a `RedactionData` type.
+pub(crate) struct RedactionDataDef {
+    /// Maps a field's _reflection_ index to its [`SkippedFieldDef`] if marked as `#[reflect(redact)]`.
+    ///
+    /// Redacted fields are never deserialized from their serialized value,
+    /// so they make use of the same default function as skipped fields.
+    redacted: HashMap<ReflectionIndex, SkippedFieldDef>,
+}
+
+impl RedactionDataDef {
+    /// Attempts to create a new `RedactionDataDef` from the given collection of fields.
+    ///
+    /// Returns `Ok(Some(data))` if there are any fields marked as redacted.
+    /// Otherwise, returns `Ok(None)`.
+    pub fn new(fields: &[StructField<'_>]) -> Result<Option<Self>, syn::Error> {
+        let mut redacted = HashMap::default();
+
+        for field in fields.iter().filter(|field| field.attrs.redact) {
+            let reflection_index = field.reflection_index.ok_or_else(|| {
+                syn::Error::new(
+                    field.data.span(),
+                    format!("`#[reflect({REDACT_ATTR})]` cannot be used on ignored fields"),
+                )
+            })?;
+            redacted.insert(reflection_index, SkippedFieldDef::new(field)?);
+        }
+
+        if redacted.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(Self { redacted }))
+        }
+    }
+
+    /// Returns a `TokenStream` containing an initialized `RedactionData` type.
+    pub fn as_redaction_data(&self, bevy_reflect_path: &Path) -> proc_macro2::TokenStream {
+        let fields =
+            self.redacted
+                .iter()
+                .map(|(reflection_index, SkippedFieldDef { default_fn })| {
+                    quote! {(
+                        #reflection_index,
+                        #bevy_reflect_path::serde::SkippedField::new(#default_fn)
+                    )}
+                });
+        quote! {
+            #bevy_reflect_path::serde::RedactionData::new(
+                ::core::iter::IntoIterator::into_iter([#(#fields),*])
+            )
+        }
+    }
+}
+
 /// Collected field data used to generate a `SkippedField` type.
 pub(crate) struct SkippedFieldDef {
     /// The default function for this field.
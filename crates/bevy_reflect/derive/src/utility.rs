@@ -5,8 +5,9 @@ use bevy_macro_utils::{
     fq_std::{FQAny, FQOption, FQSend, FQSync},
     BevyManifest,
 };
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream, Peek};
 use syn::punctuated::Punctuated;
 use syn::{spanned::Spanned, LitStr, Member, Path, Token, Type, WhereClause};
@@ -68,6 +69,69 @@ pub(crate) fn ident_or_index(ident: Option<&Ident>, index: usize) -> Member {
     )
 }
 
+/// Returns the name reflection uses for the given field or variant identifier.
+///
+/// Raw identifiers have their `r#` prefix stripped,
+/// so that `r#type` is reflected, serialized, and accessed by path as `type`.
+pub(crate) fn ident_name(ident: &Ident) -> String {
+    ident.unraw().to_string()
+}
+
+/// Creates an identifier for a binding introduced by generated code.
+///
+/// The identifier uses [`Span::mixed_site`] so that it can never shadow,
+/// or be shadowed by, user-defined fields and variants that happen to share its name.
+pub(crate) fn generated_ident(name: &str) -> Ident {
+    Ident::new(name, Span::mixed_site())
+}
+
+/// Returns true if the given type is a primitive, or an array or tuple made up of primitives.
+///
+/// This is a purely syntactic check, so type aliases of primitives are not recognized.
+pub(crate) fn is_pod_type(ty: &Type) -> bool {
+    const PRIMITIVES: [&str; 16] = [
+        "bool", "char", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+        "i128", "isize", "f32", "f64",
+    ];
+
+    match ty {
+        Type::Path(type_path) => {
+            type_path.qself.is_none()
+                && PRIMITIVES
+                    .iter()
+                    .any(|primitive| type_path.path.is_ident(primitive))
+        }
+        Type::Array(array) => is_pod_type(&array.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().all(is_pod_type),
+        Type::Paren(paren) => is_pod_type(&paren.elem),
+        Type::Group(group) => is_pod_type(&group.elem),
+        _ => false,
+    }
+}
+
+/// Returns the span of the first occurrence of any of the given lifetimes in `tokens`, if any.
+pub(crate) fn find_lifetime(tokens: TokenStream, lifetimes: &[&Ident]) -> Option<Span> {
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                if let Some(TokenTree::Ident(ident)) = tokens.peek() {
+                    if lifetimes.contains(&ident) {
+                        return Some(punct.span());
+                    }
+                }
+            }
+            TokenTree::Group(group) => {
+                if let Some(span) = find_lifetime(group.stream(), lifetimes) {
+                    return Some(span);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
 /// Options defining how to extend the `where` clause for reflection.
 pub(crate) struct WhereClauseOptions<'a, 'b> {
     meta: &'a ReflectMeta<'b>,
@@ -176,6 +240,12 @@ impl<'a, 'b> WhereClauseOptions<'a, 'b> {
     fn predicates(&self) -> Punctuated<TokenStream, Token![,]> {
         let mut predicates = Punctuated::new();
 
+        // Reflected types must be `'static`, so all lifetimes must be as well
+        predicates.extend(self.meta.type_path().generics().lifetimes().map(|param| {
+            let lifetime = &param.lifetime;
+            quote!(#lifetime: 'static)
+        }));
+
         if let Some(type_param_predicates) = self.type_param_predicates() {
             predicates.extend(type_param_predicates);
         }
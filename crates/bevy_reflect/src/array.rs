@@ -5,6 +5,7 @@ use crate::{
 use bevy_reflect_derive::impl_type_path;
 use std::{
     any::{Any, TypeId},
+    cmp::Ordering,
     fmt::{Debug, Formatter},
     hash::{Hash, Hasher},
 };
@@ -306,6 +307,10 @@ impl Reflect for DynamicArray {
         array_partial_eq(self, value)
     }
 
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        array_partial_cmp(self, value)
+    }
+
     fn debug(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "DynamicArray(")?;
         array_debug(self, f)?;
@@ -392,10 +397,14 @@ impl<'a> Iterator for ArrayIter<'a> {
 impl<'a> ExactSizeIterator for ArrayIter<'a> {}
 
 /// Returns the `u64` hash of the given [array](Array).
+///
+/// The hash only depends on the array's elements, not its concrete type,
+/// so that it agrees with [`array_partial_eq`] for arrays of different types,
+/// such as an array and its [`DynamicArray`] representation.
 #[inline]
 pub fn array_hash<A: Array>(array: &A) -> Option<u64> {
     let mut hasher = reflect_hasher();
-    Any::type_id(array).hash(&mut hasher);
+    ReflectKind::Array.hash(&mut hasher);
     array.len().hash(&mut hasher);
     for value in array.iter() {
         hasher.write_u64(value.reflect_hash()?);
@@ -478,6 +487,29 @@ pub fn array_partial_eq<A: Array>(array: &A, reflect: &dyn Reflect) -> Option<bo
     Some(true)
 }
 
+/// Lexicographically compares two [arrays](Array) (one concrete and one reflected).
+///
+/// Elements are compared pairwise with [`Reflect::reflect_partial_cmp`],
+/// returning the first result which is not [`Ordering::Equal`].
+///
+/// Returns [`None`] if `reflect` is not an array of the same length,
+/// or if any pair of elements could not be compared.
+#[inline]
+pub fn array_partial_cmp<A: Array>(array: &A, reflect: &dyn Reflect) -> Option<Ordering> {
+    match reflect.reflect_ref() {
+        ReflectRef::Array(reflect_array) if reflect_array.len() == array.len() => {
+            for (a, b) in array.iter().zip(reflect_array.iter()) {
+                match a.reflect_partial_cmp(b)? {
+                    Ordering::Equal => {}
+                    ordering => return Some(ordering),
+                }
+            }
+            Some(Ordering::Equal)
+        }
+        _ => None,
+    }
+}
+
 /// The default debug formatter for [`Array`] types.
 ///
 /// # Example
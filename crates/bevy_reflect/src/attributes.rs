@@ -1,3 +1,4 @@
+use crate as bevy_reflect;
 use crate::Reflect;
 use bevy_utils::TypeIdMap;
 use core::fmt::{Debug, Formatter};
@@ -116,6 +117,78 @@ impl Debug for CustomAttribute {
     }
 }
 
+/// A standard attribute describing the units of a numeric value, such as `"meters"` or `"seconds"`.
+///
+/// This is one of a small set of well-known attributes shipped by this crate
+/// so that different tools can agree on how to interpret common metadata.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, Typed, TypeInfo};
+/// # use bevy_reflect::attributes::Units;
+/// #[derive(Reflect)]
+/// struct Player {
+///     #[reflect(@Units("meters"))]
+///     height: f32,
+/// }
+///
+/// let TypeInfo::Struct(info) = Player::type_info() else {
+///     panic!("expected struct info");
+/// };
+///
+/// let units = info.field("height").unwrap().get_attribute::<Units>().unwrap();
+/// assert_eq!("meters", units.0);
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Units(pub &'static str);
+
+/// A standard attribute describing the increment by which a numeric value should be adjusted,
+/// such as by a slider or drag control.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct Step(pub f64);
+
+/// A standard attribute describing the inclusive range of valid values for a numeric value.
+///
+/// When constructed from literals in a `#[reflect(@...)]` attribute,
+/// the derive macro will reject ranges where `min` is greater than `max`.
+///
+/// ```compile_fail
+/// # use bevy_reflect::Reflect;
+/// use bevy_reflect::attributes::MinMax;
+///
+/// #[derive(Reflect)]
+/// struct Slider {
+///     #[reflect(@MinMax { min: 1.0, max: -1.0 })]
+///     value: f32,
+/// }
+/// ```
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct MinMax {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl MinMax {
+    /// Creates a new range from `min` to `max` (inclusive).
+    pub const fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns `true` if `value` is within this range.
+    pub fn contains(&self, value: f64) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+}
+
+/// A standard attribute providing a short description to display when hovering over a value.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tooltip(pub &'static str);
+
+/// A standard attribute indicating that a string value should be edited with a multiline text input.
+#[derive(Reflect, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Multiline;
+
 /// Implements methods for accessing custom attributes.
 ///
 /// Implements the following methods:
@@ -442,4 +515,43 @@ mod tests {
         let field = info.field("value").unwrap();
         assert!(field.get_attribute::<bool>().unwrap());
     }
+
+    #[test]
+    fn should_derive_standard_attributes() {
+        #[derive(Reflect)]
+        struct Settings {
+            #[reflect(@Units("meters"), @Step(0.5), @MinMax { min: -10.0, max: 10.0 })]
+            distance: f32,
+            #[reflect(@MinMax::new(0.0, 1.0))]
+            volume: f32,
+            #[reflect(@super::Tooltip("The name shown to other players"), @Multiline)]
+            description: String,
+        }
+
+        let TypeInfo::Struct(info) = Settings::type_info() else {
+            panic!("expected struct info");
+        };
+
+        let distance = info.field("distance").unwrap();
+        assert_eq!(&Units("meters"), distance.get_attribute::<Units>().unwrap());
+        assert_eq!(&Step(0.5), distance.get_attribute::<Step>().unwrap());
+        let range = distance.get_attribute::<MinMax>().unwrap();
+        assert_eq!(&MinMax::new(-10.0, 10.0), range);
+        assert!(range.contains(-10.0));
+        assert!(!range.contains(10.5));
+        assert!(!distance.has_attribute::<Multiline>());
+
+        let volume = info.field("volume").unwrap();
+        assert_eq!(
+            &MinMax::new(0.0, 1.0),
+            volume.get_attribute::<MinMax>().unwrap()
+        );
+
+        let description = info.field("description").unwrap();
+        assert_eq!(
+            &super::Tooltip("The name shown to other players"),
+            description.get_attribute::<super::Tooltip>().unwrap()
+        );
+        assert!(description.has_attribute::<Multiline>());
+    }
 }
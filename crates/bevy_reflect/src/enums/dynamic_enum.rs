@@ -1,9 +1,9 @@
 use bevy_reflect_derive::impl_type_path;
 
 use crate::{
-    self as bevy_reflect, enum_debug, enum_hash, enum_partial_eq, ApplyError, DynamicStruct,
-    DynamicTuple, Enum, Reflect, ReflectKind, ReflectMut, ReflectOwned, ReflectRef, Struct, Tuple,
-    TypeInfo, VariantFieldIter, VariantType,
+    self as bevy_reflect, enum_debug, enum_hash, enum_partial_eq, enum_try_apply_fields,
+    ApplyError, DynamicStruct, DynamicTuple, Enum, Reflect, ReflectKind, ReflectMut, ReflectOwned,
+    ReflectRef, Struct, Tuple, TypeInfo, VariantFieldIter, VariantType,
 };
 use std::any::Any;
 use std::fmt::Formatter;
@@ -45,6 +45,49 @@ impl From<()> for DynamicVariant {
     }
 }
 
+/// Creates a [`DynamicEnum`] using the same syntax as constructing the variant.
+///
+/// Unit, tuple, and struct variants are all supported,
+/// and field values can be any expression implementing [`Reflect`](crate::Reflect).
+/// Since each field may have a different type,
+/// integer literals without a suffix are inferred as `i32`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{dyn_enum, Enum, Reflect};
+/// #[derive(Reflect, Debug, PartialEq)]
+/// enum Shape {
+///     Point,
+///     Circle(f32),
+///     Rect { width: f32, height: f32 },
+/// }
+///
+/// let mut shape = Shape::Point;
+/// shape.apply(&dyn_enum!(Rect { width: 1.0f32, height: 2.0f32 }));
+/// assert_eq!(Shape::Rect { width: 1.0, height: 2.0 }, shape);
+///
+/// shape.apply(&dyn_enum!(Circle(3.0f32)));
+/// assert_eq!(Shape::Circle(3.0), shape);
+///
+/// assert_eq!("Point", dyn_enum!(Point).variant_name());
+/// ```
+#[macro_export]
+macro_rules! dyn_enum {
+    ($variant:ident) => {
+        $crate::DynamicEnum::new(::core::stringify!($variant), ())
+    };
+    ($variant:ident ( $($value:expr),* $(,)? )) => {
+        $crate::DynamicEnum::new(::core::stringify!($variant), $crate::dyn_tuple!($($value),*))
+    };
+    ($variant:ident { $($field:ident : $value:expr),* $(,)? }) => {
+        $crate::DynamicEnum::new(
+            ::core::stringify!($variant),
+            $crate::dyn_struct!($(::core::stringify!($field) => $value),*),
+        )
+    };
+}
+
 /// A dynamic representation of an enum.
 ///
 /// This allows for enums to be configured at runtime.
@@ -369,10 +412,7 @@ impl Reflect for DynamicEnum {
                 self.set_variant(value.variant_name(), dyn_variant);
             }
         } else {
-            return Err(ApplyError::MismatchedKinds {
-                from_kind: value.reflect_kind(),
-                to_kind: ReflectKind::Enum,
-            });
+            return enum_try_apply_fields(self, value);
         }
         Ok(())
     }
@@ -1,9 +1,14 @@
 use crate::attributes::{impl_custom_attribute_methods, CustomAttributes};
-use crate::{DynamicEnum, Reflect, TypePath, TypePathTable, VariantInfo, VariantType};
+use crate::std_traits::ReflectDefault;
+use crate::{
+    DynamicEnum, DynamicStruct, DynamicTuple, DynamicVariant, Reflect, TypeInfo, TypePath,
+    TypePathTable, TypeRegistry, VariantInfo, VariantType,
+};
 use bevy_utils::HashMap;
 use std::any::{Any, TypeId};
 use std::slice::Iter;
 use std::sync::Arc;
+use thiserror::Error;
 
 /// A trait used to power [enum-like] operations via [reflection].
 ///
@@ -130,6 +135,21 @@ pub trait Enum: Reflect {
     fn variant_path(&self) -> String {
         format!("{}::{}", self.reflect_type_path(), self.variant_name())
     }
+    /// The discriminant value of the current variant, if known.
+    ///
+    /// By default, this is read from the [`UnitVariantInfo`] of the represented type,
+    /// so it returns `None` for enums containing data-carrying variants.
+    ///
+    /// [`UnitVariantInfo`]: crate::UnitVariantInfo
+    fn discriminant(&self) -> Option<i128> {
+        match self.get_represented_type_info()? {
+            TypeInfo::Enum(info) => match info.variant(self.variant_name())? {
+                VariantInfo::Unit(variant) => variant.discriminant(),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
 }
 
 /// A container for compile-time enum info, used by [`TypeInfo`](crate::TypeInfo).
@@ -204,6 +224,17 @@ impl EnumInfo {
         self.variants.get(index)
     }
 
+    /// Get the unit variant with the given discriminant value.
+    ///
+    /// Returns `None` if no variant has this discriminant,
+    /// including when the enum contains data-carrying variants.
+    pub fn variant_with_discriminant(&self, discriminant: i128) -> Option<&VariantInfo> {
+        self.variants.iter().find(|variant| match variant {
+            VariantInfo::Unit(info) => info.discriminant() == Some(discriminant),
+            _ => false,
+        })
+    }
+
     /// Get the index of the variant with the given name.
     pub fn index_of(&self, name: &str) -> Option<usize> {
         self.variant_indices.get(name).copied()
@@ -231,6 +262,117 @@ impl EnumInfo {
         self.variants.len()
     }
 
+    /// Iterate over a template value for each variant of this enum.
+    ///
+    /// Each template is a [`DynamicEnum`] set to the corresponding variant,
+    /// with every field set to its default value and the represented type set to this enum.
+    /// Field defaults are generated using the [`ReflectDefault`] registered for the field's type.
+    ///
+    /// The iterator is lazy and yields one result per variant,
+    /// so a variant whose template cannot be built does not prevent the others from being listed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{Enum, Reflect, TypeInfo, Typed, TypeRegistry};
+    /// #[derive(Reflect)]
+    /// enum Shape {
+    ///     Point,
+    ///     Circle(f32),
+    ///     Rect { width: f32, height: f32 },
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::default();
+    /// registry.register::<Shape>();
+    ///
+    /// let TypeInfo::Enum(info) = Shape::type_info() else { unreachable!() };
+    /// let names: Vec<_> = info
+    ///     .iter_variant_templates(&registry)
+    ///     .map(|template| template.unwrap().variant_name().to_string())
+    ///     .collect();
+    /// assert_eq!(vec!["Point", "Circle", "Rect"], names);
+    /// ```
+    pub fn iter_variant_templates<'a>(
+        &'a self,
+        registry: &'a TypeRegistry,
+    ) -> impl Iterator<Item = Result<DynamicEnum, TemplateError>> + 'a {
+        self.variants
+            .iter()
+            .enumerate()
+            .map(move |(index, variant)| self.build_template(index, variant, registry))
+    }
+
+    /// Returns a template value for the variant with the given name.
+    ///
+    /// See [`EnumInfo::iter_variant_templates`] for details.
+    pub fn template_for(
+        &self,
+        name: &str,
+        registry: &TypeRegistry,
+    ) -> Result<DynamicEnum, TemplateError> {
+        let index = self
+            .index_of(name)
+            .ok_or_else(|| TemplateError::UnknownVariant {
+                enum_name: self.type_path().into(),
+                variant_name: name.into(),
+            })?;
+        self.build_template(index, &self.variants[index], registry)
+    }
+
+    fn build_template(
+        &self,
+        index: usize,
+        variant: &VariantInfo,
+        registry: &TypeRegistry,
+    ) -> Result<DynamicEnum, TemplateError> {
+        let represented_type = registry
+            .get(self.type_id)
+            .ok_or_else(|| TemplateError::NotRegistered {
+                type_path: self.type_path(),
+            })?
+            .type_info();
+
+        let default_for = |field: Box<str>, type_id: TypeId, field_type: &'static str| {
+            registry
+                .get_type_data::<ReflectDefault>(type_id)
+                .map(ReflectDefault::default)
+                .ok_or_else(|| TemplateError::MissingDefault {
+                    variant_name: variant.name(),
+                    field,
+                    field_type,
+                })
+        };
+
+        let dynamic_variant = match variant {
+            VariantInfo::Struct(info) => {
+                let mut dynamic_struct = DynamicStruct::default();
+                for field in info.iter() {
+                    let value =
+                        default_for(field.name().into(), field.type_id(), field.type_path())?;
+                    dynamic_struct.insert_boxed(field.name(), value);
+                }
+                DynamicVariant::Struct(dynamic_struct)
+            }
+            VariantInfo::Tuple(info) => {
+                let mut dynamic_tuple = DynamicTuple::default();
+                for field in info.iter() {
+                    let value = default_for(
+                        field.index().to_string().into(),
+                        field.type_id(),
+                        field.type_path(),
+                    )?;
+                    dynamic_tuple.insert_boxed(value);
+                }
+                DynamicVariant::Tuple(dynamic_tuple)
+            }
+            VariantInfo::Unit(_) => DynamicVariant::Unit,
+        };
+
+        let mut template = DynamicEnum::new_with_index(index, variant.name(), dynamic_variant);
+        template.set_represented_type(Some(represented_type));
+        Ok(template)
+    }
+
     /// A representation of the type path of the value.
     ///
     /// Provides dynamic access to all methods on [`TypePath`].
@@ -267,6 +409,32 @@ impl EnumInfo {
     impl_custom_attribute_methods!(self.custom_attributes, "enum");
 }
 
+/// An error that occurs when building a variant template with [`EnumInfo::template_for`]
+/// or [`EnumInfo::iter_variant_templates`].
+#[derive(Error, Debug)]
+pub enum TemplateError {
+    #[error("enum `{type_path}` is not registered in the type registry")]
+    /// The enum itself was not registered, so its [`TypeInfo`](crate::TypeInfo) could not be retrieved.
+    NotRegistered { type_path: &'static str },
+
+    #[error("variant with name `{variant_name}` does not exist on enum `{enum_name}`")]
+    /// The requested variant does not exist.
+    UnknownVariant {
+        enum_name: Box<str>,
+        variant_name: Box<str>,
+    },
+
+    #[error("field `{field}` of variant `{variant_name}` has type `{field_type}`, which does not register `ReflectDefault`")]
+    /// A field of the variant could not be defaulted.
+    ///
+    /// For tuple variants, `field` is the index of the field.
+    MissingDefault {
+        variant_name: &'static str,
+        field: Box<str>,
+        field_type: &'static str,
+    },
+}
+
 /// An iterator over the fields in the current enum variant.
 pub struct VariantFieldIter<'a> {
     container: &'a dyn Enum,
@@ -1,4 +1,7 @@
-use crate::{utility::reflect_hasher, Enum, Reflect, ReflectRef, VariantType};
+use crate::{
+    utility::reflect_hasher, ApplyError, DynamicEnum, DynamicVariant, Enum, Reflect,
+    ReflectFromReflect, ReflectKind, ReflectRef, TypeInfo, TypeRegistration, VariantType,
+};
 use std::fmt::Debug;
 use std::hash::{Hash, Hasher};
 
@@ -15,6 +18,70 @@ pub fn enum_hash<TEnum: Enum>(value: &TEnum) -> Option<u64> {
     Some(hasher.finish())
 }
 
+/// Applies a non-enum patch to the fields of the current variant of an [`Enum`].
+///
+/// This allows updating the payload of a variant without a full [`DynamicEnum`]:
+/// - A [struct] patch is applied by field name if the current variant is a struct variant.
+/// - A [tuple] or [tuple struct] patch is applied by field index if the current variant is a tuple variant.
+///
+/// Fields not present on the current variant are ignored.
+/// The variant itself is never changed.
+///
+/// # Errors
+///
+/// Returns [`ApplyError::VariantShapeMismatch`] if the shape of the patch does not match
+/// the current variant, and [`ApplyError::MismatchedKinds`] if the patch is not a struct or tuple.
+///
+/// [`DynamicEnum`]: crate::DynamicEnum
+/// [struct]: crate::Struct
+/// [tuple]: crate::Tuple
+/// [tuple struct]: crate::TupleStruct
+pub fn enum_try_apply_fields<TEnum: Enum + ?Sized>(
+    target: &mut TEnum,
+    patch: &dyn Reflect,
+) -> Result<(), ApplyError> {
+    let shape_mismatch = |target: &TEnum| ApplyError::VariantShapeMismatch {
+        expected_variant: target.variant_name().into(),
+        patch_kind: patch.reflect_kind(),
+    };
+
+    match (patch.reflect_ref(), target.variant_type()) {
+        (ReflectRef::Struct(patch), VariantType::Struct) => {
+            for (index, value) in patch.iter_fields().enumerate() {
+                let name = patch.name_at(index).unwrap();
+                if let Some(field) = target.field_mut(name) {
+                    field.try_apply(value)?;
+                }
+            }
+        }
+        (ReflectRef::Tuple(patch), VariantType::Tuple) => {
+            for (index, value) in patch.iter_fields().enumerate() {
+                if let Some(field) = target.field_at_mut(index) {
+                    field.try_apply(value)?;
+                }
+            }
+        }
+        (ReflectRef::TupleStruct(patch), VariantType::Tuple) => {
+            for (index, value) in patch.iter_fields().enumerate() {
+                if let Some(field) = target.field_at_mut(index) {
+                    field.try_apply(value)?;
+                }
+            }
+        }
+        (ReflectRef::Struct(_) | ReflectRef::Tuple(_) | ReflectRef::TupleStruct(_), _) => {
+            return Err(shape_mismatch(target));
+        }
+        _ => {
+            return Err(ApplyError::MismatchedKinds {
+                from_kind: patch.reflect_kind(),
+                to_kind: ReflectKind::Enum,
+            });
+        }
+    }
+
+    Ok(())
+}
+
 /// Compares an [`Enum`] with a [`Reflect`] value.
 ///
 /// Returns true if and only if all of the following are true:
@@ -117,3 +184,46 @@ pub fn enum_debug(dyn_enum: &dyn Enum, f: &mut std::fmt::Formatter<'_>) -> std::
         }
     }
 }
+
+/// Constructs the unit variant of the registered enum with the given discriminant value.
+///
+/// If the registration contains [`ReflectFromReflect`], the returned value is the concrete enum.
+/// Otherwise, it is a [`DynamicEnum`] representing that enum.
+///
+/// Returns `None` if the registered type is not an enum
+/// or has no variant with this discriminant (see [`EnumInfo::variant_with_discriminant`]).
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{enum_from_discriminant, GetTypeRegistration, Reflect};
+/// #[derive(Reflect, Debug, PartialEq)]
+/// enum Slot {
+///     Head = 1,
+///     Chest = 2,
+/// }
+///
+/// let registration = Slot::get_type_registration();
+/// let value = enum_from_discriminant(&registration, 2).unwrap();
+/// assert_eq!(Some(&Slot::Chest), value.downcast_ref::<Slot>());
+/// ```
+///
+/// [`EnumInfo::variant_with_discriminant`]: crate::EnumInfo::variant_with_discriminant
+pub fn enum_from_discriminant(
+    registration: &TypeRegistration,
+    discriminant: i128,
+) -> Option<Box<dyn Reflect>> {
+    let TypeInfo::Enum(info) = registration.type_info() else {
+        return None;
+    };
+
+    let variant = info.variant_with_discriminant(discriminant)?;
+    let index = info.index_of(variant.name())?;
+    let mut dynamic = DynamicEnum::new_with_index(index, variant.name(), DynamicVariant::Unit);
+    dynamic.set_represented_type(Some(registration.type_info()));
+
+    match registration.data::<ReflectFromReflect>() {
+        Some(reflect_from_reflect) => reflect_from_reflect.from_reflect(&dynamic),
+        None => Some(Box::new(dynamic)),
+    }
+}
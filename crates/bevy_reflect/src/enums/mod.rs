@@ -284,13 +284,13 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "called `Result::unwrap()` on an `Err` value: MismatchedKinds { from_kind: Tuple, to_kind: Enum }"
+        expected = "called `Result::unwrap()` on an `Err` value: MismatchedKinds { from_kind: List, to_kind: Enum }"
     )]
     fn applying_non_enum_should_panic() {
         let mut value = MyEnum::B(0, 0);
-        let mut dyn_tuple = DynamicTuple::default();
-        dyn_tuple.insert((123_usize, 321_i32));
-        value.apply(&dyn_tuple);
+        let mut dyn_list = DynamicList::default();
+        dyn_list.push(123_usize);
+        value.apply(&dyn_list);
     }
 
     #[test]
@@ -666,4 +666,281 @@ mod tests {
             "expected TestEnum::C{{value: 123}} != TestEnum::C2{{value: 1.23}}"
         );
     }
+
+    #[test]
+    fn should_build_variant_templates() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<MyEnum>();
+
+        let TypeInfo::Enum(info) = MyEnum::type_info() else {
+            panic!("expected enum info");
+        };
+
+        let templates = info
+            .iter_variant_templates(&registry)
+            .map(|template| MyEnum::from_reflect(&template.unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                MyEnum::A,
+                MyEnum::B(0, 0),
+                MyEnum::C {
+                    foo: 0.0,
+                    bar: false
+                }
+            ],
+            templates
+        );
+
+        let template = info.template_for("C", &registry).unwrap();
+        assert!(template
+            .get_represented_type_info()
+            .is_some_and(|info| info.is::<MyEnum>()));
+        assert!(matches!(
+            info.template_for("D", &registry),
+            Err(TemplateError::UnknownVariant { .. })
+        ));
+    }
+
+    #[test]
+    fn should_report_undefaultable_variant_templates() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct NoDefault(u8);
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Foo {
+            A,
+            B { value: NoDefault },
+            C(String),
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Foo>();
+
+        let TypeInfo::Enum(info) = Foo::type_info() else {
+            panic!("expected enum info");
+        };
+
+        let mut templates = info.iter_variant_templates(&registry);
+        assert_eq!(
+            Foo::A,
+            Foo::from_reflect(&templates.next().unwrap().unwrap()).unwrap()
+        );
+        assert!(matches!(
+            templates.next().unwrap(),
+            Err(TemplateError::MissingDefault {
+                variant_name: "B",
+                field,
+                ..
+            }) if &*field == "value"
+        ));
+        assert_eq!(
+            Foo::C(String::new()),
+            Foo::from_reflect(&templates.next().unwrap().unwrap()).unwrap()
+        );
+        assert!(templates.next().is_none());
+    }
+
+    #[test]
+    fn enum_should_apply_struct_patch_to_struct_variant() {
+        let mut value = MyEnum::C {
+            foo: 1.23,
+            bar: false,
+        };
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("bar", true);
+        value.apply(&patch);
+        assert_eq!(
+            MyEnum::C {
+                foo: 1.23,
+                bar: true
+            },
+            value
+        );
+
+        let mut value = DynamicEnum::from(value);
+        patch.insert("foo", 3.21_f32);
+        value.apply(&patch);
+        assert_eq!(
+            Some(true),
+            value.reflect_partial_eq(&MyEnum::C {
+                foo: 3.21,
+                bar: true
+            })
+        );
+    }
+
+    #[test]
+    fn enum_should_apply_tuple_patch_to_tuple_variant() {
+        let mut value = MyEnum::B(123, 321);
+
+        let mut patch = DynamicTuple::default();
+        patch.insert(456_usize);
+        value.apply(&patch);
+        assert_eq!(MyEnum::B(456, 321), value);
+
+        let mut patch = DynamicTupleStruct::default();
+        patch.insert(789_usize);
+        patch.insert(654_i32);
+        value.apply(&patch);
+        assert_eq!(MyEnum::B(789, 654), value);
+
+        let mut value = Some(1_u32);
+        value.apply(&(2_u32,));
+        assert_eq!(Some(2), value);
+    }
+
+    #[test]
+    fn enum_should_reject_mismatched_variant_patch() {
+        let mut value = MyEnum::B(123, 321);
+        let mut patch = DynamicStruct::default();
+        patch.insert("foo", 1.0_f32);
+
+        let result = value.try_apply(&patch);
+        assert!(
+            matches!(
+                &result,
+                Err(ApplyError::VariantShapeMismatch {
+                    expected_variant,
+                    patch_kind: ReflectKind::Struct,
+                }) if &**expected_variant == "B"
+            ),
+            "result was {result:?}"
+        );
+        assert_eq!(MyEnum::B(123, 321), value);
+
+        let mut value = MyEnum::C {
+            foo: 1.23,
+            bar: false,
+        };
+        let result = value.try_apply(&(1_usize, 2_i32));
+        assert!(
+            matches!(
+                result,
+                Err(ApplyError::VariantShapeMismatch {
+                    patch_kind: ReflectKind::Tuple,
+                    ..
+                })
+            ),
+            "result was {result:?}"
+        );
+    }
+
+    #[test]
+    fn enum_should_reject_field_patch_to_unit_variant() {
+        let mut value = MyEnum::A;
+
+        let result = value.try_apply(&DynamicStruct::default());
+        assert!(
+            matches!(
+                result,
+                Err(ApplyError::VariantShapeMismatch {
+                    patch_kind: ReflectKind::Struct,
+                    ..
+                })
+            ),
+            "result was {result:?}"
+        );
+
+        let result = value.try_apply(&DynamicTuple::default());
+        assert!(
+            matches!(
+                result,
+                Err(ApplyError::VariantShapeMismatch {
+                    patch_kind: ReflectKind::Tuple,
+                    ..
+                })
+            ),
+            "result was {result:?}"
+        );
+
+        let mut value = DynamicEnum::from(MyEnum::A);
+        let result = value.try_apply(&DynamicStruct::default());
+        assert!(
+            matches!(result, Err(ApplyError::VariantShapeMismatch { .. })),
+            "result was {result:?}"
+        );
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Slot {
+        Head = 1,
+        Chest = 2,
+        Legs = 10,
+        Feet,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Implicit {
+        A,
+        B,
+        C,
+    }
+
+    #[test]
+    fn should_read_discriminants() {
+        assert_eq!(Some(1), Slot::Head.discriminant());
+        assert_eq!(Some(2), Slot::Chest.discriminant());
+        assert_eq!(Some(10), Slot::Legs.discriminant());
+        assert_eq!(Some(11), Slot::Feet.discriminant());
+
+        assert_eq!(Some(0), Implicit::A.discriminant());
+        assert_eq!(Some(2), Implicit::C.discriminant());
+
+        let TypeInfo::Enum(info) = Slot::type_info() else {
+            panic!("expected enum info");
+        };
+        let VariantInfo::Unit(variant) = info.variant("Legs").unwrap() else {
+            panic!("expected unit variant");
+        };
+        assert_eq!(Some(10), variant.discriminant());
+
+        // Dynamic enums read the discriminant from their represented type
+        assert_eq!(Some(11), Slot::Feet.clone_dynamic().discriminant());
+        assert_eq!(None, DynamicEnum::new("Feet", ()).discriminant());
+    }
+
+    #[test]
+    fn should_find_variant_by_discriminant() {
+        let TypeInfo::Enum(info) = Slot::type_info() else {
+            panic!("expected enum info");
+        };
+
+        assert_eq!("Chest", info.variant_with_discriminant(2).unwrap().name());
+        assert_eq!("Feet", info.variant_with_discriminant(11).unwrap().name());
+        assert!(info.variant_with_discriminant(3).is_none());
+    }
+
+    #[test]
+    fn should_construct_from_discriminant() {
+        let registration = Slot::get_type_registration();
+        let value = enum_from_discriminant(&registration, 10).unwrap();
+        assert_eq!(Some(&Slot::Legs), value.downcast_ref::<Slot>());
+        assert!(enum_from_discriminant(&registration, 0).is_none());
+
+        // Without `ReflectFromReflect`, the value is dynamic
+        let registration = TypeRegistration::of::<Implicit>();
+        let value = enum_from_discriminant(&registration, 1).unwrap();
+        assert!(value.represents::<Implicit>());
+        assert_eq!(Some(true), value.reflect_partial_eq(&Implicit::B));
+
+        assert!(enum_from_discriminant(&u32::get_type_registration(), 1).is_none());
+    }
+
+    #[test]
+    fn should_not_report_discriminants_for_data_carrying_enums() {
+        let TypeInfo::Enum(info) = MyEnum::type_info() else {
+            panic!("expected enum info");
+        };
+        let VariantInfo::Unit(variant) = info.variant("A").unwrap() else {
+            panic!("expected unit variant");
+        };
+
+        assert_eq!(None, variant.discriminant());
+        assert_eq!(None, MyEnum::A.discriminant());
+        assert_eq!(None, MyEnum::B(1, 2).discriminant());
+        assert!(info.variant_with_discriminant(0).is_none());
+        assert!(enum_from_discriminant(&MyEnum::get_type_registration(), 0).is_none());
+    }
 }
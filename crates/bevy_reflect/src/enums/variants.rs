@@ -261,6 +261,7 @@ impl TupleVariantInfo {
 #[derive(Clone, Debug)]
 pub struct UnitVariantInfo {
     name: &'static str,
+    discriminant: Option<i128>,
     custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
@@ -271,6 +272,7 @@ impl UnitVariantInfo {
     pub fn new(name: &'static str) -> Self {
         Self {
             name,
+            discriminant: None,
             custom_attributes: Arc::new(CustomAttributes::default()),
             #[cfg(feature = "documentation")]
             docs: None,
@@ -291,11 +293,27 @@ impl UnitVariantInfo {
         }
     }
 
+    /// Sets the discriminant value of this variant.
+    pub fn with_discriminant(self, discriminant: i128) -> Self {
+        Self {
+            discriminant: Some(discriminant),
+            ..self
+        }
+    }
+
     /// The name of this variant.
     pub fn name(&self) -> &'static str {
         self.name
     }
 
+    /// The discriminant value of this variant, if known.
+    ///
+    /// This is only set by the derive macro for enums made up entirely of unit variants,
+    /// whether their discriminants are explicit (`A = 3`) or implicit.
+    pub fn discriminant(&self) -> Option<i128> {
+        self.discriminant
+    }
+
     /// The docstring of this variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
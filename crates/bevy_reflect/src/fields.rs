@@ -1,6 +1,8 @@
 use crate::attributes::{impl_custom_attribute_methods, CustomAttributes};
 use crate::{Reflect, TypePath, TypePathTable};
+use bevy_ptr::Ptr;
 use std::any::{Any, TypeId};
+use std::ptr::NonNull;
 use std::sync::Arc;
 
 /// The named field of a reflected struct.
@@ -9,6 +11,11 @@ pub struct NamedField {
     name: &'static str,
     type_path: TypePathTable,
     type_id: TypeId,
+    stable_index: Option<usize>,
+    offset: Option<usize>,
+    skip_hash: bool,
+    skip_partial_eq: bool,
+    redacted: bool,
     custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
@@ -21,6 +28,11 @@ impl NamedField {
             name,
             type_path: TypePathTable::of::<T>(),
             type_id: TypeId::of::<T>(),
+            stable_index: None,
+            offset: None,
+            skip_hash: false,
+            skip_partial_eq: false,
+            redacted: false,
             custom_attributes: Arc::new(CustomAttributes::default()),
             #[cfg(feature = "documentation")]
             docs: None,
@@ -41,11 +53,102 @@ impl NamedField {
         }
     }
 
+    /// Sets an explicit stable index for this field.
+    ///
+    /// This corresponds to the `#[reflect(index = N)]` attribute.
+    /// See [`StructInfo::stable_index_of`] for how stable indices are used.
+    ///
+    /// [`StructInfo::stable_index_of`]: crate::StructInfo::stable_index_of
+    pub fn with_stable_index(self, stable_index: usize) -> Self {
+        Self {
+            stable_index: Some(stable_index),
+            ..self
+        }
+    }
+
+    /// Sets the byte offset of this field within its containing type.
+    ///
+    /// This corresponds to the `#[reflect(field_offsets)]` attribute.
+    ///
+    /// # Safety
+    ///
+    /// `offset` must be the offset of this field within the type
+    /// whose [`TypeInfo`](crate::TypeInfo) contains this field,
+    /// as returned by [`core::mem::offset_of`].
+    /// It is used to access the field through a pointer to a value of that type.
+    #[allow(unsafe_code)]
+    pub unsafe fn with_offset(self, offset: usize) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    /// Marks this field as excluded from [`Reflect::reflect_hash`].
+    ///
+    /// This corresponds to the `#[reflect(skip_hash)]` attribute.
+    pub fn with_skip_hash(self) -> Self {
+        Self {
+            skip_hash: true,
+            ..self
+        }
+    }
+
+    /// Marks this field as excluded from [`Reflect::reflect_partial_eq`].
+    ///
+    /// This corresponds to the `#[reflect(skip_partial_eq)]` attribute.
+    pub fn with_skip_partial_eq(self) -> Self {
+        Self {
+            skip_partial_eq: true,
+            ..self
+        }
+    }
+
+    /// Marks this field as containing sensitive data.
+    ///
+    /// This corresponds to the `#[reflect(redact)]` attribute.
+    pub fn with_redacted(self) -> Self {
+        Self {
+            redacted: true,
+            ..self
+        }
+    }
+
     /// The name of the field.
     pub fn name(&self) -> &'static str {
         self.name
     }
 
+    /// The explicit stable index of this field, if any.
+    pub fn stable_index(&self) -> Option<usize> {
+        self.stable_index
+    }
+
+    /// The byte offset of this field within its containing type, if known.
+    ///
+    /// Offsets are only available for fields of types deriving `Reflect` with `#[reflect(field_offsets)]`.
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Returns true if this field is excluded from [`Reflect::reflect_hash`].
+    pub fn skip_hash(&self) -> bool {
+        self.skip_hash
+    }
+
+    /// Returns true if this field is excluded from [`Reflect::reflect_partial_eq`].
+    pub fn skip_partial_eq(&self) -> bool {
+        self.skip_partial_eq
+    }
+
+    /// Returns true if this field contains sensitive data.
+    ///
+    /// Redacted fields are masked in [`Reflect::debug`] output and by the reflection serializers.
+    /// Tools which display or edit reflected values should mask them as well.
+    pub fn is_redacted(&self) -> bool {
+        self.redacted
+    }
+
     /// A representation of the type path of the field.
     ///
     /// Provides dynamic access to all methods on [`TypePath`].
@@ -88,6 +191,10 @@ pub struct UnnamedField {
     index: usize,
     type_path: TypePathTable,
     type_id: TypeId,
+    offset: Option<usize>,
+    skip_hash: bool,
+    skip_partial_eq: bool,
+    redacted: bool,
     custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
@@ -99,6 +206,10 @@ impl UnnamedField {
             index,
             type_path: TypePathTable::of::<T>(),
             type_id: TypeId::of::<T>(),
+            offset: None,
+            skip_hash: false,
+            skip_partial_eq: false,
+            redacted: false,
             custom_attributes: Arc::new(CustomAttributes::default()),
             #[cfg(feature = "documentation")]
             docs: None,
@@ -119,11 +230,84 @@ impl UnnamedField {
         }
     }
 
+    /// Sets the byte offset of this field within its containing type.
+    ///
+    /// This corresponds to the `#[reflect(field_offsets)]` attribute.
+    ///
+    /// # Safety
+    ///
+    /// `offset` must be the offset of this field within the type
+    /// whose [`TypeInfo`](crate::TypeInfo) contains this field,
+    /// as returned by [`core::mem::offset_of`].
+    /// It is used to access the field through a pointer to a value of that type.
+    #[allow(unsafe_code)]
+    pub unsafe fn with_offset(self, offset: usize) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    /// Marks this field as excluded from [`Reflect::reflect_hash`].
+    ///
+    /// This corresponds to the `#[reflect(skip_hash)]` attribute.
+    pub fn with_skip_hash(self) -> Self {
+        Self {
+            skip_hash: true,
+            ..self
+        }
+    }
+
+    /// Marks this field as excluded from [`Reflect::reflect_partial_eq`].
+    ///
+    /// This corresponds to the `#[reflect(skip_partial_eq)]` attribute.
+    pub fn with_skip_partial_eq(self) -> Self {
+        Self {
+            skip_partial_eq: true,
+            ..self
+        }
+    }
+
+    /// Marks this field as containing sensitive data.
+    ///
+    /// This corresponds to the `#[reflect(redact)]` attribute.
+    pub fn with_redacted(self) -> Self {
+        Self {
+            redacted: true,
+            ..self
+        }
+    }
+
     /// Returns the index of the field.
     pub fn index(&self) -> usize {
         self.index
     }
 
+    /// The byte offset of this field within its containing type, if known.
+    ///
+    /// Offsets are only available for fields of types deriving `Reflect` with `#[reflect(field_offsets)]`.
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Returns true if this field is excluded from [`Reflect::reflect_hash`].
+    pub fn skip_hash(&self) -> bool {
+        self.skip_hash
+    }
+
+    /// Returns true if this field is excluded from [`Reflect::reflect_partial_eq`].
+    pub fn skip_partial_eq(&self) -> bool {
+        self.skip_partial_eq
+    }
+
+    /// Returns true if this field contains sensitive data.
+    ///
+    /// Redacted fields are masked in [`Reflect::debug`] output and by the reflection serializers.
+    /// Tools which display or edit reflected values should mask them as well.
+    pub fn is_redacted(&self) -> bool {
+        self.redacted
+    }
+
     /// A representation of the type path of the field.
     ///
     /// Provides dynamic access to all methods on [`TypePath`].
@@ -159,3 +343,17 @@ impl UnnamedField {
 
     impl_custom_attribute_methods!(self.custom_attributes, "field");
 }
+
+/// Returns a pointer to the field at `offset` within `value`,
+/// provided `value` is of the type with the given [`TypeId`].
+#[allow(unsafe_code)]
+pub(crate) fn field_ptr(value: &dyn Any, type_id: TypeId, offset: usize) -> Option<Ptr<'_>> {
+    if value.type_id() != type_id {
+        return None;
+    }
+
+    let base = NonNull::from(value).cast::<u8>();
+    // SAFETY: `value` is of the type the offset was computed for, as required by `with_offset`,
+    // so the resulting pointer points to a field within the same value
+    Some(unsafe { Ptr::new(base).byte_add(offset) })
+}
@@ -1,4 +1,5 @@
-use crate::{FromType, Reflect};
+use crate::{FromType, Reflect, TypeRegistry};
+use thiserror::Error;
 
 /// A trait that enables types to be dynamically constructed from reflected data.
 ///
@@ -124,3 +125,72 @@ impl<T: FromReflect> FromType<T> for ReflectFromReflect {
         }
     }
 }
+
+/// An error returned by [`deep_clone_concrete`] and [`TypeRegistration::clone_concrete`].
+///
+/// [`TypeRegistration::clone_concrete`]: crate::TypeRegistration::clone_concrete
+#[derive(Error, Debug)]
+pub enum CloneError {
+    #[error("`{type_path}` does not represent any type")]
+    /// The value is a dynamic type that does not [represent](Reflect::get_represented_type_info) a concrete type.
+    NotRepresented { type_path: Box<str> },
+
+    #[error("`{type_path}` is not registered")]
+    /// The represented type was not found in the registry.
+    NotRegistered { type_path: Box<str> },
+
+    #[error("`{type_path}` did not register `ReflectFromReflect`")]
+    /// The represented type does not have [`ReflectFromReflect`] type data.
+    MissingFromReflect { type_path: Box<str> },
+
+    #[error("failed to create `{type_path}` from reflected value")]
+    /// [`FromReflect::from_reflect`] returned `None`.
+    FromReflectFailed { type_path: Box<str> },
+}
+
+/// Clones the given value into an instance of its concrete type.
+///
+/// Unlike [`Reflect::clone_value`], which may return a dynamic type (such as [`DynamicStruct`]),
+/// this uses the [`ReflectFromReflect`] type data registered for the value's
+/// [represented type] to produce the concrete type.
+/// Since [`FromReflect`] is already deep, nested values will also be concrete.
+///
+/// Returns an error naming the type if it isn't registered or didn't register [`ReflectFromReflect`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{deep_clone_concrete, Reflect, TypeRegistry};
+/// # use std::any::{Any, TypeId};
+/// #[derive(Reflect)]
+/// struct Foo(i32);
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Foo>();
+///
+/// let dynamic = Foo(123).clone_value();
+/// assert_ne!(TypeId::of::<Foo>(), dynamic.as_any().type_id());
+///
+/// let concrete = deep_clone_concrete(dynamic.as_ref(), &registry).unwrap();
+/// assert_eq!(TypeId::of::<Foo>(), concrete.as_any().type_id());
+/// ```
+///
+/// [`DynamicStruct`]: crate::DynamicStruct
+/// [represented type]: Reflect::get_represented_type_info
+pub fn deep_clone_concrete(
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, CloneError> {
+    let info = value
+        .get_represented_type_info()
+        .ok_or_else(|| CloneError::NotRepresented {
+            type_path: value.reflect_type_path().into(),
+        })?;
+
+    registry
+        .get(info.type_id())
+        .ok_or_else(|| CloneError::NotRegistered {
+            type_path: info.type_path().into(),
+        })?
+        .clone_concrete(value)
+}
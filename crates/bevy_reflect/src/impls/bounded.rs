@@ -0,0 +1,180 @@
+/// Implements reflection for a fixed-capacity, `Vec`-like type with a `CAP` const parameter.
+///
+/// The fallible insertion functions must return a `Result` which is an `Err` when the list is full.
+macro_rules! impl_reflect_for_bounded_veclike {
+    ($ty:ty, $try_insert:expr, $remove:expr, $try_push:expr, $pop:expr) => {
+        /// Note that [`List::insert`] and [`List::push`] will panic if the list is already full.
+        impl<T: FromReflect + TypePath, const CAP: usize> List for $ty {
+            fn get(&self, index: usize) -> Option<&dyn Reflect> {
+                <[T]>::get(self, index).map(|value| value as &dyn Reflect)
+            }
+
+            fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+                <[T]>::get_mut(self, index).map(|value| value as &mut dyn Reflect)
+            }
+
+            fn insert(&mut self, index: usize, value: Box<dyn Reflect>) {
+                let value = value.take::<T>().unwrap_or_else(|value| {
+                    T::from_reflect(&*value).unwrap_or_else(|| {
+                        panic!(
+                            "Attempted to insert invalid value: expected value of type `{}` but found `{}`",
+                            std::any::type_name::<T>(),
+                            value.reflect_type_path()
+                        )
+                    })
+                });
+                if $try_insert(self, index, value).is_err() {
+                    panic!(
+                        "Attempted to insert into a full `{}`",
+                        <Self as TypePath>::type_path()
+                    );
+                }
+            }
+
+            fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+                Box::new($remove(self, index))
+            }
+
+            fn push(&mut self, value: Box<dyn Reflect>) {
+                let value = value.take::<T>().unwrap_or_else(|value| {
+                    T::from_reflect(&*value).unwrap_or_else(|| {
+                        panic!(
+                            "Attempted to push invalid value: expected value of type `{}` but found `{}`",
+                            std::any::type_name::<T>(),
+                            value.reflect_type_path()
+                        )
+                    })
+                });
+                if $try_push(self, value).is_err() {
+                    panic!(
+                        "Attempted to push into a full `{}`",
+                        <Self as TypePath>::type_path()
+                    );
+                }
+            }
+
+            fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+                $pop(self).map(|value| Box::new(value) as Box<dyn Reflect>)
+            }
+
+            fn len(&self) -> usize {
+                <[T]>::len(self)
+            }
+
+            fn iter(&self) -> ListIter<'_> {
+                ListIter::new(self)
+            }
+
+            fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+                IntoIterator::into_iter(*self)
+                    .map(|value| Box::new(value) as Box<dyn Reflect>)
+                    .collect()
+            }
+        }
+
+        impl<T: FromReflect + TypePath, const CAP: usize> Reflect for $ty {
+            fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+                Some(<Self as Typed>::type_info())
+            }
+
+            fn into_any(self: Box<Self>) -> Box<dyn Any> {
+                self
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+
+            fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                self
+            }
+
+            fn as_reflect(&self) -> &dyn Reflect {
+                self
+            }
+
+            fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                self
+            }
+
+            fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+                if let ReflectRef::List(list) = value.reflect_ref() {
+                    if list.len() > CAP {
+                        return Err(ApplyError::DifferentSize {
+                            from_size: list.len(),
+                            to_size: CAP,
+                        });
+                    }
+                }
+                crate::list_try_apply(self, value)
+            }
+
+            fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                *self = value.take()?;
+                Ok(())
+            }
+
+            fn reflect_kind(&self) -> ReflectKind {
+                ReflectKind::List
+            }
+
+            fn reflect_ref(&self) -> ReflectRef<'_> {
+                ReflectRef::List(self)
+            }
+
+            fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                ReflectMut::List(self)
+            }
+
+            fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                ReflectOwned::List(self)
+            }
+
+            fn clone_value(&self) -> Box<dyn Reflect> {
+                Box::new(self.clone_dynamic())
+            }
+
+            fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+                crate::list_partial_eq(self, value)
+            }
+        }
+
+        impl<T: FromReflect + TypePath, const CAP: usize> Typed for $ty {
+            fn type_info() -> &'static TypeInfo {
+                static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    TypeInfo::List(ListInfo::new::<Self, T>().with_capacity(CAP))
+                })
+            }
+        }
+
+        impl<T: FromReflect + TypePath, const CAP: usize> FromReflect for $ty {
+            /// Returns `None` if `reflect` is not a list or has more than `CAP` items.
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                if let ReflectRef::List(ref_list) = reflect.reflect_ref() {
+                    let mut new_list = Self::new();
+                    for field in ref_list.iter() {
+                        $try_push(&mut new_list, T::from_reflect(field)?).ok()?;
+                    }
+                    Some(new_list)
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<T: FromReflect + TypePath, const CAP: usize> GetTypeRegistration for $ty {
+            fn get_type_registration() -> TypeRegistration {
+                let mut registration = TypeRegistration::of::<Self>();
+                registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+                registration
+            }
+        }
+    };
+}
+
+pub(crate) use impl_reflect_for_bounded_veclike;
@@ -1,5 +1,6 @@
 use crate as bevy_reflect;
 use crate::prelude::ReflectDefault;
+use crate::{Lerp, ReflectLerp};
 use bevy_reflect_derive::{impl_reflect, impl_reflect_value};
 use glam::*;
 
@@ -118,7 +119,7 @@ impl_reflect!(
 );
 
 impl_reflect!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Lerp)]
     #[type_path = "glam"]
     struct Vec2 {
         x: f32,
@@ -126,7 +127,7 @@ impl_reflect!(
     }
 );
 impl_reflect!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Lerp)]
     #[type_path = "glam"]
     struct Vec3 {
         x: f32,
@@ -135,7 +136,7 @@ impl_reflect!(
     }
 );
 impl_reflect!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Lerp)]
     #[type_path = "glam"]
     struct Vec3A {
         x: f32,
@@ -144,7 +145,7 @@ impl_reflect!(
     }
 );
 impl_reflect!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Lerp)]
     #[type_path = "glam"]
     struct Vec4 {
         x: f32,
@@ -183,7 +184,7 @@ impl_reflect!(
 );
 
 impl_reflect!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Lerp)]
     #[type_path = "glam"]
     struct DVec2 {
         x: f64,
@@ -191,7 +192,7 @@ impl_reflect!(
     }
 );
 impl_reflect!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Lerp)]
     #[type_path = "glam"]
     struct DVec3 {
         x: f64,
@@ -200,7 +201,7 @@ impl_reflect!(
     }
 );
 impl_reflect!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Lerp)]
     #[type_path = "glam"]
     struct DVec4 {
         x: f64,
@@ -310,7 +311,7 @@ impl_reflect!(
 );
 
 impl_reflect!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Lerp)]
     #[type_path = "glam"]
     struct Quat {
         x: f32,
@@ -320,7 +321,7 @@ impl_reflect!(
     }
 );
 impl_reflect!(
-    #[reflect(Debug, PartialEq, Default)]
+    #[reflect(Debug, PartialEq, Default, Lerp)]
     #[type_path = "glam"]
     struct DQuat {
         x: f64,
@@ -330,6 +331,44 @@ impl_reflect!(
     }
 );
 
+macro_rules! impl_lerp_vec {
+    ($float:ty: $($ty:ty),*) => {
+        $(
+            impl Lerp for $ty {
+                fn lerp(&self, other: &Self, t: f32) -> Self {
+                    let t = t as $float;
+                    // Weighting both ends keeps the endpoints exact, unlike `Vec3::lerp`
+                    *self * (1.0 - t) + *other * t
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_lerp_quat {
+    ($float:ty: $($ty:ty),*) => {
+        $(
+            /// Uses spherical linear interpolation, returning the endpoints exactly at `0.0` and `1.0`.
+            impl Lerp for $ty {
+                fn lerp(&self, other: &Self, t: f32) -> Self {
+                    if t == 0.0 {
+                        *self
+                    } else if t == 1.0 {
+                        *other
+                    } else {
+                        self.slerp(*other, t as $float)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_lerp_vec!(f32: Vec2, Vec3, Vec3A, Vec4);
+impl_lerp_vec!(f64: DVec2, DVec3, DVec4);
+impl_lerp_quat!(f32: Quat);
+impl_lerp_quat!(f64: DQuat);
+
 impl_reflect_value!(::glam::EulerRot(Debug, Default));
 impl_reflect_value!(::glam::BVec3A(Debug, Default));
 impl_reflect_value!(::glam::BVec4A(Debug, Default));
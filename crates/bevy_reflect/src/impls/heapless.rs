@@ -0,0 +1,84 @@
+use bevy_reflect_derive::impl_type_path;
+
+use std::any::Any;
+
+use crate::impls::bounded::impl_reflect_for_bounded_veclike;
+use crate::utility::GenericTypeInfoCell;
+use crate::{
+    self as bevy_reflect, ApplyError, FromReflect, FromType, GetTypeRegistration, List, ListInfo,
+    ListIter, Reflect, ReflectFromPtr, ReflectKind, ReflectMut, ReflectOwned, ReflectRef, TypeInfo,
+    TypePath, TypeRegistration, Typed,
+};
+
+impl_reflect_for_bounded_veclike!(
+    ::heapless::Vec<T, CAP>,
+    heapless::Vec::insert,
+    heapless::Vec::remove,
+    heapless::Vec::push,
+    heapless::Vec::pop
+);
+
+impl_type_path!(::heapless::Vec<T, const CAP: usize>);
+
+#[cfg(test)]
+mod tests {
+    use crate::serde::{ReflectDeserializer, ReflectSerializer};
+    use crate::{FromReflect, List, Reflect, TypeInfo, TypeRegistry, Typed};
+    use serde::de::DeserializeSeed;
+
+    type Bounded = heapless::Vec<u32, 3>;
+
+    #[test]
+    fn should_round_trip_within_capacity() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Bounded>();
+
+        let value = Bounded::from_iter([1, 2]);
+        let serializer = ReflectSerializer::new(&value, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let output = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Some(value), Bounded::from_reflect(output.as_ref()));
+    }
+
+    #[test]
+    fn should_report_capacity() {
+        let TypeInfo::List(info) = Bounded::type_info() else {
+            panic!("expected list info");
+        };
+        assert_eq!(Some(3), info.capacity());
+        assert!(info.item_is::<u32>());
+    }
+
+    #[test]
+    fn from_reflect_should_fail_when_over_capacity() {
+        assert_eq!(None, Bounded::from_reflect(&vec![1_u32, 2, 3, 4]));
+        assert_eq!(
+            Some(Bounded::from_iter([1, 2, 3])),
+            Bounded::from_reflect(&vec![1_u32, 2, 3])
+        );
+    }
+
+    #[test]
+    fn try_apply_should_fail_when_over_capacity() {
+        let mut value = Bounded::from_iter([1]);
+        assert!(value.try_apply(&vec![1_u32, 2, 3, 4]).is_err());
+        assert_eq!(Bounded::from_iter([1]), value);
+
+        // Applying should behave the same as `Vec`
+        let mut list = vec![1_u32];
+        value.apply(&vec![5_u32, 6]);
+        list.apply(&vec![5_u32, 6]);
+        assert_eq!(list.as_slice(), value.as_slice());
+    }
+
+    #[test]
+    #[should_panic(expected = "Attempted to push into a full `heapless::Vec<u32, 3>`")]
+    fn push_should_panic_when_full() {
+        let mut value = Bounded::from_iter([1, 2, 3]);
+        List::push(&mut value, Box::new(4_u32));
+    }
+}
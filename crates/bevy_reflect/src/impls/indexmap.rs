@@ -0,0 +1,321 @@
+use bevy_reflect_derive::impl_type_path;
+use indexmap::IndexMap;
+
+use std::any::Any;
+use std::hash::{BuildHasher, Hash};
+
+use crate::utility::GenericTypeInfoCell;
+use crate::{
+    self as bevy_reflect, map_partial_eq, map_try_apply, ApplyError, DynamicMap, FromReflect,
+    FromType, GetTypeRegistration, Map, MapInfo, MapIter, Reflect, ReflectFromPtr, ReflectKind,
+    ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath, TypeRegistration, TypeRegistry,
+    Typed,
+};
+
+/// Unlike the reflected hash maps, iteration, [`Map::clone_dynamic`], and serialization
+/// all follow insertion order.
+///
+/// Applying a map keeps the position of existing keys and appends new keys to the end,
+/// while [`Map::remove`] shifts the following entries down to preserve the order of the rest.
+impl<K, V, S> Map for IndexMap<K, V, S>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+    V: FromReflect + TypePath + GetTypeRegistration,
+    S: TypePath + BuildHasher + Send + Sync,
+{
+    fn get(&self, key: &dyn Reflect) -> Option<&dyn Reflect> {
+        key.downcast_ref::<K>()
+            .and_then(|key| Self::get(self, key))
+            .map(|value| value as &dyn Reflect)
+    }
+
+    fn get_mut(&mut self, key: &dyn Reflect) -> Option<&mut dyn Reflect> {
+        key.downcast_ref::<K>()
+            .and_then(move |key| Self::get_mut(self, key))
+            .map(|value| value as &mut dyn Reflect)
+    }
+
+    fn get_at(&self, index: usize) -> Option<(&dyn Reflect, &dyn Reflect)> {
+        self.get_index(index)
+            .map(|(key, value)| (key as &dyn Reflect, value as &dyn Reflect))
+    }
+
+    fn get_at_mut(&mut self, index: usize) -> Option<(&dyn Reflect, &mut dyn Reflect)> {
+        self.get_index_mut(index)
+            .map(|(key, value)| (key as &dyn Reflect, value as &mut dyn Reflect))
+    }
+
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+
+    fn iter(&self) -> MapIter<'_> {
+        MapIter::new(self)
+    }
+
+    fn drain(self: Box<Self>) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
+        self.into_iter()
+            .map(|(key, value)| {
+                (
+                    Box::new(key) as Box<dyn Reflect>,
+                    Box::new(value) as Box<dyn Reflect>,
+                )
+            })
+            .collect()
+    }
+
+    fn clone_dynamic(&self) -> DynamicMap {
+        let mut dynamic_map = DynamicMap::default();
+        dynamic_map.set_represented_type(self.get_represented_type_info());
+        for (k, v) in self {
+            let key = K::from_reflect(k).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to clone invalid key of type {}.",
+                    k.reflect_type_path()
+                )
+            });
+            dynamic_map.insert_boxed(Box::new(key), v.clone_value());
+        }
+        dynamic_map
+    }
+
+    fn insert_boxed(
+        &mut self,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    ) -> Option<Box<dyn Reflect>> {
+        let key = key.take::<K>().unwrap_or_else(|key| {
+            K::from_reflect(&*key).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to insert invalid key: expected value of type `{}` but found `{}`",
+                    std::any::type_name::<K>(),
+                    key.reflect_type_path()
+                )
+            })
+        });
+        let value = value.take::<V>().unwrap_or_else(|value| {
+            V::from_reflect(&*value).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to insert invalid value: expected value of type `{}` but found `{}`",
+                    std::any::type_name::<V>(),
+                    value.reflect_type_path()
+                )
+            })
+        });
+        self.insert(key, value)
+            .map(|old_value| Box::new(old_value) as Box<dyn Reflect>)
+    }
+
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        let mut from_reflect = None;
+        key.downcast_ref::<K>()
+            .or_else(|| {
+                from_reflect = K::from_reflect(key);
+                from_reflect.as_ref()
+            })
+            .and_then(|key| self.shift_remove(key))
+            .map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
+}
+
+impl<K, V, S> Reflect for IndexMap<K, V, S>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+    V: FromReflect + TypePath + GetTypeRegistration,
+    S: TypePath + BuildHasher + Send + Sync,
+{
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    #[inline]
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        map_try_apply(self, value)
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::Map
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::Map(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Map(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::Map(self)
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone_dynamic())
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        map_partial_eq(self, value)
+    }
+}
+
+impl<K, V, S> Typed for IndexMap<K, V, S>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+    V: FromReflect + TypePath + GetTypeRegistration,
+    S: TypePath + BuildHasher + Send + Sync,
+{
+    fn type_info() -> &'static TypeInfo {
+        static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+        CELL.get_or_insert::<Self, _>(|| TypeInfo::Map(MapInfo::new::<Self, K, V>()))
+    }
+}
+
+impl_type_path!(::indexmap::IndexMap<K, V, S>);
+
+impl<K, V, S> GetTypeRegistration for IndexMap<K, V, S>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+    V: FromReflect + TypePath + GetTypeRegistration,
+    S: TypePath + BuildHasher + Send + Sync,
+{
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration
+    }
+
+    fn register_type_dependencies(registry: &mut TypeRegistry) {
+        registry.register::<K>();
+        registry.register::<V>();
+    }
+}
+
+impl<K, V, S> FromReflect for IndexMap<K, V, S>
+where
+    K: FromReflect + TypePath + GetTypeRegistration + Eq + Hash,
+    V: FromReflect + TypePath + GetTypeRegistration,
+    S: TypePath + BuildHasher + Default + Send + Sync,
+{
+    /// Creates a map from a reflected map, keeping the order of its entries.
+    ///
+    /// If multiple entries convert to the same key, the last entry wins
+    /// but keeps the position of the first.
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let ReflectRef::Map(ref_map) = reflect.reflect_ref() else {
+            return None;
+        };
+
+        let mut new_map = Self::with_capacity_and_hasher(ref_map.len(), S::default());
+        for (key, value) in ref_map.iter() {
+            let new_key = K::from_reflect(key)?;
+            let new_value = V::from_reflect(value)?;
+            new_map.insert(new_key, new_value);
+        }
+        Some(new_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::serde::{ReflectDeserializer, ReflectSerializer};
+    use crate::{DynamicMap, FromReflect, Map, Reflect, TypeRegistry};
+    use indexmap::IndexMap;
+    use serde::de::DeserializeSeed;
+
+    type Ordered = IndexMap<String, u32>;
+
+    fn ordered(entries: &[(&str, u32)]) -> Ordered {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), *value))
+            .collect()
+    }
+
+    fn keys(map: &dyn Map) -> Vec<String> {
+        map.iter()
+            .map(|(key, _)| key.downcast_ref::<String>().unwrap().clone())
+            .collect()
+    }
+
+    #[test]
+    fn should_serialize_in_insertion_order() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Ordered>();
+
+        let value = ordered(&[("zebra", 1), ("apple", 2), ("mango", 3)]);
+        let serializer = ReflectSerializer::new(&value, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(
+            r#"{"indexmap::IndexMap<alloc::string::String, u32, std::collections::hash_map::RandomState>":{"zebra":1,"apple":2,"mango":3}}"#,
+            output
+        );
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let dynamic = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let round_trip = Ordered::from_reflect(dynamic.as_ref()).unwrap();
+        assert!(value.iter().eq(round_trip.iter()));
+
+        // Serializing again should give the exact same output
+        let serializer = ReflectSerializer::new(&round_trip, &registry);
+        assert_eq!(output, ron::ser::to_string(&serializer).unwrap());
+    }
+
+    #[test]
+    fn apply_should_preserve_order() {
+        let mut value = ordered(&[("c", 1), ("a", 2), ("b", 3)]);
+        value.apply(&ordered(&[("d", 40), ("b", 30), ("c", 10)]));
+
+        assert_eq!(vec!["c", "a", "b", "d"], keys(&value));
+        assert_eq!(
+            vec![10, 2, 30, 40],
+            value.values().copied().collect::<Vec<_>>()
+        );
+
+        let (key, entry) = Map::get_at(&value, 3).unwrap();
+        assert_eq!(Some(&"d".to_string()), key.downcast_ref());
+        assert_eq!(Some(&40_u32), entry.downcast_ref());
+    }
+
+    #[test]
+    fn from_reflect_should_keep_dynamic_order() {
+        let mut dynamic = DynamicMap::default();
+        dynamic.insert("y".to_string(), 1_u32);
+        dynamic.insert("x".to_string(), 2_u32);
+        dynamic.insert("w".to_string(), 3_u32);
+
+        let value = Ordered::from_reflect(&dynamic).unwrap();
+        assert_eq!(vec!["y", "x", "w"], keys(&value));
+        assert_eq!(keys(&dynamic), keys(&value.clone_dynamic()));
+    }
+}
@@ -34,7 +34,8 @@ where
         let value = value.take::<T::Item>().unwrap_or_else(|value| {
             <T as smallvec::Array>::Item::from_reflect(&*value).unwrap_or_else(|| {
                 panic!(
-                    "Attempted to insert invalid value of type {}.",
+                    "Attempted to insert invalid value: expected value of type `{}` but found `{}`",
+                    std::any::type_name::<T::Item>(),
                     value.reflect_type_path()
                 )
             })
@@ -50,7 +51,8 @@ where
         let value = value.take::<T::Item>().unwrap_or_else(|value| {
             <T as smallvec::Array>::Item::from_reflect(&*value).unwrap_or_else(|| {
                 panic!(
-                    "Attempted to push invalid value of type {}.",
+                    "Attempted to push invalid value: expected value of type `{}` but found `{}`",
+                    std::any::type_name::<T::Item>(),
                     value.reflect_type_path()
                 )
             })
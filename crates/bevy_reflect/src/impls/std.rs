@@ -6,9 +6,9 @@ use crate::{
     self as bevy_reflect, impl_type_path, map_apply, map_partial_eq, map_try_apply, ApplyError,
     Array, ArrayInfo, ArrayIter, DynamicMap, DynamicTypePath, FromReflect, FromType,
     GetTypeRegistration, List, ListInfo, ListIter, Map, MapInfo, MapIter, Reflect,
-    ReflectDeserialize, ReflectFromPtr, ReflectFromReflect, ReflectKind, ReflectMut, ReflectOwned,
-    ReflectRef, ReflectSerialize, TypeInfo, TypePath, TypeRegistration, TypeRegistry, Typed,
-    ValueInfo,
+    ReflectDeserialize, ReflectFromPtr, ReflectFromReflect, ReflectKind, ReflectLerp, ReflectMut,
+    ReflectOwned, ReflectRef, ReflectSerialize, TypeInfo, TypePath, TypeRegistration, TypeRegistry,
+    Typed, ValueInfo,
 };
 use bevy_reflect_derive::{impl_reflect, impl_reflect_value};
 use std::fmt;
@@ -17,6 +17,7 @@ use std::{
     borrow::Cow,
     collections::VecDeque,
     hash::{BuildHasher, Hash, Hasher},
+    ops::{Bound, ControlFlow},
     path::Path,
 };
 
@@ -24,6 +25,7 @@ impl_reflect_value!(bool(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
     Default
@@ -32,57 +34,155 @@ impl_reflect_value!(char(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
     Default
 ));
-impl_reflect_value!(u8(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(u16(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(u32(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(u64(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
+impl_reflect_value!(u8(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Lerp
+));
+impl_reflect_value!(u16(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Lerp
+));
+impl_reflect_value!(u32(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Lerp
+));
+impl_reflect_value!(u64(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Lerp
+));
 impl_reflect_value!(u128(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Lerp
 ));
 impl_reflect_value!(usize(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Lerp
+));
+impl_reflect_value!(i8(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Lerp
+));
+impl_reflect_value!(i16(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Lerp
+));
+impl_reflect_value!(i32(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Lerp
+));
+impl_reflect_value!(i64(
+    Debug,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Lerp
 ));
-impl_reflect_value!(i8(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(i16(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(i32(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(i64(Debug, Hash, PartialEq, Serialize, Deserialize, Default));
 impl_reflect_value!(i128(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Lerp
 ));
 impl_reflect_value!(isize(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
-    Default
+    Default,
+    Lerp
+));
+impl_reflect_value!(f32(
+    Debug,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Lerp
+));
+impl_reflect_value!(f64(
+    Debug,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+    Default,
+    Lerp
 ));
-impl_reflect_value!(f32(Debug, PartialEq, Serialize, Deserialize, Default));
-impl_reflect_value!(f64(Debug, PartialEq, Serialize, Deserialize, Default));
 impl_type_path!(str);
 impl_reflect_value!(::alloc::string::String(
     Debug,
     Hash,
     PartialEq,
+    PartialOrd,
     Serialize,
     Deserialize,
     Default
@@ -237,7 +337,8 @@ macro_rules! impl_reflect_for_veclike {
                 let value = value.take::<T>().unwrap_or_else(|value| {
                     T::from_reflect(&*value).unwrap_or_else(|| {
                         panic!(
-                            "Attempted to insert invalid value of type {}.",
+                            "Attempted to insert invalid value: expected value of type `{}` but found `{}`",
+                            std::any::type_name::<T>(),
                             value.reflect_type_path()
                         )
                     })
@@ -250,11 +351,14 @@ macro_rules! impl_reflect_for_veclike {
             }
 
             fn push(&mut self, value: Box<dyn Reflect>) {
-                let value = T::take_from_reflect(value).unwrap_or_else(|value| {
-                    panic!(
-                        "Attempted to push invalid value of type {}.",
-                        value.reflect_type_path()
-                    )
+                let value = value.take::<T>().unwrap_or_else(|value| {
+                    T::from_reflect(&*value).unwrap_or_else(|| {
+                        panic!(
+                            "Attempted to push invalid value: expected value of type `{}` but found `{}`",
+                            std::any::type_name::<T>(),
+                            value.reflect_type_path()
+                        )
+                    })
                 });
                 $push(self, value);
             }
@@ -477,17 +581,23 @@ macro_rules! impl_reflect_for_hashmap {
                 key: Box<dyn Reflect>,
                 value: Box<dyn Reflect>,
             ) -> Option<Box<dyn Reflect>> {
-                let key = K::take_from_reflect(key).unwrap_or_else(|key| {
-                    panic!(
-                        "Attempted to insert invalid key of type {}.",
-                        key.reflect_type_path()
-                    )
+                let key = key.take::<K>().unwrap_or_else(|key| {
+                    K::from_reflect(&*key).unwrap_or_else(|| {
+                        panic!(
+                            "Attempted to insert invalid key: expected value of type `{}` but found `{}`",
+                            std::any::type_name::<K>(),
+                            key.reflect_type_path()
+                        )
+                    })
                 });
-                let value = V::take_from_reflect(value).unwrap_or_else(|value| {
-                    panic!(
-                        "Attempted to insert invalid value of type {}.",
-                        value.reflect_type_path()
-                    )
+                let value = value.take::<V>().unwrap_or_else(|value| {
+                    V::from_reflect(&*value).unwrap_or_else(|| {
+                        panic!(
+                            "Attempted to insert invalid value: expected value of type `{}` but found `{}`",
+                            std::any::type_name::<V>(),
+                            value.reflect_type_path()
+                        )
+                    })
                 });
                 self.insert(key, value)
                     .map(|old_value| Box::new(old_value) as Box<dyn Reflect>)
@@ -614,6 +724,10 @@ macro_rules! impl_reflect_for_hashmap {
             V: FromReflect + TypePath + GetTypeRegistration,
             S: TypePath + BuildHasher + Default + Send + Sync,
         {
+            /// Creates a map from a reflected map.
+            ///
+            /// If multiple entries convert to the same key, the last entry wins.
+            /// Use [`map_from_reflect_strict`](crate::map_from_reflect_strict) to reject such entries instead.
             fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
                 if let ReflectRef::Map(ref_map) = reflect.reflect_ref() {
                     let mut new_map = Self::with_capacity_and_hasher(ref_map.len(), S::default());
@@ -707,17 +821,23 @@ where
         key: Box<dyn Reflect>,
         value: Box<dyn Reflect>,
     ) -> Option<Box<dyn Reflect>> {
-        let key = K::take_from_reflect(key).unwrap_or_else(|key| {
-            panic!(
-                "Attempted to insert invalid key of type {}.",
-                key.reflect_type_path()
-            )
+        let key = key.take::<K>().unwrap_or_else(|key| {
+            K::from_reflect(&*key).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to insert invalid key: expected value of type `{}` but found `{}`",
+                    std::any::type_name::<K>(),
+                    key.reflect_type_path()
+                )
+            })
         });
-        let value = V::take_from_reflect(value).unwrap_or_else(|value| {
-            panic!(
-                "Attempted to insert invalid value of type {}.",
-                value.reflect_type_path()
-            )
+        let value = value.take::<V>().unwrap_or_else(|value| {
+            V::from_reflect(&*value).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to insert invalid value: expected value of type `{}` but found `{}`",
+                    std::any::type_name::<V>(),
+                    value.reflect_type_path()
+                )
+            })
         });
         self.insert(key, value)
             .map(|old_value| Box::new(old_value) as Box<dyn Reflect>)
@@ -835,6 +955,10 @@ where
     K: FromReflect + TypePath + GetTypeRegistration + Eq + Ord,
     V: FromReflect + TypePath + GetTypeRegistration,
 {
+    /// Creates a map from a reflected map.
+    ///
+    /// If multiple entries convert to the same key, the last entry wins.
+    /// Use [`map_from_reflect_strict`](crate::map_from_reflect_strict) to reject such entries instead.
     fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
         if let ReflectRef::Map(ref_map) = reflect.reflect_ref() {
             let mut new_map = Self::new();
@@ -966,6 +1090,11 @@ impl<T: Reflect + TypePath + GetTypeRegistration, const N: usize> Reflect for [T
     fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
         crate::array_partial_eq(self, value)
     }
+
+    #[inline]
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<std::cmp::Ordering> {
+        crate::array_partial_cmp(self, value)
+    }
 }
 
 impl<T: FromReflect + TypePath + GetTypeRegistration, const N: usize> FromReflect for [T; N] {
@@ -1027,6 +1156,23 @@ impl_reflect! {
     }
 }
 
+impl_reflect! {
+    #[type_path = "core::ops"]
+    enum Bound<T> {
+        Included(T),
+        Excluded(T),
+        Unbounded,
+    }
+}
+
+impl_reflect! {
+    #[type_path = "core::ops"]
+    enum ControlFlow<B, C> {
+        Continue(C),
+        Break(B),
+    }
+}
+
 impl<T: TypePath + ?Sized> TypePath for &'static T {
     fn type_path() -> &'static str {
         static CELL: GenericTypePathCell = GenericTypePathCell::new();
@@ -1193,11 +1339,12 @@ impl<T: FromReflect + Clone + TypePath + GetTypeRegistration> List for Cow<'stat
     }
 
     fn insert(&mut self, index: usize, element: Box<dyn Reflect>) {
-        let value = element.take::<T>().unwrap_or_else(|value| {
-            T::from_reflect(&*value).unwrap_or_else(|| {
+        let value = element.take::<T>().unwrap_or_else(|element| {
+            T::from_reflect(&*element).unwrap_or_else(|| {
                 panic!(
-                    "Attempted to insert invalid value of type {}.",
-                    value.reflect_type_path()
+                    "Attempted to insert invalid value: expected value of type `{}` but found `{}`",
+                    std::any::type_name::<T>(),
+                    element.reflect_type_path()
                 )
             })
         });
@@ -1209,11 +1356,14 @@ impl<T: FromReflect + Clone + TypePath + GetTypeRegistration> List for Cow<'stat
     }
 
     fn push(&mut self, value: Box<dyn Reflect>) {
-        let value = T::take_from_reflect(value).unwrap_or_else(|value| {
-            panic!(
-                "Attempted to push invalid value of type {}.",
-                value.reflect_type_path()
-            )
+        let value = value.take::<T>().unwrap_or_else(|value| {
+            T::from_reflect(&*value).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to push invalid value: expected value of type `{}` but found `{}`",
+                    std::any::type_name::<T>(),
+                    value.reflect_type_path()
+                )
+            })
         });
         self.to_mut().push(value);
     }
@@ -1675,15 +1825,18 @@ impl GetTypeRegistration for Cow<'static, Path> {
 #[cfg(test)]
 mod tests {
     use crate as bevy_reflect;
+    use crate::serde::{ReflectDeserializer, ReflectSerializer};
     use crate::{
-        Enum, FromReflect, Reflect, ReflectSerialize, TypeInfo, TypeRegistry, Typed, VariantInfo,
-        VariantType,
+        dyn_enum, Enum, FromReflect, GetTypeRegistration, Reflect, ReflectSerialize, TypeInfo,
+        TypeRegistry, Typed, VariantInfo, VariantType,
     };
     use bevy_utils::HashMap;
     use bevy_utils::{Duration, Instant};
+    use serde::de::DeserializeSeed;
     use static_assertions::assert_impl_all;
     use std::collections::BTreeMap;
     use std::f32::consts::{PI, TAU};
+    use std::ops::{Bound, ControlFlow};
     use std::path::Path;
 
     #[test]
@@ -1854,6 +2007,100 @@ mod tests {
         assert_eq!(patch, value, "Some apply onto Some");
     }
 
+    #[test]
+    fn bound_should_impl_enum() {
+        let mut value = Bound::Included(1.0f32);
+        assert_eq!("Included", value.variant_name());
+        assert_eq!(0, value.variant_index());
+        assert_eq!("core::ops::Bound<f32>::Included", value.variant_path());
+
+        *value
+            .field_at_mut(0)
+            .unwrap()
+            .downcast_mut::<f32>()
+            .unwrap() = 2.0;
+        assert_eq!(Bound::Included(2.0), value);
+
+        assert_eq!(1, Bound::Excluded(1.0f32).variant_index());
+        assert_eq!(VariantType::Unit, Bound::<f32>::Unbounded.variant_type());
+
+        let TypeInfo::Enum(info) = Bound::<f32>::type_info() else {
+            panic!("expected `TypeInfo::Enum`");
+        };
+        assert_eq!(&["Included", "Excluded", "Unbounded"], info.variant_names());
+        let VariantInfo::Tuple(variant) = info.variant("Excluded").unwrap() else {
+            panic!("expected `VariantInfo::Tuple`");
+        };
+        assert!(variant.field_at(0).unwrap().is::<f32>());
+
+        // Applying a different variant swaps the variant while keeping the value
+        value.apply(&Bound::Excluded(2.0f32));
+        assert_eq!(Bound::Excluded(2.0), value);
+    }
+
+    #[test]
+    fn control_flow_should_impl_enum() {
+        let value = ControlFlow::<String, u32>::Continue(5);
+        assert_eq!("Continue", value.variant_name());
+        assert_eq!(Some(&5), value.field_at(0).unwrap().downcast_ref::<u32>());
+
+        let TypeInfo::Enum(info) = ControlFlow::<String, u32>::type_info() else {
+            panic!("expected `TypeInfo::Enum`");
+        };
+        assert_eq!(Some(1), info.index_of("Break"));
+        let VariantInfo::Tuple(variant) = info.variant_at(1).unwrap() else {
+            panic!("expected `VariantInfo::Tuple`");
+        };
+        assert!(variant.field_at(0).unwrap().is::<String>());
+    }
+
+    #[test]
+    fn bound_and_control_flow_should_serialize() {
+        fn round_trip<
+            T: FromReflect + Typed + GetTypeRegistration + PartialEq + std::fmt::Debug,
+        >(
+            value: T,
+        ) {
+            let mut registry = TypeRegistry::default();
+            registry.register::<T>();
+
+            let serializer = ReflectSerializer::new(&value, &registry);
+            let output = ron::ser::to_string(&serializer).unwrap();
+
+            let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+            let dynamic = ReflectDeserializer::new(&registry)
+                .deserialize(&mut deserializer)
+                .unwrap();
+            assert_eq!(Some(value), T::from_reflect(dynamic.as_ref()), "{output}");
+        }
+
+        round_trip(Bound::Included(1.5f32));
+        round_trip(Bound::Excluded(-1.5f32));
+        round_trip(Bound::<f32>::Unbounded);
+        round_trip(ControlFlow::<String, u32>::Continue(7));
+        round_trip(ControlFlow::<String, u32>::Break("done".to_string()));
+    }
+
+    #[test]
+    fn bound_and_control_flow_should_from_reflect() {
+        let dynamic = dyn_enum!(Excluded(3u32));
+        assert_eq!(
+            Some(Bound::Excluded(3u32)),
+            Bound::<u32>::from_reflect(&dynamic)
+        );
+        assert_eq!(
+            Some(Bound::<u32>::Unbounded),
+            Bound::<u32>::from_reflect(&dyn_enum!(Unbounded))
+        );
+        assert_eq!(None, Bound::<u32>::from_reflect(&dyn_enum!(Excluded())));
+
+        let dynamic = dyn_enum!(Break("stop".to_string()));
+        assert_eq!(
+            Some(ControlFlow::Break("stop".to_string())),
+            ControlFlow::<String, ()>::from_reflect(&dynamic)
+        );
+    }
+
     #[test]
     fn option_should_impl_typed() {
         assert_impl_all!(Option<()>: Typed);
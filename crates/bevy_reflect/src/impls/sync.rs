@@ -0,0 +1,177 @@
+use crate::utility::GenericTypeInfoCell;
+use crate::{
+    self as bevy_reflect, ApplyError, FromReflect, FromType, GetTypeRegistration, Reflect,
+    ReflectFromPtr, ReflectFromReflect, ReflectKind, ReflectLock, ReflectMut, ReflectOwned,
+    ReflectRef, TypeInfo, TypePath, TypeRegistration, TypeRegistry, Typed, ValueInfo,
+};
+use bevy_reflect_derive::impl_type_path;
+use std::any::Any;
+use std::fmt;
+use std::sync::{Mutex, PoisonError, RwLock, TryLockError};
+
+impl_type_path!(::std::sync::Mutex<T>);
+impl_type_path!(::std::sync::RwLock<T>);
+
+/// Implements [`Reflect`] for a lock type as a [value](ReflectKind::Value).
+///
+/// Since the contents of a lock can only be accessed while holding it,
+/// every operation acquires the lock internally and releases it before returning.
+/// Field-level access into the guarded value is therefore not supported.
+///
+/// - `$lock` is the method used to acquire shared access (e.g. `Mutex::lock` or `RwLock::read`).
+/// - `$try_lock` is its non-blocking counterpart, used for debug formatting.
+macro_rules! impl_reflect_for_lock {
+    ($ty:ident, $lock:ident, $try_lock:ident) => {
+        impl<T: FromReflect + TypePath + GetTypeRegistration> Reflect for $ty<T> {
+            fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+                Some(<Self as Typed>::type_info())
+            }
+
+            fn into_any(self: Box<Self>) -> Box<dyn Any> {
+                self
+            }
+
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn Any {
+                self
+            }
+
+            fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+                self
+            }
+
+            fn as_reflect(&self) -> &dyn Reflect {
+                self
+            }
+
+            fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+                self
+            }
+
+            /// Applies `value` to the guarded value.
+            ///
+            /// `value` may either be another lock of the same type or a value
+            /// that can be applied to the guarded type directly.
+            ///
+            /// Returns [`ApplyError::PoisonedLock`] if either lock is poisoned.
+            fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+                let poisoned = || ApplyError::PoisonedLock {
+                    type_path: Self::type_path().into(),
+                };
+                let inner = self.get_mut().map_err(|_| poisoned())?;
+                match value.downcast_ref::<Self>() {
+                    Some(value) => inner.try_apply(&*value.$lock().map_err(|_| poisoned())?),
+                    None => inner.try_apply(value),
+                }
+            }
+
+            fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+                *self = value.take()?;
+                Ok(())
+            }
+
+            fn reflect_kind(&self) -> ReflectKind {
+                ReflectKind::Value
+            }
+
+            fn reflect_ref(&self) -> ReflectRef<'_> {
+                ReflectRef::Value(self)
+            }
+
+            fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                ReflectMut::Value(self)
+            }
+
+            fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+                ReflectOwned::Value(self)
+            }
+
+            /// Clones the guarded value into a new lock.
+            ///
+            /// If the lock is poisoned, its contents are still cloned.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the guarded value cannot be recreated with [`FromReflect`].
+            fn clone_value(&self) -> Box<dyn Reflect> {
+                let inner = self.$lock().unwrap_or_else(PoisonError::into_inner);
+                let inner = T::from_reflect(&*inner).unwrap_or_else(|| {
+                    panic!(
+                        "failed to clone `{}`: `FromReflect` could not recreate its guarded value",
+                        Self::type_path()
+                    )
+                });
+                Box::new($ty::new(inner))
+            }
+
+            /// Compares the guarded values of two locks of the same type.
+            ///
+            /// Returns `None` if either lock is poisoned.
+            fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+                let Some(value) = value.downcast_ref::<Self>() else {
+                    return Some(false);
+                };
+
+                let inner = self.$lock().ok()?;
+                if std::ptr::eq(self, value) {
+                    // Avoid acquiring the same lock twice
+                    return inner.reflect_partial_eq(&*inner);
+                }
+
+                let other = value.$lock().ok()?;
+                inner.reflect_partial_eq(&*other)
+            }
+
+            fn debug(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, concat!(stringify!($ty), "("))?;
+                match self.$try_lock() {
+                    Ok(inner) => inner.debug(f)?,
+                    Err(TryLockError::Poisoned(_)) => write!(f, "<poisoned>")?,
+                    Err(TryLockError::WouldBlock) => write!(f, "<locked>")?,
+                }
+                write!(f, ")")
+            }
+        }
+
+        impl<T: FromReflect + TypePath + GetTypeRegistration> Typed for $ty<T> {
+            fn type_info() -> &'static TypeInfo {
+                static CELL: GenericTypeInfoCell = GenericTypeInfoCell::new();
+                CELL.get_or_insert::<Self, _>(|| TypeInfo::Value(ValueInfo::new::<Self>()))
+            }
+        }
+
+        impl<T: FromReflect + TypePath + GetTypeRegistration> GetTypeRegistration for $ty<T> {
+            fn get_type_registration() -> TypeRegistration {
+                let mut registration = TypeRegistration::of::<Self>();
+                registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+                registration.insert::<ReflectFromReflect>(FromType::<Self>::from_type());
+                registration.insert::<ReflectLock>(FromType::<Self>::from_type());
+                registration
+            }
+
+            fn register_type_dependencies(registry: &mut TypeRegistry) {
+                registry.register::<T>();
+            }
+        }
+
+        impl<T: FromReflect + TypePath + GetTypeRegistration> FromReflect for $ty<T> {
+            /// Creates a new lock around a value converted from `reflect`.
+            ///
+            /// `reflect` may either be another lock of the same type or a value
+            /// that the guarded type can be created from.
+            fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+                match reflect.downcast_ref::<Self>() {
+                    Some(value) => T::from_reflect(&*value.$lock().ok()?),
+                    None => T::from_reflect(reflect),
+                }
+                .map($ty::new)
+            }
+        }
+    };
+}
+
+impl_reflect_for_lock!(Mutex, lock, try_lock);
+impl_reflect_for_lock!(RwLock, read, try_read);
@@ -0,0 +1,481 @@
+//! Importing tabular data, such as CSV files, as lists of reflected structs.
+//!
+//! Designer data often arrives as a spreadsheet whose columns map onto the fields of a row struct.
+//! This module does not parse any file format itself.
+//! Instead, it takes the already-split header and row cells
+//! and builds a [`DynamicList`] of [`DynamicStruct`] rows,
+//! which can then be converted to the concrete row type with [`FromReflect`].
+//!
+//! [`FromReflect`]: crate::FromReflect
+
+use crate::std_traits::{ReflectDefault, ReflectFromStr};
+use crate::{DynamicList, DynamicStruct, FromType, Reflect, ReflectRef, StructInfo, TypeRegistry};
+use std::any::TypeId;
+use thiserror::Error;
+
+/// An error returned when importing rows with [`rows_to_dynamic_list`].
+///
+/// Row indices count data rows only, starting at 0 for the first row after the headers.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ImportError {
+    #[error("row type `{type_path}` is not registered in the type registry")]
+    /// The row type was not registered, so its [`TypeInfo`](crate::TypeInfo) could not be retrieved.
+    NotRegistered { type_path: &'static str },
+
+    #[error("column {column} has header `{header}`, which does not match any field")]
+    /// A header does not match the name of any field in the row type.
+    UnknownColumn { column: usize, header: String },
+
+    #[error("column {column} has header `{header}`, which was already used by another column")]
+    /// Two columns have the same header.
+    DuplicateColumn { column: usize, header: String },
+
+    #[error("no column provides field `{field}` and its type `{type_path}` has no default")]
+    /// A field has no column and no default value could be found for it.
+    MissingColumn {
+        field: &'static str,
+        type_path: &'static str,
+    },
+
+    #[error("column {column} provides field `{field}`, but its type `{type_path}` cannot be parsed from a string")]
+    /// The type of a column's field is not a primitive and has no registered [`ReflectFromStr`].
+    Unparseable {
+        column: usize,
+        field: &'static str,
+        type_path: &'static str,
+    },
+
+    #[error("row {row} has {found} cells, but there are {expected} columns")]
+    /// A row does not have one cell per column.
+    CellCount {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("cell at row {row}, column {column} could not be parsed as `{type_path}`: `{value}`")]
+    /// A cell could not be parsed into the type of its field.
+    InvalidCell {
+        row: usize,
+        column: usize,
+        value: String,
+        type_path: &'static str,
+    },
+}
+
+/// Builds a [`DynamicList`] containing one [`DynamicStruct`] per row.
+///
+/// Each header is matched to the field of the row struct with the same name.
+/// Cells are parsed into the type of their field:
+/// primitives, `bool`, `char`, and `String` are parsed directly,
+/// while any other type must have [`ReflectFromStr`] registered.
+///
+/// Fields without a column are filled in from the row type's [`ReflectDefault`] if registered,
+/// and otherwise from the [`ReflectDefault`] of the field's own type.
+///
+/// Every row has its represented type set to the row struct,
+/// so the list can be converted into a `Vec` of that struct with [`FromReflect`].
+///
+/// # Errors
+///
+/// Returns an error if a header does not match a field, a field cannot be filled in,
+/// or a cell cannot be parsed.
+/// Errors for individual cells contain the indices of their row and column.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{FromReflect, Reflect, TypeInfo, Typed, TypeRegistry};
+/// # use bevy_reflect::import::rows_to_dynamic_list;
+/// #[derive(Reflect, Debug, PartialEq)]
+/// struct Item {
+///     name: String,
+///     price: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Item>();
+///
+/// let TypeInfo::Struct(info) = Item::type_info() else { unreachable!() };
+/// let rows = [vec!["10", "Sword"], vec!["5", "Shield"]].into_iter();
+/// let list = rows_to_dynamic_list(info, &registry, &["price", "name"], rows).unwrap();
+///
+/// let items = Vec::<Item>::from_reflect(&list).unwrap();
+/// assert_eq!(Item { name: "Shield".into(), price: 5 }, items[1]);
+/// ```
+///
+/// [`FromReflect`]: crate::FromReflect
+pub fn rows_to_dynamic_list<'a>(
+    info: &StructInfo,
+    registry: &TypeRegistry,
+    headers: &[&str],
+    rows: impl Iterator<Item = Vec<&'a str>>,
+) -> Result<DynamicList, ImportError> {
+    let represented_type = registry
+        .get(info.type_id())
+        .ok_or_else(|| ImportError::NotRegistered {
+            type_path: info.type_path(),
+        })?
+        .type_info();
+
+    let columns = resolve_columns(info, registry, headers)?;
+    let defaults = resolve_defaults(info, registry, &columns)?;
+
+    let mut list = DynamicList::default();
+    for (row, cells) in rows.enumerate() {
+        if cells.len() != columns.len() {
+            return Err(ImportError::CellCount {
+                row,
+                expected: columns.len(),
+                found: cells.len(),
+            });
+        }
+
+        let mut dynamic_struct = DynamicStruct::default();
+        dynamic_struct.set_represented_type(Some(represented_type));
+        for (index, field) in info.iter().enumerate() {
+            let value = match columns.iter().position(|column| column.field == index) {
+                Some(column) => {
+                    let cell = cells[column];
+                    columns[column].parser.from_str(cell).ok_or_else(|| {
+                        ImportError::InvalidCell {
+                            row,
+                            column,
+                            value: cell.to_string(),
+                            type_path: field.type_path(),
+                        }
+                    })?
+                }
+                None => defaults[index]
+                    .as_ref()
+                    .expect("fields without a column should have a default")
+                    .clone_value(),
+            };
+            dynamic_struct.insert_boxed(field.name(), value);
+        }
+        list.push_box(Box::new(dynamic_struct));
+    }
+
+    Ok(list)
+}
+
+/// A column of the imported data.
+struct Column {
+    /// The index of the field this column provides.
+    field: usize,
+    /// Parses a cell into the type of the field.
+    parser: ReflectFromStr,
+}
+
+fn resolve_columns(
+    info: &StructInfo,
+    registry: &TypeRegistry,
+    headers: &[&str],
+) -> Result<Vec<Column>, ImportError> {
+    let mut columns: Vec<Column> = Vec::with_capacity(headers.len());
+    for (column, header) in headers.iter().enumerate() {
+        let field = info
+            .index_of(header)
+            .ok_or_else(|| ImportError::UnknownColumn {
+                column,
+                header: header.to_string(),
+            })?;
+
+        if columns.iter().any(|other| other.field == field) {
+            return Err(ImportError::DuplicateColumn {
+                column,
+                header: header.to_string(),
+            });
+        }
+
+        let field_info = info.field_at(field).unwrap();
+        let parser = primitive_parser(field_info.type_id())
+            .or_else(|| {
+                registry
+                    .get_type_data::<ReflectFromStr>(field_info.type_id())
+                    .cloned()
+            })
+            .ok_or_else(|| ImportError::Unparseable {
+                column,
+                field: field_info.name(),
+                type_path: field_info.type_path(),
+            })?;
+
+        columns.push(Column { field, parser });
+    }
+    Ok(columns)
+}
+
+/// Returns the default value of every field that has no column, indexed by field.
+fn resolve_defaults(
+    info: &StructInfo,
+    registry: &TypeRegistry,
+    columns: &[Column],
+) -> Result<Vec<Option<Box<dyn Reflect>>>, ImportError> {
+    let row_default = registry
+        .get_type_data::<ReflectDefault>(info.type_id())
+        .map(ReflectDefault::default);
+
+    info.iter()
+        .enumerate()
+        .map(|(index, field)| {
+            if columns.iter().any(|column| column.field == index) {
+                return Ok(None);
+            }
+
+            let from_row = row_default
+                .as_ref()
+                .and_then(|row| match row.reflect_ref() {
+                    ReflectRef::Struct(row) => row.field_at(index).map(Reflect::clone_value),
+                    _ => None,
+                });
+
+            from_row
+                .or_else(|| {
+                    registry
+                        .get_type_data::<ReflectDefault>(field.type_id())
+                        .map(ReflectDefault::default)
+                })
+                .map(Some)
+                .ok_or(ImportError::MissingColumn {
+                    field: field.name(),
+                    type_path: field.type_path(),
+                })
+        })
+        .collect()
+}
+
+/// Returns the parser for the given type if it is a primitive or `String`.
+fn primitive_parser(type_id: TypeId) -> Option<ReflectFromStr> {
+    macro_rules! parsers {
+        ($($ty:ty),*) => {
+            $(
+                if type_id == TypeId::of::<$ty>() {
+                    return Some(<ReflectFromStr as FromType<$ty>>::from_type());
+                }
+            )*
+        };
+    }
+
+    parsers!(
+        bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64,
+        String
+    );
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{FromReflect, List, TypeInfo, TypePath, Typed};
+    use std::str::FromStr;
+
+    #[derive(Reflect, Debug, PartialEq, Clone, Copy)]
+    #[reflect(FromStr)]
+    enum Rarity {
+        Common,
+        Rare,
+    }
+
+    impl FromStr for Rarity {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "common" => Ok(Self::Common),
+                "rare" => Ok(Self::Rare),
+                _ => Err(()),
+            }
+        }
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Row {
+        name: String,
+        damage: f32,
+        rarity: Rarity,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(Default)]
+    struct Settings {
+        name: String,
+        volume: u8,
+        muted: Option<bool>,
+    }
+
+    impl Default for Settings {
+        fn default() -> Self {
+            Self {
+                name: String::new(),
+                volume: 80,
+                muted: Some(false),
+            }
+        }
+    }
+
+    fn struct_info<T: Typed>() -> &'static StructInfo {
+        match T::type_info() {
+            TypeInfo::Struct(info) => info,
+            _ => panic!("expected struct info"),
+        }
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Row>();
+        registry.register::<Settings>();
+        registry
+    }
+
+    #[test]
+    fn should_import_rows() {
+        let registry = registry();
+        let rows = [
+            vec!["Sword", "12.5", "common"],
+            vec!["Axe", "20", "rare"],
+            vec!["Dagger", "4", "common"],
+        ];
+
+        let list = rows_to_dynamic_list(
+            struct_info::<Row>(),
+            &registry,
+            &["name", "damage", "rarity"],
+            rows.into_iter(),
+        )
+        .unwrap();
+
+        assert_eq!(3, list.len());
+        assert!(list.get(0).unwrap().represents::<Row>());
+
+        let rows = Vec::<Row>::from_reflect(&list).unwrap();
+        assert_eq!(
+            vec![
+                Row {
+                    name: "Sword".into(),
+                    damage: 12.5,
+                    rarity: Rarity::Common
+                },
+                Row {
+                    name: "Axe".into(),
+                    damage: 20.0,
+                    rarity: Rarity::Rare
+                },
+                Row {
+                    name: "Dagger".into(),
+                    damage: 4.0,
+                    rarity: Rarity::Common
+                },
+            ],
+            rows
+        );
+    }
+
+    #[test]
+    fn should_report_invalid_cell_coordinates() {
+        let registry = registry();
+        let rows = [
+            vec!["common", "Sword", "12.5"],
+            vec!["legendary", "Axe", "20"],
+        ];
+
+        let result = rows_to_dynamic_list(
+            struct_info::<Row>(),
+            &registry,
+            &["rarity", "name", "damage"],
+            rows.into_iter(),
+        );
+        assert_eq!(
+            ImportError::InvalidCell {
+                row: 1,
+                column: 0,
+                value: "legendary".into(),
+                type_path: Rarity::type_path(),
+            },
+            result.unwrap_err()
+        );
+
+        let rows = [vec!["Sword", "sharp", "common"]];
+        let result = rows_to_dynamic_list(
+            struct_info::<Row>(),
+            &registry,
+            &["name", "damage", "rarity"],
+            rows.into_iter(),
+        );
+        assert!(matches!(
+            result,
+            Err(ImportError::InvalidCell {
+                row: 0,
+                column: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn should_fill_missing_columns_with_defaults() {
+        let registry = registry();
+        let rows = [vec!["Music", "50"], vec!["Effects", "100"]];
+
+        let list = rows_to_dynamic_list(
+            struct_info::<Settings>(),
+            &registry,
+            &["name", "volume"],
+            rows.into_iter(),
+        )
+        .unwrap();
+
+        let settings = Vec::<Settings>::from_reflect(&list).unwrap();
+        assert_eq!(Some(false), settings[0].muted);
+        assert_eq!(100, settings[1].volume);
+
+        // Neither `Row` nor `Rarity` has a default
+        let result = rows_to_dynamic_list(
+            struct_info::<Row>(),
+            &registry,
+            &["name", "damage"],
+            [vec!["Sword", "1"]].into_iter(),
+        );
+        assert_eq!(
+            ImportError::MissingColumn {
+                field: "rarity",
+                type_path: Rarity::type_path(),
+            },
+            result.unwrap_err()
+        );
+    }
+
+    #[test]
+    fn should_reject_mismatched_headers() {
+        let registry = registry();
+        let info = struct_info::<Row>();
+
+        let result = rows_to_dynamic_list(info, &registry, &["name", "dmg"], [].into_iter());
+        assert!(matches!(
+            result,
+            Err(ImportError::UnknownColumn { column: 1, .. })
+        ));
+
+        let result = rows_to_dynamic_list(info, &registry, &["name", "name"], [].into_iter());
+        assert!(matches!(
+            result,
+            Err(ImportError::DuplicateColumn { column: 1, .. })
+        ));
+
+        let result = rows_to_dynamic_list(
+            info,
+            &registry,
+            &["name", "damage", "rarity"],
+            [vec!["Sword", "1"]].into_iter(),
+        );
+        assert_eq!(
+            ImportError::CellCount {
+                row: 0,
+                expected: 3,
+                found: 2,
+            },
+            result.unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,423 @@
+use crate::{NamedField, TypeInfo, TypeRegistry, UnnamedField, VariantInfo};
+use std::any::TypeId;
+use std::borrow::Cow;
+use thiserror::Error;
+
+/// The first structural difference found when comparing the layouts of two types.
+///
+/// Returned by [`TypeInfo::is_layout_compatible_detailed`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{property} of `{type_path}` changed from `{old}` to `{new}`")]
+pub struct LayoutDifference {
+    /// The [type path] of the type being compared.
+    ///
+    /// [type path]: crate::TypePath::type_path
+    pub type_path: &'static str,
+    /// A description of the differing property, such as ``field `a` type``.
+    pub property: Cow<'static, str>,
+    /// The value of the property in the original layout.
+    pub old: Cow<'static, str>,
+    /// The value of the property in the new layout.
+    pub new: Cow<'static, str>,
+}
+
+impl TypeInfo {
+    /// Returns a stable hash of the structural shape of this type.
+    ///
+    /// The fingerprint covers the kind of the type, its type path, the names and order of its fields,
+    /// the type paths of its fields, its enum variants and their shapes, and array capacities.
+    /// Docs and custom attributes are excluded.
+    ///
+    /// The hash is computed with a fixed algorithm, so it can be compared across
+    /// builds and platforms, such as before and after reloading a dynamic library.
+    ///
+    /// Note that this only considers the type paths of field types.
+    /// Use [`TypeRegistry::layout_fingerprint`] to also include the shapes of registered field types.
+    pub fn layout_fingerprint(&self) -> u64 {
+        fingerprint(&describe_layout(self, &|_| None))
+    }
+
+    /// Returns `true` if this type has the same structural shape as `other`.
+    ///
+    /// See [`TypeInfo::layout_fingerprint`] for details on what is compared.
+    pub fn is_layout_compatible(&self, other: &TypeInfo) -> bool {
+        self.is_layout_compatible_detailed(other).is_ok()
+    }
+
+    /// Compares the structural shape of this type with `other`,
+    /// returning the first difference found.
+    ///
+    /// `self` is treated as the original layout and `other` as the new one.
+    ///
+    /// See [`TypeInfo::layout_fingerprint`] for details on what is compared.
+    pub fn is_layout_compatible_detailed(&self, other: &TypeInfo) -> Result<(), LayoutDifference> {
+        let old = describe_layout(self, &|_| None);
+        let new = describe_layout(other, &|_| None);
+
+        let missing = || Cow::Borrowed("<none>");
+        for index in 0..old.len().max(new.len()) {
+            let (old, new) = (old.get(index), new.get(index));
+            if old == new {
+                continue;
+            }
+
+            let property = old.or(new).map(|entry| entry.property.clone()).unwrap();
+            return Err(LayoutDifference {
+                type_path: self.type_path(),
+                property,
+                old: old.map(|entry| entry.value.clone()).unwrap_or_else(missing),
+                new: new.map(|entry| entry.value.clone()).unwrap_or_else(missing),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl TypeRegistry {
+    /// Returns a stable hash of the structural shape of the type with the given [`TypeId`],
+    /// including the shapes of any registered field types.
+    ///
+    /// Unlike [`TypeInfo::layout_fingerprint`], this detects changes to nested types
+    /// whose type paths stayed the same.
+    /// Recursive types are supported: a type referring back to one of its containing types
+    /// is hashed as a reference to that type rather than expanded again.
+    ///
+    /// Returns `None` if the type is not registered.
+    pub fn layout_fingerprint(&self, type_id: TypeId) -> Option<u64> {
+        let type_info = self.get_type_info(type_id)?;
+        let resolve = |type_id| self.get_type_info(type_id);
+        Some(fingerprint(&describe_layout(type_info, &resolve)))
+    }
+}
+
+/// A single property of a type's layout.
+#[derive(PartialEq, Eq)]
+struct LayoutEntry {
+    property: Cow<'static, str>,
+    value: Cow<'static, str>,
+}
+
+/// Flattens the layout of a type into an ordered list of properties.
+///
+/// Field types are expanded using `resolve`, if it returns their [`TypeInfo`].
+fn describe_layout(
+    type_info: &TypeInfo,
+    resolve: &dyn Fn(TypeId) -> Option<&'static TypeInfo>,
+) -> Vec<LayoutEntry> {
+    let mut describer = LayoutDescriber {
+        entries: Vec::new(),
+        stack: vec![type_info.type_id()],
+        resolve,
+    };
+    describer.describe(type_info);
+    describer.entries
+}
+
+struct LayoutDescriber<'a> {
+    entries: Vec<LayoutEntry>,
+    /// The types currently being described, used to detect cycles.
+    stack: Vec<TypeId>,
+    resolve: &'a dyn Fn(TypeId) -> Option<&'static TypeInfo>,
+}
+
+impl LayoutDescriber<'_> {
+    fn push(&mut self, property: impl Into<Cow<'static, str>>, value: impl ToString) {
+        self.entries.push(LayoutEntry {
+            property: property.into(),
+            value: Cow::Owned(value.to_string()),
+        });
+    }
+
+    fn describe(&mut self, type_info: &TypeInfo) {
+        self.push("type path", type_info.type_path());
+        match type_info {
+            TypeInfo::Struct(info) => {
+                self.push("kind", "struct");
+                self.describe_named_fields(info.iter());
+            }
+            TypeInfo::TupleStruct(info) => {
+                self.push("kind", "tuple struct");
+                self.describe_unnamed_fields(info.iter());
+            }
+            TypeInfo::Tuple(info) => {
+                self.push("kind", "tuple");
+                self.describe_unnamed_fields(info.iter());
+            }
+            TypeInfo::List(info) => {
+                self.push("kind", "list");
+                if let Some(capacity) = info.capacity() {
+                    self.push("capacity", capacity);
+                }
+                self.describe_type(
+                    "item",
+                    info.item_type_path_table().path(),
+                    info.item_type_id(),
+                );
+            }
+            TypeInfo::Array(info) => {
+                self.push("kind", "array");
+                self.push("capacity", info.capacity());
+                self.describe_type(
+                    "item",
+                    info.item_type_path_table().path(),
+                    info.item_type_id(),
+                );
+            }
+            TypeInfo::Map(info) => {
+                self.push("kind", "map");
+                self.describe_type("key", info.key_type_path_table().path(), info.key_type_id());
+                self.describe_type(
+                    "value",
+                    info.value_type_path_table().path(),
+                    info.value_type_id(),
+                );
+            }
+            TypeInfo::Enum(info) => {
+                self.push("kind", "enum");
+                self.push("variant count", info.variant_len());
+                for (index, variant) in info.iter().enumerate() {
+                    self.push(format!("variant {index} name"), variant.name());
+                    match variant {
+                        VariantInfo::Struct(variant) => {
+                            self.push(format!("variant `{}` kind", variant.name()), "struct");
+                            self.describe_named_fields(variant.iter());
+                        }
+                        VariantInfo::Tuple(variant) => {
+                            self.push(format!("variant `{}` kind", variant.name()), "tuple");
+                            self.describe_unnamed_fields(variant.iter());
+                        }
+                        VariantInfo::Unit(variant) => {
+                            self.push(format!("variant `{}` kind", variant.name()), "unit");
+                        }
+                    }
+                }
+            }
+            TypeInfo::Value(_) => {
+                self.push("kind", "value");
+            }
+        }
+    }
+
+    fn describe_named_fields<'a>(&mut self, fields: impl ExactSizeIterator<Item = &'a NamedField>) {
+        self.push("field count", fields.len());
+        for (index, field) in fields.enumerate() {
+            self.push(format!("field {index} name"), field.name());
+            self.describe_type(
+                format!("field `{}`", field.name()),
+                field.type_path(),
+                field.type_id(),
+            );
+        }
+    }
+
+    fn describe_unnamed_fields<'a>(
+        &mut self,
+        fields: impl ExactSizeIterator<Item = &'a UnnamedField>,
+    ) {
+        self.push("field count", fields.len());
+        for field in fields {
+            self.describe_type(
+                format!("field {}", field.index()),
+                field.type_path(),
+                field.type_id(),
+            );
+        }
+    }
+
+    /// Describes a field or item type by its type path, expanding it if it can be resolved.
+    fn describe_type(&mut self, name: impl Into<String>, type_path: &str, type_id: TypeId) {
+        let name = name.into();
+        self.push(format!("{name} type"), type_path);
+
+        if let Some(depth) = self.stack.iter().rev().position(|id| *id == type_id) {
+            // Refer back to the containing type instead of expanding it again
+            self.push(format!("{name} cycle"), depth);
+            return;
+        }
+
+        if let Some(type_info) = (self.resolve)(type_id) {
+            self.stack.push(type_id);
+            self.describe(type_info);
+            self.stack.pop();
+        }
+    }
+}
+
+/// Hashes the given layout using 64-bit FNV-1a.
+///
+/// A fixed algorithm is used, rather than the [`std`] or [`reflect_hasher`] hashers,
+/// so that fingerprints are stable across builds and platforms.
+///
+/// [`reflect_hasher`]: crate::utility::reflect_hasher
+fn fingerprint(entries: &[LayoutEntry]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    let mut write = |bytes: &[u8]| {
+        for byte in bytes {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    };
+
+    for entry in entries {
+        for part in [&entry.property, &entry.value] {
+            write(&(part.len() as u64).to_le_bytes());
+            write(part.as_bytes());
+        }
+    }
+
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{Reflect, Typed};
+
+    mod old {
+        use crate as bevy_reflect;
+        use crate::Reflect;
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub struct Player {
+            pub name: String,
+            pub health: u32,
+        }
+    }
+
+    mod added_field {
+        use crate as bevy_reflect;
+        use crate::Reflect;
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub struct Player {
+            pub name: String,
+            pub health: u32,
+            pub armor: u32,
+        }
+    }
+
+    mod reordered_fields {
+        use crate as bevy_reflect;
+        use crate::Reflect;
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub struct Player {
+            pub health: u32,
+            pub name: String,
+        }
+    }
+
+    mod changed_type {
+        use crate as bevy_reflect;
+        use crate::Reflect;
+
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        pub struct Player {
+            /// Docs are not part of the layout
+            pub name: String,
+            #[reflect(@0.0_f32)]
+            pub health: f32,
+        }
+    }
+
+    #[derive(Reflect)]
+    #[type_path = "game"]
+    enum Action {
+        Idle,
+        Move(f32, f32),
+        Attack { target: u32 },
+    }
+
+    #[derive(Reflect)]
+    #[reflect(no_field_bounds)]
+    #[type_path = "game"]
+    struct Node {
+        value: u32,
+        children: Vec<Node>,
+    }
+
+    #[test]
+    fn should_have_stable_fingerprints() {
+        assert_eq!(0x36c3_54bc_5f2e_304a, u32::type_info().layout_fingerprint());
+        assert_eq!(
+            0x955c_c847_2e69_83d4,
+            <[u8; 4]>::type_info().layout_fingerprint()
+        );
+        assert_eq!(
+            0x48c7_12a8_fa3a_5c98,
+            old::Player::type_info().layout_fingerprint()
+        );
+        assert_eq!(
+            0xc2c3_9cf9_5135_ec7d,
+            Action::type_info().layout_fingerprint()
+        );
+    }
+
+    #[test]
+    fn should_detect_layout_differences() {
+        let old = old::Player::type_info();
+        assert!(old.is_layout_compatible(old));
+
+        let new = added_field::Player::type_info();
+        assert!(!old.is_layout_compatible(new));
+        assert_ne!(old.layout_fingerprint(), new.layout_fingerprint());
+        assert_eq!(
+            Err(LayoutDifference {
+                type_path: "game::Player",
+                property: "field count".into(),
+                old: "2".into(),
+                new: "3".into(),
+            }),
+            old.is_layout_compatible_detailed(new)
+        );
+
+        let new = reordered_fields::Player::type_info();
+        assert_ne!(old.layout_fingerprint(), new.layout_fingerprint());
+        assert_eq!(
+            Err(LayoutDifference {
+                type_path: "game::Player",
+                property: "field 0 name".into(),
+                old: "name".into(),
+                new: "health".into(),
+            }),
+            old.is_layout_compatible_detailed(new)
+        );
+
+        let new = changed_type::Player::type_info();
+        assert_ne!(old.layout_fingerprint(), new.layout_fingerprint());
+        let difference = old.is_layout_compatible_detailed(new).unwrap_err();
+        assert_eq!(
+            "field `health` type of `game::Player` changed from `u32` to `f32`",
+            difference.to_string()
+        );
+    }
+
+    #[test]
+    fn should_handle_recursive_types() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Node>();
+
+        let fingerprint = registry.layout_fingerprint(TypeId::of::<Node>()).unwrap();
+        assert_eq!(
+            Some(fingerprint),
+            registry.layout_fingerprint(TypeId::of::<Node>())
+        );
+        // The registry fingerprint includes the shape of `Vec<Node>`
+        assert_ne!(fingerprint, Node::type_info().layout_fingerprint());
+        assert!(registry
+            .layout_fingerprint(TypeId::of::<Vec<Node>>())
+            .is_some());
+        assert!(registry
+            .layout_fingerprint(TypeId::of::<Action>())
+            .is_none());
+    }
+}
@@ -0,0 +1,469 @@
+use crate::{
+    DynamicArray, DynamicEnum, DynamicStruct, DynamicTuple, DynamicTupleStruct, DynamicVariant,
+    FromType, Reflect, ReflectKind, ReflectRef, TypeRegistry, VariantType,
+};
+use std::fmt::Write;
+use thiserror::Error;
+
+/// A trait for types that can be linearly interpolated.
+///
+/// Implementations should return `self` when `t` is `0.0` and `other` when `t` is `1.0`.
+///
+/// This trait is implemented for the following types:
+/// * Floats, which are interpolated linearly.
+/// * Integers, which are interpolated linearly and rounded to the nearest integer,
+///   saturating at the bounds of the type when extrapolating.
+/// * With the `glam` feature, the float vector types, which are interpolated per component,
+///   and [`Quat`](glam::Quat) and [`DQuat`](glam::DQuat), which use spherical linear interpolation (slerp).
+///
+/// All other types snap from one value to the other when interpolated with [`lerp_dynamic`].
+pub trait Lerp: Reflect {
+    /// Interpolates between `self` and `other` by `t`.
+    fn lerp(&self, other: &Self, t: f32) -> Self;
+}
+
+/// A struct used to interpolate between two values of a type using its [`Lerp`] implementation.
+///
+/// A [`ReflectLerp`] for type `T` can be obtained via [`FromType::from_type`].
+#[derive(Clone)]
+pub struct ReflectLerp {
+    lerp: fn(&dyn Reflect, &dyn Reflect, f32) -> Option<Box<dyn Reflect>>,
+}
+
+impl ReflectLerp {
+    /// Interpolates between `a` and `b` by `t`.
+    ///
+    /// Returns `None` if either value is not of the type this [`ReflectLerp`] was created for.
+    pub fn lerp(&self, a: &dyn Reflect, b: &dyn Reflect, t: f32) -> Option<Box<dyn Reflect>> {
+        (self.lerp)(a, b, t)
+    }
+}
+
+impl<T: Lerp> FromType<T> for ReflectLerp {
+    fn from_type() -> Self {
+        ReflectLerp {
+            lerp: |a, b, t| {
+                let a = a.downcast_ref::<T>()?;
+                let b = b.downcast_ref::<T>()?;
+                Some(Box::new(a.lerp(b, t)))
+            },
+        }
+    }
+}
+
+macro_rules! impl_lerp_float {
+    ($($ty:ty),*) => {
+        $(
+            impl Lerp for $ty {
+                fn lerp(&self, other: &Self, t: f32) -> Self {
+                    let t = t as $ty;
+                    // Weighting both ends keeps the endpoints exact
+                    *self * (1.0 - t) + *other * t
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_lerp_int {
+    ($($ty:ty => $unsigned:ty, $add:ident, $sub:ident);*) => {
+        $(
+            impl Lerp for $ty {
+                fn lerp(&self, other: &Self, t: f32) -> Self {
+                    if t == 0.0 {
+                        return *self;
+                    }
+                    if t == 1.0 {
+                        return *other;
+                    }
+                    // Only the distance between the values goes through a float,
+                    // so that values far from zero keep their precision
+                    let offset = (self.abs_diff(*other) as f64 * t as f64).round();
+                    let magnitude = offset.abs() as $unsigned;
+                    if (other >= self) == (offset >= 0.0) {
+                        self.$add(magnitude)
+                    } else {
+                        self.$sub(magnitude)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_lerp_float!(f32, f64);
+impl_lerp_int!(
+    u8 => u8, saturating_add, saturating_sub;
+    u16 => u16, saturating_add, saturating_sub;
+    u32 => u32, saturating_add, saturating_sub;
+    u64 => u64, saturating_add, saturating_sub;
+    u128 => u128, saturating_add, saturating_sub;
+    usize => usize, saturating_add, saturating_sub;
+    i8 => u8, saturating_add_unsigned, saturating_sub_unsigned;
+    i16 => u16, saturating_add_unsigned, saturating_sub_unsigned;
+    i32 => u32, saturating_add_unsigned, saturating_sub_unsigned;
+    i64 => u64, saturating_add_unsigned, saturating_sub_unsigned;
+    i128 => u128, saturating_add_unsigned, saturating_sub_unsigned;
+    isize => usize, saturating_add_unsigned, saturating_sub_unsigned
+);
+
+/// An error returned by [`lerp_dynamic`].
+///
+/// The `path` of each variant is the [path] to the mismatched value,
+/// relative to the values passed to [`lerp_dynamic`].
+///
+/// [path]: crate::GetPath
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum LerpError {
+    #[error("cannot interpolate from a {a} to a {b} at path `{path}`")]
+    MismatchedKinds {
+        path: String,
+        a: ReflectKind,
+        b: ReflectKind,
+    },
+    #[error("the field at path `{path}` is missing from the second value")]
+    MissingField { path: String },
+    #[error("cannot interpolate arrays of length {a} and {b} at path `{path}`")]
+    MismatchedLength { path: String, a: usize, b: usize },
+}
+
+/// Interpolates between two reflected values by `t`.
+///
+/// If the type of `a` has registered [`ReflectLerp`] type data, it is used to interpolate the values directly.
+/// Otherwise, structs, tuple structs, tuples, arrays, and enums of the same variant
+/// are interpolated field by field, returning the corresponding dynamic type.
+/// Values without [`ReflectLerp`], lists, maps, and enums of different variants are snapped wholesale,
+/// returning a clone of `a` when `t` is less than `0.5` and a clone of `b` otherwise.
+///
+/// # Errors
+///
+/// Returns an error if the two values, or any of their fields, have different [kinds](ReflectKind),
+/// if a field of `a` is missing from `b`, or if two arrays have different lengths.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{lerp_dynamic, FromReflect, Reflect, TypeRegistry};
+/// #[derive(Reflect, Debug, PartialEq)]
+/// struct Light {
+///     intensity: f32,
+///     range: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Light>();
+///
+/// let a = Light { intensity: 0.0, range: 10 };
+/// let b = Light { intensity: 1.0, range: 20 };
+/// let halfway = lerp_dynamic(&a, &b, 0.5, &registry).unwrap();
+/// assert_eq!(
+///     Some(Light { intensity: 0.5, range: 15 }),
+///     Light::from_reflect(halfway.as_ref())
+/// );
+/// ```
+pub fn lerp_dynamic(
+    a: &dyn Reflect,
+    b: &dyn Reflect,
+    t: f32,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, LerpError> {
+    lerp_at(a, b, t, registry, &mut String::new())
+}
+
+fn lerp_at(
+    a: &dyn Reflect,
+    b: &dyn Reflect,
+    t: f32,
+    registry: &TypeRegistry,
+    path: &mut String,
+) -> Result<Box<dyn Reflect>, LerpError> {
+    if let Some(lerped) = a
+        .get_represented_type_info()
+        .and_then(|info| registry.get_type_data::<ReflectLerp>(info.type_id()))
+        .and_then(|reflect_lerp| reflect_lerp.lerp(a, b, t))
+    {
+        return Ok(lerped);
+    }
+
+    let snap = || {
+        if t < 0.5 {
+            a.clone_value()
+        } else {
+            b.clone_value()
+        }
+    };
+
+    match (a.reflect_ref(), b.reflect_ref()) {
+        (ReflectRef::Struct(a_struct), ReflectRef::Struct(b_struct)) => {
+            let mut dynamic = DynamicStruct::default();
+            dynamic.set_represented_type(a.get_represented_type_info());
+            for (index, a_field) in a_struct.iter_fields().enumerate() {
+                let name = a_struct.name_at(index).unwrap();
+                let len = path.len();
+                write!(path, ".{name}").unwrap();
+                let b_field = b_struct
+                    .field(name)
+                    .ok_or_else(|| LerpError::MissingField { path: path.clone() })?;
+                dynamic.insert_boxed(name, lerp_at(a_field, b_field, t, registry, path)?);
+                path.truncate(len);
+            }
+            Ok(Box::new(dynamic))
+        }
+        (ReflectRef::TupleStruct(a_tuple), ReflectRef::TupleStruct(b_tuple)) => {
+            let mut dynamic = DynamicTupleStruct::default();
+            dynamic.set_represented_type(a.get_represented_type_info());
+            for (index, a_field) in a_tuple.iter_fields().enumerate() {
+                let len = path.len();
+                write!(path, ".{index}").unwrap();
+                let b_field = b_tuple
+                    .field(index)
+                    .ok_or_else(|| LerpError::MissingField { path: path.clone() })?;
+                dynamic.insert_boxed(lerp_at(a_field, b_field, t, registry, path)?);
+                path.truncate(len);
+            }
+            Ok(Box::new(dynamic))
+        }
+        (ReflectRef::Tuple(a_tuple), ReflectRef::Tuple(b_tuple)) => {
+            let mut dynamic = DynamicTuple::default();
+            dynamic.set_represented_type(a.get_represented_type_info());
+            for (index, a_field) in a_tuple.iter_fields().enumerate() {
+                let len = path.len();
+                write!(path, ".{index}").unwrap();
+                let b_field = b_tuple
+                    .field(index)
+                    .ok_or_else(|| LerpError::MissingField { path: path.clone() })?;
+                dynamic.insert_boxed(lerp_at(a_field, b_field, t, registry, path)?);
+                path.truncate(len);
+            }
+            Ok(Box::new(dynamic))
+        }
+        (ReflectRef::Array(a_array), ReflectRef::Array(b_array)) => {
+            if a_array.len() != b_array.len() {
+                return Err(LerpError::MismatchedLength {
+                    path: path.clone(),
+                    a: a_array.len(),
+                    b: b_array.len(),
+                });
+            }
+            let mut values = Vec::with_capacity(a_array.len());
+            for (index, (a_item, b_item)) in a_array.iter().zip(b_array.iter()).enumerate() {
+                let len = path.len();
+                write!(path, "[{index}]").unwrap();
+                values.push(lerp_at(a_item, b_item, t, registry, path)?);
+                path.truncate(len);
+            }
+            let mut dynamic = DynamicArray::new(values.into_boxed_slice());
+            dynamic.set_represented_type(a.get_represented_type_info());
+            Ok(Box::new(dynamic))
+        }
+        (ReflectRef::Enum(a_enum), ReflectRef::Enum(b_enum)) => {
+            if a_enum.variant_name() != b_enum.variant_name() {
+                return Ok(snap());
+            }
+            let variant = match a_enum.variant_type() {
+                VariantType::Unit => DynamicVariant::Unit,
+                VariantType::Tuple => {
+                    let mut tuple = DynamicTuple::default();
+                    for index in 0..a_enum.field_len() {
+                        let len = path.len();
+                        write!(path, ".{index}").unwrap();
+                        let b_field = b_enum
+                            .field_at(index)
+                            .ok_or_else(|| LerpError::MissingField { path: path.clone() })?;
+                        let a_field = a_enum.field_at(index).unwrap();
+                        tuple.insert_boxed(lerp_at(a_field, b_field, t, registry, path)?);
+                        path.truncate(len);
+                    }
+                    DynamicVariant::Tuple(tuple)
+                }
+                VariantType::Struct => {
+                    let mut dynamic = DynamicStruct::default();
+                    for index in 0..a_enum.field_len() {
+                        let name = a_enum.name_at(index).unwrap();
+                        let len = path.len();
+                        write!(path, ".{name}").unwrap();
+                        let b_field = b_enum
+                            .field(name)
+                            .ok_or_else(|| LerpError::MissingField { path: path.clone() })?;
+                        let a_field = a_enum.field_at(index).unwrap();
+                        dynamic.insert_boxed(name, lerp_at(a_field, b_field, t, registry, path)?);
+                        path.truncate(len);
+                    }
+                    DynamicVariant::Struct(dynamic)
+                }
+            };
+            let mut dynamic =
+                DynamicEnum::new_with_index(a_enum.variant_index(), a_enum.variant_name(), variant);
+            dynamic.set_represented_type(a.get_represented_type_info());
+            Ok(Box::new(dynamic))
+        }
+        (ReflectRef::List(_), ReflectRef::List(_))
+        | (ReflectRef::Map(_), ReflectRef::Map(_))
+        | (ReflectRef::Value(_), ReflectRef::Value(_)) => Ok(snap()),
+        _ => Err(LerpError::MismatchedKinds {
+            path: path.clone(),
+            a: a.reflect_kind(),
+            b: b.reflect_kind(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::FromReflect;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Keyframe {
+        alpha: f32,
+        scale: f64,
+        count: i32,
+        label: String,
+    }
+
+    #[test]
+    #[cfg(feature = "glam")]
+    fn should_lerp_struct_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Transform {
+            alpha: f32,
+            scale: f64,
+            count: i32,
+            position: glam::Vec3,
+            label: String,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Transform>();
+
+        let a = Transform {
+            alpha: 0.0,
+            scale: 1.0,
+            count: 1,
+            position: glam::Vec3::ZERO,
+            label: "start".to_string(),
+        };
+        let b = Transform {
+            alpha: 1.0,
+            scale: 3.0,
+            count: 4,
+            position: glam::Vec3::new(2.0, 4.0, -8.0),
+            label: "end".to_string(),
+        };
+
+        let output = lerp_dynamic(&a, &b, 0.5, &registry).unwrap();
+        assert_eq!(
+            Some(Transform {
+                alpha: 0.5,
+                scale: 2.0,
+                // 2.5 rounds away from zero
+                count: 3,
+                position: glam::Vec3::new(1.0, 2.0, -4.0),
+                label: "end".to_string(),
+            }),
+            Transform::from_reflect(output.as_ref())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "glam")]
+    fn should_slerp_quat() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<glam::Quat>();
+
+        let a = glam::Quat::IDENTITY;
+        let b = glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_2);
+
+        let output = lerp_dynamic(&a, &b, 0.5, &registry).unwrap();
+        let output = output.downcast_ref::<glam::Quat>().unwrap();
+        assert!(output.is_normalized());
+        assert!(output.abs_diff_eq(
+            glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_4),
+            1e-5
+        ));
+    }
+
+    #[test]
+    fn should_be_exact_at_endpoints() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Keyframe>();
+
+        // Values that are not exactly representable would drift with `a + (b - a) * t`
+        let a = Keyframe {
+            alpha: 0.1,
+            scale: -0.3,
+            count: -7,
+            label: "start".to_string(),
+        };
+        let b = Keyframe {
+            alpha: 1e8,
+            scale: 0.7,
+            count: 1_000_000,
+            label: "end".to_string(),
+        };
+
+        let start = lerp_dynamic(&a, &b, 0.0, &registry).unwrap();
+        assert_eq!(Some(&a), Keyframe::from_reflect(start.as_ref()).as_ref());
+
+        let end = lerp_dynamic(&a, &b, 1.0, &registry).unwrap();
+        assert_eq!(Some(&b), Keyframe::from_reflect(end.as_ref()).as_ref());
+    }
+
+    #[test]
+    fn should_lerp_wide_integers_exactly() {
+        const BIG: u64 = 1 << 60;
+
+        assert_eq!(BIG + 2, (BIG + 1).lerp(&(BIG + 3), 0.5));
+        assert_eq!(BIG + 1, (BIG + 1).lerp(&(BIG + 3), 0.2));
+        assert_eq!(BIG + 3, 1.lerp(&(BIG + 3), 1.0));
+        assert_eq!(
+            -(BIG as i64) - 2,
+            (-(BIG as i64) - 3).lerp(&(-(BIG as i64) - 1), 0.5)
+        );
+        assert_eq!(0, i64::MIN.lerp(&i64::MAX, 0.5));
+        assert_eq!(u128::MAX - 1, (u128::MAX - 2).lerp(&u128::MAX, 0.5));
+        assert_eq!(i128::MAX, i128::MIN.lerp(&i128::MAX, 2.0));
+        assert_eq!(0_u8, 10_u8.lerp(&20, -2.0));
+    }
+
+    #[test]
+    fn should_error_on_mismatched_kinds() {
+        #[derive(Reflect)]
+        struct Outer {
+            inner: (f32, Inner),
+        }
+
+        #[derive(Reflect)]
+        struct Inner {
+            value: f32,
+        }
+
+        #[derive(Reflect)]
+        struct Other {
+            inner: (f32, Vec<f32>),
+        }
+
+        let registry = TypeRegistry::default();
+        let a = Outer {
+            inner: (0.0, Inner { value: 0.0 }),
+        };
+        let b = Other {
+            inner: (1.0, vec![1.0]),
+        };
+
+        let error = lerp_dynamic(&a, &b, 0.5, &registry).unwrap_err();
+        assert_eq!(
+            LerpError::MismatchedKinds {
+                path: ".inner.1".to_string(),
+                a: ReflectKind::Struct,
+                b: ReflectKind::List,
+            },
+            error
+        );
+        assert_eq!(
+            "cannot interpolate from a struct to a list at path `.inner.1`",
+            error.to_string()
+        );
+    }
+}
@@ -476,11 +476,18 @@
 mod array;
 mod fields;
 mod from_reflect;
+mod layout;
+mod lerp;
 mod list;
+mod lock;
 mod map;
+mod migrate;
+mod patch;
 mod path;
+mod pod;
 mod reflect;
 mod struct_trait;
+mod transparent;
 mod tuple;
 mod tuple_struct;
 mod type_info;
@@ -488,8 +495,16 @@ mod type_path;
 mod type_registry;
 
 mod impls {
+    #[cfg(feature = "arrayvec")]
+    mod arrayvec;
+    #[cfg(any(feature = "arrayvec", feature = "heapless"))]
+    mod bounded;
     #[cfg(feature = "glam")]
     mod glam;
+    #[cfg(feature = "heapless")]
+    mod heapless;
+    #[cfg(feature = "indexmap")]
+    mod indexmap;
     #[cfg(feature = "petgraph")]
     mod petgraph;
     #[cfg(feature = "smallvec")]
@@ -498,14 +513,19 @@ mod impls {
     mod smol_str;
 
     mod std;
+    mod sync;
     #[cfg(feature = "uuid")]
     mod uuid;
 }
 
 pub mod attributes;
 mod enums;
+pub mod import;
+pub mod query;
 pub mod serde;
 pub mod std_traits;
+pub mod table;
+pub mod test_suite;
 pub mod utility;
 
 pub mod prelude {
@@ -522,11 +542,18 @@ pub use array::*;
 pub use enums::*;
 pub use fields::*;
 pub use from_reflect::*;
+pub use layout::*;
+pub use lerp::*;
 pub use list::*;
+pub use lock::*;
 pub use map::*;
+pub use migrate::*;
+pub use patch::*;
 pub use path::*;
+pub use pod::*;
 pub use reflect::*;
 pub use struct_trait::*;
+pub use transparent::*;
 pub use tuple::*;
 pub use tuple_struct::*;
 pub use type_info::*;
@@ -580,6 +607,164 @@ pub mod __macro_exports {
     impl RegisterForReflection for DynamicArray {}
 
     impl RegisterForReflection for DynamicTuple {}
+
+    /// Applies `value` onto `target` with a single assignment if it is also a `T`.
+    ///
+    /// This is the fast path used by `Reflect::try_apply` for types deriving `Reflect`
+    /// with `#[reflect(pod)]`.
+    pub fn try_apply_pod<T: crate::PodStruct>(target: &mut T, value: &dyn crate::Reflect) -> bool {
+        crate::pod::try_apply_pod(target, value)
+    }
+
+    /// Helpers for validating `MinMax` custom attributes at compile time.
+    ///
+    /// The derive macro can't tell whether a path named `MinMax` refers to
+    /// [`MinMax`](crate::attributes::MinMax), so it asserts on `<IsMinMax<T>>::IS_MIN_MAX` instead.
+    /// This resolves to the inherent constant when `T` is `MinMax`,
+    /// and to the [`NotMinMax`](min_max::NotMinMax) fallback for any other type.
+    pub mod min_max {
+        use crate::attributes::MinMax;
+        use std::marker::PhantomData;
+
+        pub struct IsMinMax<T>(PhantomData<fn() -> T>);
+
+        impl IsMinMax<MinMax> {
+            pub const IS_MIN_MAX: bool = true;
+        }
+
+        pub trait NotMinMax {
+            const IS_MIN_MAX: bool = false;
+        }
+
+        impl<T> NotMinMax for IsMinMax<T> {}
+    }
+
+    /// Helpers for detecting trait implementations on types using `#[reflect(auto)]`.
+    ///
+    /// These rely on autoref-based specialization:
+    /// calling a method on `&&AutoImpl<T>` resolves to the `*Impl` trait when `T` implements
+    /// the corresponding standard trait, and to the `*Fallback` trait (which returns `None`) otherwise.
+    /// This only works when `T` is a concrete type, which is why `#[reflect(auto)]` rejects generic types.
+    pub mod auto {
+        use crate::std_traits::ReflectDefault;
+        use crate::{FromType, Reflect};
+        use std::any::Any;
+        use std::cmp::Ordering;
+        use std::fmt::{Debug, Formatter, Result};
+        use std::hash::{Hash, Hasher};
+        use std::marker::PhantomData;
+
+        pub struct AutoImpl<T>(PhantomData<fn() -> T>);
+
+        impl<T> AutoImpl<T> {
+            #[allow(clippy::new_without_default)]
+            pub const fn new() -> Self {
+                Self(PhantomData)
+            }
+        }
+
+        /// The default implementation of [`Reflect::debug`].
+        pub fn reflect_debug<T: Reflect>(value: &T, f: &mut Formatter<'_>) -> Result {
+            crate::reflect::reflect_debug(value, f)
+        }
+
+        fn reflect_hash<T: Hash + Any>(value: &T) -> u64 {
+            let mut hasher = crate::utility::reflect_hasher();
+            Hash::hash(&Any::type_id(value), &mut hasher);
+            Hash::hash(value, &mut hasher);
+            hasher.finish()
+        }
+
+        pub trait DebugImpl<T> {
+            fn debug_fn(&self) -> Option<fn(&T, &mut Formatter<'_>) -> Result>;
+        }
+
+        impl<T: Debug> DebugImpl<T> for &AutoImpl<T> {
+            fn debug_fn(&self) -> Option<fn(&T, &mut Formatter<'_>) -> Result> {
+                Some(<T as Debug>::fmt)
+            }
+        }
+
+        pub trait DebugFallback<T> {
+            fn debug_fn(&self) -> Option<fn(&T, &mut Formatter<'_>) -> Result> {
+                None
+            }
+        }
+
+        impl<T> DebugFallback<T> for AutoImpl<T> {}
+
+        pub trait PartialEqImpl<T> {
+            fn partial_eq_fn(&self) -> Option<fn(&T, &T) -> bool>;
+        }
+
+        impl<T: PartialEq> PartialEqImpl<T> for &AutoImpl<T> {
+            fn partial_eq_fn(&self) -> Option<fn(&T, &T) -> bool> {
+                Some(<T as PartialEq>::eq)
+            }
+        }
+
+        pub trait PartialEqFallback<T> {
+            fn partial_eq_fn(&self) -> Option<fn(&T, &T) -> bool> {
+                None
+            }
+        }
+
+        impl<T> PartialEqFallback<T> for AutoImpl<T> {}
+
+        pub trait PartialOrdImpl<T> {
+            fn partial_cmp_fn(&self) -> Option<fn(&T, &T) -> Option<Ordering>>;
+        }
+
+        impl<T: PartialOrd> PartialOrdImpl<T> for &AutoImpl<T> {
+            fn partial_cmp_fn(&self) -> Option<fn(&T, &T) -> Option<Ordering>> {
+                Some(<T as PartialOrd>::partial_cmp)
+            }
+        }
+
+        pub trait PartialOrdFallback<T> {
+            fn partial_cmp_fn(&self) -> Option<fn(&T, &T) -> Option<Ordering>> {
+                None
+            }
+        }
+
+        impl<T> PartialOrdFallback<T> for AutoImpl<T> {}
+
+        pub trait HashImpl<T> {
+            fn hash_fn(&self) -> Option<fn(&T) -> u64>;
+        }
+
+        impl<T: Hash + Any> HashImpl<T> for &AutoImpl<T> {
+            fn hash_fn(&self) -> Option<fn(&T) -> u64> {
+                Some(reflect_hash::<T>)
+            }
+        }
+
+        pub trait HashFallback<T> {
+            fn hash_fn(&self) -> Option<fn(&T) -> u64> {
+                None
+            }
+        }
+
+        impl<T> HashFallback<T> for AutoImpl<T> {}
+
+        pub trait DefaultImpl<T> {
+            fn default_data(&self) -> Option<ReflectDefault>;
+        }
+
+        impl<T: Reflect + Default> DefaultImpl<T> for &AutoImpl<T> {
+            fn default_data(&self) -> Option<ReflectDefault> {
+                Some(<ReflectDefault as FromType<T>>::from_type())
+            }
+        }
+
+        pub trait DefaultFallback<T> {
+            fn default_data(&self) -> Option<ReflectDefault> {
+                None
+            }
+        }
+
+        impl<T> DefaultFallback<T> for AutoImpl<T> {}
+    }
 }
 
 #[cfg(test)]
@@ -864,6 +1049,52 @@ mod tests {
             .unwrap_or_default());
     }
 
+    #[test]
+    fn should_deep_clone_concrete() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Outer {
+            inner: Inner,
+            list: Vec<Inner>,
+            map: HashMap<usize, Inner>,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Inner(Option<usize>);
+
+        #[derive(Reflect)]
+        struct Unregistered;
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Outer>();
+
+        let value = Outer {
+            inner: Inner(Some(1)),
+            list: vec![Inner(Some(2)), Inner(None)],
+            map: HashMap::from([(3, Inner(Some(3)))]),
+        };
+        let dynamic = value.clone_value();
+        assert!(dynamic.is_dynamic());
+
+        let cloned = deep_clone_concrete(dynamic.as_ref(), &registry).unwrap();
+        assert_eq!(TypeId::of::<Outer>(), cloned.as_any().type_id());
+        assert_eq!(Some(&value), cloned.downcast_ref::<Outer>());
+
+        // Nested values should be concrete at every level
+        let cloned = deep_clone_concrete(value.inner.clone_value().as_ref(), &registry).unwrap();
+        assert_eq!(TypeId::of::<Inner>(), cloned.as_any().type_id());
+
+        let registration = registry.get(TypeId::of::<Outer>()).unwrap();
+        let cloned = registration.clone_concrete(dynamic.as_ref()).unwrap();
+        assert_eq!(Some(&value), cloned.downcast_ref::<Outer>());
+
+        let error = deep_clone_concrete(&Unregistered, &registry).unwrap_err();
+        assert!(matches!(error, CloneError::NotRegistered { .. }));
+        assert!(error.to_string().contains("Unregistered"));
+
+        let error = deep_clone_concrete(&DynamicStruct::default(), &registry).unwrap_err();
+        assert!(matches!(error, CloneError::NotRepresented { .. }));
+    }
+
     #[test]
     fn from_reflect_should_allow_ignored_unnamed_fields() {
         #[derive(Reflect, Eq, PartialEq, Debug)]
@@ -1041,54 +1272,23 @@ mod tests {
             h: [2; 2],
         };
 
-        let mut foo_patch = DynamicStruct::default();
-        foo_patch.insert("a", 2u32);
-        foo_patch.insert("b", 2u32); // this should be ignored
-
-        let mut list = DynamicList::default();
-        list.push(3isize);
-        list.push(4isize);
-        list.push(5isize);
-        foo_patch.insert("c", list.clone_dynamic());
-
-        let mut map = DynamicMap::default();
-        map.insert(2usize, 3i8);
-        map.insert(3usize, 4i8);
-        foo_patch.insert("d", map);
-
-        let mut bar_patch = DynamicStruct::default();
-        bar_patch.insert("x", 2u32);
-        foo_patch.insert("e", bar_patch.clone_dynamic());
-
-        let mut tuple = DynamicTuple::default();
-        tuple.insert(2i32);
-        tuple.insert(list);
-        tuple.insert(bar_patch);
-        foo_patch.insert("f", tuple);
-
-        let mut composite = DynamicList::default();
-        composite.push({
-            let mut tuple = DynamicTuple::default();
-            tuple.insert({
-                let mut tuple_struct = DynamicTupleStruct::default();
-                tuple_struct.insert("new_string".to_string());
-                tuple_struct
-            });
-            tuple.insert({
-                let mut map = DynamicMap::default();
-                map.insert(1usize, {
-                    let mut struct_ = DynamicStruct::default();
-                    struct_.insert("x", 7u32);
-                    struct_
-                });
-                map
-            });
-            tuple
-        });
-        foo_patch.insert("g", composite);
-
-        let array = DynamicArray::from_vec(vec![2u32, 2u32]);
-        foo_patch.insert("h", array);
+        let foo_patch = dyn_struct! {
+            "a" => 2u32,
+            "b" => 2u32, // this should be ignored
+            "c" => dyn_list![3isize, 4, 5],
+            "d" => dyn_map! { 2usize => 3i8, 3 => 4 },
+            "e" => dyn_struct! { "x" => 2u32 },
+            "f" => dyn_tuple!(2i32, dyn_list![3isize, 4, 5], dyn_struct! { "x" => 2u32 }),
+            "g" => dyn_list![dyn_tuple!(
+                {
+                    let mut tuple_struct = DynamicTupleStruct::default();
+                    tuple_struct.insert("new_string".to_string());
+                    tuple_struct
+                },
+                dyn_map! { 1usize => dyn_struct! { "x" => 7u32 } },
+            )],
+            "h" => DynamicArray::from_vec(vec![2u32, 2u32]),
+        };
 
         foo.apply(&foo_patch);
 
@@ -1134,6 +1334,101 @@ mod tests {
         assert_eq!(new_foo, expected_new_foo);
     }
 
+    #[test]
+    fn dyn_macros_should_nest() {
+        let value = dyn_struct! {
+            "list" => dyn_list![dyn_tuple!(1u8, "a".to_string()), dyn_tuple!(2u8, "b".to_string())],
+            "map" => dyn_map! { "key".to_string() => dyn_list![1.0f32, 2.0] },
+            "variant" => dyn_enum!(Some(dyn_struct! { "x" => 1u32 })),
+        };
+
+        let ReflectRef::List(list) = value.field("list").unwrap().reflect_ref() else {
+            panic!("expected list");
+        };
+        assert_eq!(2, list.len());
+        assert_eq!(
+            Some(true),
+            list.get(1)
+                .unwrap()
+                .reflect_partial_eq(&(2u8, "b".to_string()))
+        );
+
+        let ReflectRef::Map(map) = value.field("map").unwrap().reflect_ref() else {
+            panic!("expected map");
+        };
+        let inner = map.get(&"key".to_string()).unwrap();
+        assert_eq!(Some(true), inner.reflect_partial_eq(&vec![1.0f32, 2.0]));
+
+        let ReflectRef::Enum(variant) = value.field("variant").unwrap().reflect_ref() else {
+            panic!("expected enum");
+        };
+        assert_eq!("Some", variant.variant_name());
+        assert_eq!(VariantType::Tuple, variant.variant_type());
+    }
+
+    #[test]
+    fn dyn_macros_should_allow_empty_and_trailing_commas() {
+        assert_eq!(0, dyn_struct! {}.field_len());
+        assert_eq!(0, dyn_list![].len());
+        assert_eq!(0, dyn_map! {}.len());
+        assert_eq!(0, dyn_tuple!().field_len());
+
+        assert_eq!(1, dyn_struct! { "a" => 1u8, }.field_len());
+        assert_eq!(2, dyn_list![1u8, 2,].len());
+        assert_eq!(1, dyn_map! { 1u8 => 2u8, }.len());
+        assert_eq!(2, dyn_tuple!(1u8, 2u8,).field_len());
+
+        let unit = dyn_enum!(None);
+        assert_eq!(VariantType::Unit, unit.variant_type());
+        let empty_tuple = dyn_enum!(Empty());
+        assert_eq!(VariantType::Tuple, empty_tuple.variant_type());
+        assert_eq!(0, empty_tuple.field_len());
+        let empty_struct = dyn_enum!(Empty {});
+        assert_eq!(VariantType::Struct, empty_struct.variant_type());
+        let trailing = dyn_enum!(Pair { a: 1u8, b: 2u8 });
+        assert_eq!(2, trailing.field_len());
+    }
+
+    #[test]
+    fn dyn_macros_should_accept_expressions() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Foo {
+            name: String,
+            values: Vec<u32>,
+            bar: Bar,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Bar {
+            A,
+            B { value: u32 },
+        }
+
+        let base = 10u32;
+        let name = |i: u32| format!("foo_{i}");
+        let patch = dyn_struct! {
+            "name" => name(base),
+            "values" => dyn_list![base, base + 1, base * 2],
+            "bar" => dyn_enum!(B { value: base.pow(2) }),
+        };
+
+        let mut foo = Foo {
+            name: String::new(),
+            values: Vec::new(),
+            bar: Bar::A,
+        };
+        foo.apply(&patch);
+
+        assert_eq!(
+            Foo {
+                name: "foo_10".to_string(),
+                values: vec![10, 11, 20],
+                bar: Bar::B { value: 100 },
+            },
+            foo
+        );
+    }
+
     #[test]
     fn should_auto_register_fields() {
         #[derive(Reflect)]
@@ -1252,6 +1547,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_auto_register_implemented_traits() {
+        #[derive(Reflect, Default, Debug, PartialEq)]
+        #[reflect(auto)]
+        struct Foo {
+            value: i32,
+        }
+
+        let a: Box<dyn Reflect> = Box::new(Foo { value: 123 });
+        let b: Box<dyn Reflect> = Box::new(Foo { value: 123 });
+        let c: Box<dyn Reflect> = Box::new(Foo { value: 321 });
+
+        assert_eq!(Some(true), a.reflect_partial_eq(&*b));
+        assert_eq!(Some(false), a.reflect_partial_eq(&*c));
+        assert_eq!(Some(false), a.reflect_partial_eq(&123_i32));
+        assert_eq!("Foo { value: 123 }", format!("{a:?}"));
+        assert!(a.reflect_hash().is_none());
+
+        let registration = Foo::get_type_registration();
+        let reflect_default = registration.data::<ReflectDefault>().unwrap();
+        let value = reflect_default.default();
+        assert_eq!(Some(true), value.reflect_partial_eq(&Foo::default()));
+
+        #[derive(Reflect, Hash, PartialEq, Eq, PartialOrd)]
+        #[reflect(auto)]
+        enum Bar {
+            A(i32),
+            B,
+        }
+
+        assert_eq!(Bar::A(1).reflect_hash(), Bar::A(1).reflect_hash());
+        assert_ne!(Bar::A(1).reflect_hash(), Bar::B.reflect_hash());
+        assert_eq!(
+            Some(std::cmp::Ordering::Less),
+            Bar::A(1).reflect_partial_cmp(&Bar::B)
+        );
+    }
+
+    #[test]
+    fn should_not_auto_register_unimplemented_traits() {
+        #[derive(Reflect, Clone)]
+        #[reflect(auto)]
+        struct Foo {
+            value: i32,
+        }
+
+        let a: Box<dyn Reflect> = Box::new(Foo { value: 123 });
+        let b: Box<dyn Reflect> = Box::new(Foo { value: 123 });
+
+        // Falls back to the regular reflection-based implementations
+        assert_eq!(Some(true), a.reflect_partial_eq(&*b));
+        assert_eq!("bevy_reflect::tests::Foo { value: 123 }", format!("{a:?}"));
+        assert!(a.reflect_hash().is_none());
+        assert!(a.reflect_partial_cmp(&*b).is_none());
+
+        let registration = Foo::get_type_registration();
+        assert!(registration.data::<ReflectDefault>().is_none());
+    }
+
     #[test]
     fn reflect_serialize() {
         #[derive(Reflect)]
@@ -1381,6 +1735,37 @@ mod tests {
         assert_eq!(y, Bar { x: 2 });
     }
 
+    #[test]
+    fn reflect_try_take() {
+        let value: Box<dyn Reflect> = Box::new(123_u32);
+        let error = value.try_take::<String>().unwrap_err();
+
+        assert_eq!(error.expected, "alloc::string::String");
+        assert_eq!(error.actual, "u32");
+        assert_eq!(
+            error.to_string(),
+            "expected value of type `alloc::string::String` but found `u32`"
+        );
+        assert_eq!(error.into_inner().take::<u32>().unwrap(), 123);
+
+        let value: Box<dyn Reflect> = Box::new(123_u32);
+        let error = value.try_downcast::<f32>().unwrap_err();
+        assert_eq!(error.expected, "f32");
+        assert_eq!(error.inner().downcast_ref::<u32>(), Some(&123));
+
+        let value: Box<dyn Reflect> = Box::new(123_u32);
+        assert_eq!(*value.try_downcast::<u32>().unwrap(), 123);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Attempted to push invalid value: expected value of type `alloc::string::String` but found `u32`"
+    )]
+    fn list_push_should_panic_with_type_names() {
+        let mut list: Vec<String> = Vec::new();
+        List::push(&mut list, Box::new(123_u32));
+    }
+
     #[test]
     fn not_dynamic_names() {
         let list = Vec::<usize>::new();
@@ -1488,6 +1873,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_reflect_const_generics() {
+        #[derive(Reflect, Debug, PartialEq, Default)]
+        struct Tile(u8);
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Grid<const W: usize, const H: usize> {
+            cells: [[Tile; W]; H],
+        }
+
+        type SmallGrid = Grid<2, 1>;
+        type LargeGrid = Grid<3, 2>;
+
+        assert_eq!("bevy_reflect::tests::Grid<2, 1>", SmallGrid::type_path());
+        assert_eq!("Grid<2, 1>", SmallGrid::short_type_path());
+        assert_eq!("bevy_reflect::tests::Grid<3, 2>", LargeGrid::type_path());
+        assert_eq!("Grid<3, 2>", LargeGrid::short_type_path());
+
+        let TypeInfo::Struct(info) = SmallGrid::type_info() else {
+            panic!("expected struct info");
+        };
+        assert!(info.field("cells").unwrap().is::<[[Tile; 2]; 1]>());
+        let TypeInfo::Struct(info) = LargeGrid::type_info() else {
+            panic!("expected struct info");
+        };
+        assert!(info.field("cells").unwrap().is::<[[Tile; 3]; 2]>());
+        assert_eq!(
+            "[[bevy_reflect::tests::Tile; 3]; 2]",
+            info.field("cells").unwrap().type_path()
+        );
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<SmallGrid>();
+        registry.register::<LargeGrid>();
+        assert!(registry.contains(TypeId::of::<SmallGrid>()));
+        assert!(registry.contains(TypeId::of::<LargeGrid>()));
+        assert!(registry
+            .get_with_type_path("bevy_reflect::tests::Grid<3, 2>")
+            .is_some());
+
+        let value = LargeGrid {
+            cells: [[Tile(1), Tile(2), Tile(3)], [Tile(4), Tile(5), Tile(6)]],
+        };
+        let serializer = ReflectSerializer::new(&value, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let output = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Some(value), LargeGrid::from_reflect(output.as_ref()));
+    }
+
     #[test]
     fn std_type_paths() {
         #[derive(Clone)]
@@ -1520,6 +1958,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_reflect_types_with_lifetimes() {
+        #[derive(Reflect)]
+        struct Foo<'a> {
+            value: u32,
+            #[reflect(ignore)]
+            _marker: PhantomData<&'a ()>,
+        }
+
+        #[derive(Reflect)]
+        enum Bar<'a, T: Reflect + TypePath> {
+            A(T),
+            B(#[reflect(ignore)] PhantomData<&'a ()>),
+        }
+
+        assert_eq!("bevy_reflect::tests::Foo", Foo::<'static>::type_path());
+        assert_eq!("Foo", Foo::<'static>::short_type_path());
+        assert_eq!(
+            "bevy_reflect::tests::Bar<u8>",
+            Bar::<'static, u8>::type_path()
+        );
+
+        let mut foo = Foo {
+            value: 1,
+            _marker: PhantomData,
+        };
+        foo.apply(&Foo {
+            value: 2,
+            _marker: PhantomData,
+        });
+        assert_eq!(2, foo.value);
+        assert!(matches!(Foo::type_info(), TypeInfo::Struct(_)));
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Bar<'static, u8>>();
+        assert!(registry.contains(TypeId::of::<Bar<'static, u8>>()));
+        assert!(registry.contains(TypeId::of::<u8>()));
+    }
+
     #[test]
     fn reflect_type_info() {
         // TypeInfo
@@ -1963,6 +2440,118 @@ mod tests {
         let _ = trait_object.as_reflect();
     }
 
+    #[test]
+    fn should_reflect_raw_and_reserved_identifiers() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Keywords {
+            r#type: u32,
+            r#match: String,
+            __this: f32,
+            __ref_struct: bool,
+            __param0: u8,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct KeywordsTuple(Keywords, i32);
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[allow(non_camel_case_types)]
+        enum KeywordsEnum {
+            r#loop,
+            r#type(u32, String),
+            Struct {
+                r#type: u32,
+                __this: f32,
+                __name_param: String,
+                __index_param: usize,
+                __value_param: bool,
+            },
+        }
+
+        let value = KeywordsTuple(
+            Keywords {
+                r#type: 1,
+                r#match: "match".to_string(),
+                __this: 2.0,
+                __ref_struct: true,
+                __param0: 3,
+            },
+            4,
+        );
+
+        // Field access and names
+        let Some(TypeInfo::Struct(info)) = value.0.get_represented_type_info() else {
+            panic!("expected struct info");
+        };
+        assert_eq!(
+            vec!["type", "match", "__this", "__ref_struct", "__param0"],
+            info.field_names().to_vec()
+        );
+        assert_eq!(Some(&1), value.0.get_field::<u32>("type"));
+        assert_eq!(Some(&2.0), value.0.get_field::<f32>("__this"));
+
+        // Path lookups
+        assert_eq!(Ok(&1), value.path::<u32>(".0.type"));
+        assert_eq!(Ok(&"match".to_string()), value.path::<String>(".0.match"));
+        assert_eq!(Ok(&3), value.path::<u8>(".0.__param0"));
+
+        // FromReflect
+        let dynamic = value.clone_value();
+        assert_eq!(
+            Some(&value),
+            KeywordsTuple::from_reflect(&*dynamic).as_ref()
+        );
+
+        // Serialization
+        let mut registry = TypeRegistry::default();
+        registry.register::<KeywordsTuple>();
+        registry.register::<KeywordsEnum>();
+        let output = ron::to_string(&ReflectSerializer::new(&value, &registry)).unwrap();
+        assert!(output.contains(r#"type:1,match:"match",__this:2.0"#));
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let round_trip = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            Some(&value),
+            KeywordsTuple::from_reflect(&*round_trip).as_ref()
+        );
+
+        // Enums
+        let values = [
+            KeywordsEnum::r#loop,
+            KeywordsEnum::r#type(5, "type".to_string()),
+            KeywordsEnum::Struct {
+                r#type: 6,
+                __this: 7.0,
+                __name_param: "name".to_string(),
+                __index_param: 8,
+                __value_param: true,
+            },
+        ];
+        assert_eq!("loop", values[0].variant_name());
+        assert_eq!("type", values[1].variant_name());
+        assert_eq!(Ok(&5), values[1].path::<u32>(".0"));
+        assert_eq!(Ok(&6), values[2].path::<u32>(".type"));
+        assert_eq!(Ok(&8), values[2].path::<usize>(".__index_param"));
+
+        for value in values {
+            let output = ron::to_string(&ReflectSerializer::new(&value, &registry)).unwrap();
+            let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+            let round_trip = ReflectDeserializer::new(&registry)
+                .deserialize(&mut deserializer)
+                .unwrap();
+            assert_eq!(
+                Some(&value),
+                KeywordsEnum::from_reflect(&*round_trip).as_ref()
+            );
+
+            let mut patched = KeywordsEnum::r#loop;
+            patched.apply(&*value.clone_value());
+            assert_eq!(value, patched);
+        }
+    }
+
     #[test]
     fn should_reflect_debug() {
         #[derive(Reflect)]
@@ -2121,6 +2710,148 @@ bevy_reflect::tests::Test {
         assert_eq!("123", format!("{:?}", foo));
     }
 
+    #[test]
+    fn should_skip_fields_in_hash_and_partial_eq() {
+        #[derive(Reflect, Hash, PartialEq)]
+        #[reflect(Hash, PartialEq)]
+        struct Foo {
+            a: u32,
+            #[reflect(skip_hash, skip_partial_eq)]
+            cache: u32,
+        }
+
+        #[derive(Reflect)]
+        struct Bar(u32, #[reflect(skip_partial_eq)] u32);
+
+        let foo1 = Foo { a: 1, cache: 2 };
+        let foo2 = Foo { a: 1, cache: 3 };
+        let foo3 = Foo { a: 2, cache: 2 };
+
+        assert_eq!(foo1.reflect_hash(), foo2.reflect_hash());
+        assert_ne!(foo1.reflect_hash(), foo3.reflect_hash());
+        assert_eq!(Some(true), foo1.reflect_partial_eq(&foo2));
+        assert_eq!(Some(false), foo1.reflect_partial_eq(&foo3));
+
+        assert_eq!(Some(true), Bar(1, 2).reflect_partial_eq(&Bar(1, 3)));
+        assert_eq!(Some(false), Bar(1, 2).reflect_partial_eq(&Bar(2, 2)));
+        assert_eq!(
+            Some(true),
+            Bar(1, 2).reflect_partial_eq(&Bar(1, 3).clone_dynamic())
+        );
+
+        let TypeInfo::Struct(info) = Foo::type_info() else {
+            panic!("expected struct info");
+        };
+        assert!(!info.field("a").unwrap().skip_hash());
+        assert!(!info.field("a").unwrap().skip_partial_eq());
+        assert!(info.field("cache").unwrap().skip_hash());
+        assert!(info.field("cache").unwrap().skip_partial_eq());
+
+        let TypeInfo::TupleStruct(info) = Bar::type_info() else {
+            panic!("expected tuple struct info");
+        };
+        assert!(!info.field_at(1).unwrap().skip_hash());
+        assert!(info.field_at(1).unwrap().skip_partial_eq());
+    }
+
+    #[test]
+    fn should_redact_fields() {
+        #[derive(Reflect)]
+        struct Account {
+            name: String,
+            #[reflect(redact)]
+            email: String,
+        }
+
+        #[derive(Reflect)]
+        struct Token(#[reflect(redact)] String);
+
+        let account = Account {
+            name: String::from("Bevy"),
+            email: String::from("bevy@example.com"),
+        };
+        let debug = format!("{:?}", account.as_reflect());
+        assert!(!debug.contains("bevy@example.com"));
+        assert_eq!(
+            "bevy_reflect::tests::Account { name: \"Bevy\", email: <redacted> }",
+            debug
+        );
+
+        // Dynamic values representing the type are masked as well
+        let debug = format!("{:?}", account.clone_dynamic());
+        assert!(!debug.contains("bevy@example.com"));
+
+        let token = Token(String::from("hunter2"));
+        assert_eq!(
+            "bevy_reflect::tests::Token(<redacted>)",
+            format!("{:?}", token.as_reflect())
+        );
+
+        let TypeInfo::Struct(info) = Account::type_info() else {
+            panic!("expected struct info");
+        };
+        assert!(!info.field("name").unwrap().is_redacted());
+        assert!(info.field("email").unwrap().is_redacted());
+
+        let TypeInfo::TupleStruct(info) = Token::type_info() else {
+            panic!("expected tuple struct info");
+        };
+        assert!(info.field_at(0).unwrap().is_redacted());
+    }
+
+    #[test]
+    fn should_handle_opaque_values_gracefully() {
+        #[derive(Reflect, Clone)]
+        #[reflect_value(opaque)]
+        struct Handle(u64);
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Handle>();
+
+        let TypeInfo::Value(info) = Handle::type_info() else {
+            panic!("expected value info");
+        };
+        assert!(info.is_opaque());
+        let TypeInfo::Value(info) = u64::type_info() else {
+            panic!("expected value info");
+        };
+        assert!(!info.is_opaque());
+
+        let mut handle = Handle(123);
+
+        // Debug
+        assert_eq!(
+            "<bevy_reflect::tests::Handle (opaque)>",
+            format!("{:?}", handle.as_reflect())
+        );
+
+        // Hash and PartialEq
+        assert!(handle.reflect_hash().is_none());
+        assert!(handle.reflect_partial_eq(&Handle(123)).is_none());
+
+        // Serialization
+        let serializer = ReflectSerializer::new(&handle, &registry);
+        let error = ron::ser::to_string(&serializer).unwrap_err().to_string();
+        assert!(error.contains("opaque value `bevy_reflect::tests::Handle`"));
+        assert!(error.contains("ReflectSerialize"));
+
+        let input = r#"{"bevy_reflect::tests::Handle": 123}"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let error = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("opaque value `bevy_reflect::tests::Handle`"));
+
+        // Apply
+        handle.apply(&Handle(321));
+        assert_eq!(321, handle.0);
+        assert!(matches!(
+            handle.try_apply(&123_u64),
+            Err(ApplyError::MismatchedTypes { .. })
+        ));
+    }
+
     #[test]
     fn should_allow_custom_where() {
         #[derive(Reflect)]
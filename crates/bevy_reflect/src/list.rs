@@ -1,13 +1,17 @@
 use std::any::{Any, TypeId};
+use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
+use std::ops::{Deref, DerefMut};
 
 use bevy_reflect_derive::impl_type_path;
+use thiserror::Error;
 
-use crate::utility::reflect_hasher;
+use crate::utility::{reflect_hasher, NonGenericTypeInfoCell};
 use crate::{
-    self as bevy_reflect, ApplyError, FromReflect, Reflect, ReflectKind, ReflectMut, ReflectOwned,
-    ReflectRef, TypeInfo, TypePath, TypePathTable,
+    self as bevy_reflect, ApplyError, FromReflect, FromType, GetTypeRegistration, Reflect,
+    ReflectFromPtr, ReflectKind, ReflectMut, ReflectOwned, ReflectRef, TypeInfo, TypePath,
+    TypePathTable, TypeRegistration, Typed,
 };
 
 /// A trait used to power [list-like] operations via [reflection].
@@ -103,6 +107,19 @@ pub trait List: Reflect {
             values: self.iter().map(|value| value.clone_value()).collect(),
         }
     }
+
+    /// Clones the list into an existing [`DynamicList`].
+    ///
+    /// The `target` is cleared before being filled with the list's values,
+    /// which allows its allocations to be reused across calls.
+    /// The result is equivalent to calling [`List::clone_dynamic`].
+    fn clone_into_dynamic(&self, target: &mut DynamicList) {
+        target.clear();
+        target.set_represented_type(self.get_represented_type_info());
+        for value in self.iter() {
+            target.push_box(value.clone_value());
+        }
+    }
 }
 
 /// A container for compile-time list info.
@@ -112,6 +129,8 @@ pub struct ListInfo {
     type_id: TypeId,
     item_type_path: TypePathTable,
     item_type_id: TypeId,
+    dynamic_items: bool,
+    capacity: Option<usize>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -124,6 +143,25 @@ impl ListInfo {
             type_id: TypeId::of::<TList>(),
             item_type_path: TypePathTable::of::<TItem>(),
             item_type_id: TypeId::of::<TItem>(),
+            dynamic_items: false,
+            capacity: None,
+            #[cfg(feature = "documentation")]
+            docs: None,
+        }
+    }
+
+    /// Create a new [`ListInfo`] for a list of type-erased `Box<dyn Reflect>` items, such as [`BoxedList`].
+    ///
+    /// Items of such a list may be of any reflected type,
+    /// so the item type is reported as `dyn Reflect`.
+    pub fn new_dynamic<TList: List + TypePath>() -> Self {
+        Self {
+            type_path: TypePathTable::of::<TList>(),
+            type_id: TypeId::of::<TList>(),
+            item_type_path: TypePathTable::of::<dyn Reflect>(),
+            item_type_id: TypeId::of::<dyn Reflect>(),
+            dynamic_items: true,
+            capacity: None,
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -135,6 +173,16 @@ impl ListInfo {
         Self { docs, ..self }
     }
 
+    /// Sets the maximum number of items this list can hold.
+    ///
+    /// This should be used for fixed-capacity lists, such as `ArrayVec`.
+    pub fn with_capacity(self, capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..self
+        }
+    }
+
     /// A representation of the type path of the list.
     ///
     /// Provides dynamic access to all methods on [`TypePath`].
@@ -179,6 +227,18 @@ impl ListInfo {
         TypeId::of::<T>() == self.item_type_id
     }
 
+    /// Returns `true` if the items of this list are type-erased `Box<dyn Reflect>` values.
+    ///
+    /// The concrete type of each item can only be determined at runtime.
+    pub fn has_dynamic_items(&self) -> bool {
+        self.dynamic_items
+    }
+
+    /// The maximum number of items this list can hold, if it has a fixed capacity.
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
     /// The docstring of this list, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -186,6 +246,36 @@ impl ListInfo {
     }
 }
 
+/// Creates a [`DynamicList`] from a list of values.
+///
+/// All values must have the same type,
+/// so integer literals without a suffix are inferred from the other items,
+/// such as `dyn_list![3isize, 4, 5]` creating a list of three `isize` values.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{dyn_list, List, Reflect};
+/// let list = dyn_list![3isize, 4, 5];
+/// assert_eq!(Some(true), list.reflect_partial_eq(&vec![3isize, 4, 5]));
+///
+/// let empty = dyn_list![];
+/// assert_eq!(0, empty.len());
+/// ```
+#[macro_export]
+macro_rules! dyn_list {
+    () => {
+        $crate::DynamicList::default()
+    };
+    ($($value:expr),+ $(,)?) => {{
+        let mut dynamic_list = $crate::DynamicList::default();
+        for value in [$($value),+] {
+            dynamic_list.push(value);
+        }
+        dynamic_list
+    }};
+}
+
 /// A list of reflected values.
 #[derive(Default)]
 pub struct DynamicList {
@@ -221,6 +311,18 @@ impl DynamicList {
     pub fn push_box(&mut self, value: Box<dyn Reflect>) {
         self.values.push(value);
     }
+
+    /// Removes all values from the list, keeping the allocated memory for reuse.
+    ///
+    /// The represented type is left unchanged.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    /// Returns the number of values the list can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.values.capacity()
+    }
 }
 
 impl List for DynamicList {
@@ -385,6 +487,205 @@ impl IntoIterator for DynamicList {
     }
 }
 
+/// A list of type-erased values, each of which may be of any reflected type.
+///
+/// Unlike [`DynamicList`], this is a concrete type which can be used as a field of a reflected type,
+/// such as a plugin list holding differently typed settings.
+///
+/// Reflection is implemented on this newtype rather than on `Vec<Box<dyn Reflect>>` itself.
+/// Method resolution picks trait methods on `Vec` before slice methods reached through deref,
+/// so implementing [`List`] for the bare `Vec` would silently change what `.get()`, `.get_mut()`,
+/// and `.iter()` return for every `Vec<Box<dyn Reflect>>` with [`List`] in scope.
+///
+/// Its [`ListInfo`] reports [dynamic items],
+/// so each item is serialized along with its type path and must have its type registered.
+///
+/// # Items
+///
+/// Each item is stored exactly as it was given, without being converted with [`FromReflect`]:
+/// * [`List::push`] and [`List::insert`] store the boxed value as-is.
+/// * [`Reflect::apply`] applies to existing items in place,
+///   and appends the [`clone_value`] of any extra items.
+/// * [`FromReflect::from_reflect`] stores the [`clone_value`] of each item.
+///
+/// Since [`clone_value`] returns a dynamic type, such as a [`DynamicStruct`], for anything but value types,
+/// items built through the latter two, or through deserialization, are generally not of their concrete type.
+/// They can be converted back using the [`ReflectFromReflect`] type data registered for their represented type.
+///
+/// [dynamic items]: ListInfo::has_dynamic_items
+/// [`clone_value`]: Reflect::clone_value
+/// [`DynamicStruct`]: crate::DynamicStruct
+/// [`ReflectFromReflect`]: crate::ReflectFromReflect
+#[derive(Default, Debug)]
+pub struct BoxedList(pub Vec<Box<dyn Reflect>>);
+
+impl Deref for BoxedList {
+    type Target = Vec<Box<dyn Reflect>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for BoxedList {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Vec<Box<dyn Reflect>>> for BoxedList {
+    fn from(values: Vec<Box<dyn Reflect>>) -> Self {
+        Self(values)
+    }
+}
+
+impl FromIterator<Box<dyn Reflect>> for BoxedList {
+    fn from_iter<I: IntoIterator<Item = Box<dyn Reflect>>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl List for BoxedList {
+    #[inline]
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        self.0.get(index).map(|value| &**value)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        self.0.get_mut(index).map(|value| &mut **value)
+    }
+
+    fn insert(&mut self, index: usize, value: Box<dyn Reflect>) {
+        self.0.insert(index, value);
+    }
+
+    fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+        self.0.remove(index)
+    }
+
+    fn push(&mut self, value: Box<dyn Reflect>) {
+        self.0.push(value);
+    }
+
+    fn pop(&mut self) -> Option<Box<dyn Reflect>> {
+        self.0.pop()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    fn iter(&self) -> ListIter<'_> {
+        ListIter::new(self)
+    }
+
+    #[inline]
+    fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+        self.0
+    }
+}
+
+impl Reflect for BoxedList {
+    fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+        Some(<Self as Typed>::type_info())
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+        self
+    }
+
+    fn as_reflect(&self) -> &dyn Reflect {
+        self
+    }
+
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        list_apply(self, value);
+    }
+
+    fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+        list_try_apply(self, value)
+    }
+
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+        *self = value.take()?;
+        Ok(())
+    }
+
+    fn reflect_kind(&self) -> ReflectKind {
+        ReflectKind::List
+    }
+
+    fn reflect_ref(&self) -> ReflectRef<'_> {
+        ReflectRef::List(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::List(self)
+    }
+
+    fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+        ReflectOwned::List(self)
+    }
+
+    fn clone_value(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone_dynamic())
+    }
+
+    fn reflect_hash(&self) -> Option<u64> {
+        list_hash(self)
+    }
+
+    fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+        list_partial_eq(self, value)
+    }
+}
+
+impl Typed for BoxedList {
+    fn type_info() -> &'static TypeInfo {
+        static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+        CELL.get_or_set(|| TypeInfo::List(ListInfo::new_dynamic::<Self>()))
+    }
+}
+
+impl_type_path!((in bevy_reflect) BoxedList);
+
+impl GetTypeRegistration for BoxedList {
+    fn get_type_registration() -> TypeRegistration {
+        let mut registration = TypeRegistration::of::<Self>();
+        registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+        registration
+    }
+}
+
+impl FromReflect for BoxedList {
+    fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+        let ReflectRef::List(ref_list) = reflect.reflect_ref() else {
+            return None;
+        };
+
+        Some(ref_list.iter().map(Reflect::clone_value).collect())
+    }
+}
+
 /// An iterator over an [`List`].
 pub struct ListIter<'a> {
     list: &'a dyn List,
@@ -419,10 +720,14 @@ impl<'a> Iterator for ListIter<'a> {
 impl<'a> ExactSizeIterator for ListIter<'a> {}
 
 /// Returns the `u64` hash of the given [list](List).
+///
+/// The hash only depends on the list's elements, not its concrete type,
+/// so that it agrees with [`list_partial_eq`] for lists of different types,
+/// such as a [`Vec`] and its [`DynamicList`] representation.
 #[inline]
 pub fn list_hash<L: List>(list: &L) -> Option<u64> {
     let mut hasher = reflect_hasher();
-    Any::type_id(list).hash(&mut hasher);
+    ReflectKind::List.hash(&mut hasher);
     list.len().hash(&mut hasher);
     for value in list.iter() {
         hasher.write_u64(value.reflect_hash()?);
@@ -504,6 +809,67 @@ pub fn list_partial_eq<L: List>(a: &L, b: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// An error returned by [`sort_list`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SortError {
+    #[error("elements at indices {a} and {b} cannot be compared")]
+    /// [`Reflect::reflect_partial_cmp`] returned `None` for the elements at the given indices.
+    Incomparable { a: usize, b: usize },
+}
+
+/// Sorts a [`List`] in place, using [`Reflect::reflect_partial_cmp`] to compare its elements.
+///
+/// The sort is stable: elements which compare as equal retain their relative order.
+///
+/// # Errors
+///
+/// Returns [`SortError::Incomparable`] with the indices of the first pair of elements
+/// that could not be compared, such as a `NaN` float or elements of different types.
+/// In this case, the list is left unmodified.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{sort_list, List};
+/// let mut list: Vec<i32> = vec![3, 1, 2];
+/// sort_list(&mut list).unwrap();
+/// assert_eq!(vec![1, 2, 3], list);
+/// ```
+pub fn sort_list(list: &mut dyn List) -> Result<(), SortError> {
+    let mut error = None;
+    let mut indices = (0..list.len()).collect::<Vec<_>>();
+    indices.sort_by(|&a, &b| {
+        let ordering = list
+            .get(a)
+            .zip(list.get(b))
+            .and_then(|(a, b)| a.reflect_partial_cmp(b));
+        ordering.unwrap_or_else(|| {
+            error.get_or_insert(SortError::Incomparable {
+                a: a.min(b),
+                b: a.max(b),
+            });
+            Ordering::Equal
+        })
+    });
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    let mut elements = Vec::with_capacity(indices.len());
+    while let Some(element) = list.pop() {
+        elements.push(Some(element));
+    }
+    elements.reverse();
+
+    for index in indices {
+        // Each index appears exactly once in the permutation
+        list.push(elements[index].take().unwrap());
+    }
+
+    Ok(())
+}
+
 /// The default debug formatter for [`List`] types.
 ///
 /// # Example
@@ -532,9 +898,16 @@ pub fn list_debug(dyn_list: &dyn List, f: &mut Formatter<'_>) -> std::fmt::Resul
 
 #[cfg(test)]
 mod tests {
-    use super::DynamicList;
-    use crate::{Reflect, ReflectRef};
+    use super::{BoxedList, DynamicList};
+    use crate as bevy_reflect;
+    use crate::serde::{ReflectDeserializer, ReflectSerializer};
+    use crate::{
+        sort_list, DynamicStruct, FromReflect, List, Reflect, ReflectRef, SortError, TypeInfo,
+        TypePath, TypeRegistry, Typed,
+    };
+    use serde::de::DeserializeSeed;
     use std::assert_eq;
+    use std::cmp::Ordering;
 
     #[test]
     fn test_into_iter() {
@@ -573,4 +946,162 @@ mod tests {
         assert!(iter.next().is_none());
         assert!(iter.index == SIZE);
     }
+
+    #[test]
+    fn should_clone_into_dynamic() {
+        let mut target = DynamicList::default();
+
+        vec![1_u32, 2, 3, 4].clone_into_dynamic(&mut target);
+        let capacity = target.capacity();
+
+        let value = vec![5_u32, 6];
+        value.clone_into_dynamic(&mut target);
+
+        assert_eq!(target.len(), 2);
+        assert_eq!(target.capacity(), capacity);
+        assert!(target
+            .reflect_partial_eq(&value.clone_dynamic())
+            .unwrap_or_default());
+        assert!(target.reflect_partial_eq(&value).unwrap_or_default());
+    }
+
+    #[test]
+    fn should_sort_list() {
+        let mut numbers = vec![3_i32, -1, 2, 0];
+        sort_list(&mut numbers).unwrap();
+        assert_eq!(vec![-1, 0, 2, 3], numbers);
+
+        let mut strings = vec![String::from("b"), String::from("c"), String::from("a")];
+        sort_list(&mut strings).unwrap();
+        assert_eq!(vec!["a", "b", "c"], strings);
+
+        let mut dynamic = DynamicList::default();
+        dynamic.push((2_u8, 'a'));
+        dynamic.push((1_u8, 'b'));
+        dynamic.push((1_u8, 'a'));
+        sort_list(&mut dynamic).unwrap();
+        let expected = vec![(1_u8, 'a'), (1_u8, 'b'), (2_u8, 'a')];
+        assert!(dynamic.reflect_partial_eq(&expected).unwrap_or_default());
+    }
+
+    #[test]
+    fn should_sort_list_with_derived_partial_ord() {
+        #[derive(Reflect, Debug, PartialEq, PartialOrd)]
+        #[reflect(PartialOrd)]
+        struct Level(u32);
+
+        let mut list = vec![Level(3), Level(1), Level(2)];
+        sort_list(&mut list).unwrap();
+        assert_eq!(vec![Level(1), Level(2), Level(3)], list);
+
+        fn compare_priority(a: &Priority, b: &dyn Reflect) -> Option<Ordering> {
+            let b = b.downcast_ref::<Priority>()?;
+            a.0.partial_cmp(&b.0)
+        }
+
+        #[derive(Reflect)]
+        #[reflect(PartialOrd(compare_priority))]
+        struct Priority(u32, String);
+
+        let mut list = vec![
+            Priority(2, String::from("b")),
+            Priority(1, String::from("c")),
+            Priority(2, String::from("a")),
+        ];
+        sort_list(&mut list).unwrap();
+
+        // The sort is stable
+        let labels = list
+            .into_iter()
+            .map(|priority| priority.1)
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["c", "b", "a"], labels);
+    }
+
+    #[test]
+    fn should_not_sort_incomparable_list() {
+        let mut floats = vec![1.0_f32, f32::NAN, 0.0];
+        let result = sort_list(&mut floats);
+        assert!(matches!(result, Err(SortError::Incomparable { .. })));
+        // The list is left unmodified
+        assert_eq!(1.0, floats[0]);
+        assert!(floats[1].is_nan());
+        assert_eq!(0.0, floats[2]);
+
+        let mut mixed = DynamicList::default();
+        mixed.push(1_i32);
+        mixed.push(String::from("two"));
+        assert_eq!(
+            Err(SortError::Incomparable { a: 0, b: 1 }),
+            sort_list(&mut mixed)
+        );
+    }
+
+    #[test]
+    fn should_reflect_boxed_list() {
+        #[derive(Reflect)]
+        struct Plugins {
+            items: BoxedList,
+        }
+
+        #[derive(Reflect)]
+        struct Foo {
+            value: i32,
+        }
+
+        let TypeInfo::List(info) = <BoxedList as Typed>::type_info() else {
+            panic!("expected list info");
+        };
+        assert!(info.has_dynamic_items());
+        assert_eq!(info.item_type_id(), std::any::TypeId::of::<dyn Reflect>());
+        assert_eq!(
+            <BoxedList as TypePath>::type_path(),
+            "bevy_reflect::BoxedList"
+        );
+
+        // `Box<dyn Reflect>` must keep reporting the type path of its underlying value
+        let boxed: Box<dyn Reflect> = Box::new(123_i32);
+        assert_eq!(boxed.reflect_type_path(), "i32");
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Plugins>();
+
+        let mut value = Plugins {
+            items: BoxedList(vec![Box::new(123_i32), Box::new(String::from("hello"))]),
+        };
+
+        let serializer = ReflectSerializer::new(&value, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(
+            r#"{"bevy_reflect::list::tests::Plugins":(items:[{"i32":123},{"alloc::string::String":"hello"}])}"#,
+            output
+        );
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let dynamic = ReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let deserialized = <Plugins as FromReflect>::from_reflect(&*dynamic).unwrap();
+        assert_eq!(deserialized.items.len(), 2);
+        assert_eq!(deserialized.items[0].downcast_ref::<i32>(), Some(&123));
+        assert_eq!(
+            deserialized.items[1].downcast_ref::<String>(),
+            Some(&String::from("hello"))
+        );
+
+        // Items are not converted to their concrete type
+        let item = BoxedList::from_reflect(&BoxedList(vec![Box::new(Foo { value: 1 })])).unwrap();
+        assert!(item[0].is::<DynamicStruct>());
+        assert!(item[0].represents::<Foo>());
+
+        // Items accept any reflected value without conversion
+        value.items.push(Box::new(1.5_f32));
+        assert_eq!(value.items[2].downcast_ref::<f32>(), Some(&1.5));
+
+        let mut patch = value.items.clone_dynamic();
+        patch.get_mut(0).unwrap().apply(&456_i32);
+        value.items.apply(&patch);
+        assert_eq!(value.items[0].downcast_ref::<i32>(), Some(&456));
+        assert_eq!(value.items.len(), 3);
+    }
 }
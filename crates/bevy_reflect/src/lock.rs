@@ -0,0 +1,226 @@
+use crate::{FromReflect, FromType, GetTypeRegistration, Reflect, TypePath};
+use std::any::TypeId;
+use std::sync::{Mutex, RwLock};
+use thiserror::Error;
+
+/// An error returned when the lock guarding a reflected value is poisoned.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("the lock of `{type_path}` is poisoned")]
+pub struct PoisonedLockError {
+    /// The [type path] of the lock.
+    ///
+    /// [type path]: TypePath::type_path
+    pub type_path: &'static str,
+}
+
+/// Type data for reflected lock types, such as [`Mutex`] and [`RwLock`].
+///
+/// Lock types are reflected as [values](crate::ReflectKind::Value),
+/// since their contents can only be accessed while the lock is held.
+/// This type data allows the reflection serializers to operate on an owned
+/// snapshot of the inner value, taken while holding the lock.
+///
+/// A [`ReflectLock`] for type `T` can be obtained via [`FromType::from_type`].
+#[derive(Clone)]
+pub struct ReflectLock {
+    inner_type_id: TypeId,
+    inner_type_path: &'static str,
+    snapshot: fn(&dyn Reflect) -> Option<Result<Box<dyn Reflect>, PoisonedLockError>>,
+    from_inner: fn(&dyn Reflect) -> Option<Box<dyn Reflect>>,
+}
+
+impl ReflectLock {
+    /// The [`TypeId`] of the guarded type.
+    pub fn inner_type_id(&self) -> TypeId {
+        self.inner_type_id
+    }
+
+    /// The [type path] of the guarded type.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn inner_type_path(&self) -> &'static str {
+        self.inner_type_path
+    }
+
+    /// Acquires the lock of the given value and clones its contents.
+    ///
+    /// Returns `None` if `value` is not of the type this data was created for.
+    pub fn snapshot(
+        &self,
+        value: &dyn Reflect,
+    ) -> Option<Result<Box<dyn Reflect>, PoisonedLockError>> {
+        (self.snapshot)(value)
+    }
+
+    /// Creates a new lock around the given inner value.
+    ///
+    /// Returns `None` if the inner type could not be created from `inner` via [`FromReflect`].
+    pub fn from_inner(&self, inner: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.from_inner)(inner)
+    }
+}
+
+macro_rules! impl_from_type_for_lock {
+    ($ty:ident, $lock:ident) => {
+        impl<T: FromReflect + TypePath + GetTypeRegistration> FromType<$ty<T>> for ReflectLock {
+            fn from_type() -> Self {
+                ReflectLock {
+                    inner_type_id: TypeId::of::<T>(),
+                    inner_type_path: T::type_path(),
+                    snapshot: |value| {
+                        let guard = value.downcast_ref::<$ty<T>>()?.$lock();
+                        Some(guard.map(|inner| inner.clone_value()).map_err(|_| {
+                            PoisonedLockError {
+                                type_path: <$ty<T>>::type_path(),
+                            }
+                        }))
+                    },
+                    from_inner: |inner| {
+                        T::from_reflect(inner)
+                            .map(|inner| Box::new($ty::new(inner)) as Box<dyn Reflect>)
+                    },
+                }
+            }
+        }
+    };
+}
+
+impl_from_type_for_lock!(Mutex, lock);
+impl_from_type_for_lock!(RwLock, read);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+    use crate::{ApplyError, DynamicStruct, TypeRegistry};
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Inventory {
+        gold: u32,
+        items: Vec<String>,
+    }
+
+    fn inventory() -> Inventory {
+        Inventory {
+            gold: 10,
+            items: vec![String::from("sword")],
+        }
+    }
+
+    fn poisoned() -> Mutex<Inventory> {
+        let lock = Mutex::new(inventory());
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _guard = lock.lock().unwrap();
+            panic!("poison the lock");
+        }));
+        assert!(lock.is_poisoned());
+        lock
+    }
+
+    #[test]
+    fn should_serialize_lock() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Mutex<Inventory>>();
+        registry.register::<RwLock<Inventory>>();
+
+        let value = Mutex::new(inventory());
+        let serializer = TypedReflectSerializer::new(&value, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(r#"(gold:10,items:["sword"])"#, output);
+
+        let registration = registry.get(TypeId::of::<Mutex<Inventory>>()).unwrap();
+        let deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let deserialized = deserializer.deserialize(&mut ron_deserializer).unwrap();
+        let deserialized = deserialized.downcast::<Mutex<Inventory>>().unwrap();
+        assert_eq!(inventory(), deserialized.into_inner().unwrap());
+
+        let value = RwLock::new(inventory());
+        let serializer = TypedReflectSerializer::new(&value, &registry);
+        assert_eq!(output, ron::ser::to_string(&serializer).unwrap());
+    }
+
+    #[test]
+    fn should_apply_through_lock() {
+        let mut value = Mutex::new(inventory());
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("gold", 25_u32);
+        value.apply(&patch);
+        assert_eq!(25, value.lock().unwrap().gold);
+
+        value.apply(&Mutex::new(Inventory {
+            gold: 50,
+            items: vec![String::from("shield")],
+        }));
+        assert_eq!(50, value.lock().unwrap().gold);
+        assert_eq!(vec![String::from("shield")], value.lock().unwrap().items);
+    }
+
+    #[test]
+    fn should_compare_locks() {
+        let a = Mutex::new(inventory());
+        let b = Mutex::new(inventory());
+        let c = Mutex::new(Inventory {
+            gold: 0,
+            items: Vec::new(),
+        });
+
+        assert_eq!(Some(true), a.reflect_partial_eq(&b));
+        assert_eq!(Some(false), a.reflect_partial_eq(&c));
+        assert_eq!(Some(true), a.reflect_partial_eq(&a));
+        assert_eq!(Some(false), a.reflect_partial_eq(&inventory()));
+
+        let cloned = a.clone_value();
+        assert_eq!(Some(true), a.reflect_partial_eq(cloned.as_ref()));
+        assert_eq!(
+            Some(true),
+            RwLock::new(1_u32).reflect_partial_eq(&RwLock::new(1_u32))
+        );
+    }
+
+    #[test]
+    fn should_clone_into_lock() {
+        let value = RwLock::new(inventory());
+        let cloned = value.clone_value();
+        let cloned = cloned.downcast_ref::<RwLock<Inventory>>().unwrap();
+        assert_eq!(inventory(), *cloned.read().unwrap());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "failed to clone `std::sync::Mutex<bevy_reflect::lock::tests::Unclonable>`"
+    )]
+    fn should_panic_when_clone_cannot_be_created() {
+        #[derive(Reflect)]
+        #[reflect(from_reflect = false)]
+        struct Unclonable;
+
+        impl FromReflect for Unclonable {
+            fn from_reflect(_: &dyn Reflect) -> Option<Self> {
+                None
+            }
+        }
+
+        Mutex::new(Unclonable).clone_value();
+    }
+
+    #[test]
+    fn should_handle_poisoned_lock() {
+        let mut value = poisoned();
+
+        let result = value.try_apply(&Mutex::new(inventory()));
+        assert!(matches!(result, Err(ApplyError::PoisonedLock { .. })));
+        assert_eq!(None, value.reflect_partial_eq(&Mutex::new(inventory())));
+        assert_eq!("Mutex(<poisoned>)", format!("{:?}", value.as_reflect()));
+        assert!(Mutex::<Inventory>::from_reflect(&value).is_none());
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Mutex<Inventory>>();
+        let serializer = TypedReflectSerializer::new(&value, &registry);
+        let error = ron::ser::to_string(&serializer).unwrap_err();
+        assert!(error.to_string().contains("is poisoned"), "{error}");
+    }
+}
@@ -3,10 +3,11 @@ use std::fmt::{Debug, Formatter};
 
 use bevy_reflect_derive::impl_type_path;
 use bevy_utils::{Entry, HashMap};
+use thiserror::Error;
 
 use crate::{
-    self as bevy_reflect, ApplyError, Reflect, ReflectKind, ReflectMut, ReflectOwned, ReflectRef,
-    TypeInfo, TypePath, TypePathTable,
+    self as bevy_reflect, ApplyError, FromReflect, Reflect, ReflectKind, ReflectMut, ReflectOwned,
+    ReflectRef, TypeInfo, TypePath, TypePathTable,
 };
 
 /// A trait used to power [map-like] operations via [reflection].
@@ -74,6 +75,19 @@ pub trait Map: Reflect {
     /// Clones the map, producing a [`DynamicMap`].
     fn clone_dynamic(&self) -> DynamicMap;
 
+    /// Clones the map into an existing [`DynamicMap`].
+    ///
+    /// The `target` is cleared before being filled with the map's entries,
+    /// which allows its allocations to be reused across calls.
+    /// The result is equivalent to calling [`Map::clone_dynamic`].
+    fn clone_into_dynamic(&self, target: &mut DynamicMap) {
+        target.clear();
+        target.set_represented_type(self.get_represented_type_info());
+        for (key, value) in self.iter() {
+            target.insert_boxed(key.clone_value(), value.clone_value());
+        }
+    }
+
     /// Inserts a key-value pair into the map.
     ///
     /// If the map did not have this key present, `None` is returned.
@@ -219,6 +233,35 @@ macro_rules! hash_error {
     }}
 }
 
+/// Creates a [`DynamicMap`] from a list of `key => value` pairs.
+///
+/// All keys must have the same type, as must all values,
+/// so integer literals without a suffix are inferred from the other entries.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{dyn_map, Map};
+/// let map = dyn_map! {
+///     2usize => 3i8,
+///     3 => 4,
+/// };
+/// assert_eq!(Some(&4i8), map.get(&3usize).unwrap().downcast_ref());
+/// ```
+#[macro_export]
+macro_rules! dyn_map {
+    () => {
+        $crate::DynamicMap::default()
+    };
+    ($($key:expr => $value:expr),+ $(,)?) => {{
+        let mut dynamic_map = $crate::DynamicMap::default();
+        for (key, value) in [$(($key, $value)),+] {
+            dynamic_map.insert(key, value);
+        }
+        dynamic_map
+    }};
+}
+
 /// An ordered mapping between reflected values.
 #[derive(Default)]
 pub struct DynamicMap {
@@ -251,6 +294,19 @@ impl DynamicMap {
     pub fn insert<K: Reflect, V: Reflect>(&mut self, key: K, value: V) {
         self.insert_boxed(Box::new(key), Box::new(value));
     }
+
+    /// Removes all entries from the map, keeping the allocated memory for reuse.
+    ///
+    /// The represented type is left unchanged.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.indices.clear();
+    }
+
+    /// Returns the number of entries the map can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.values.capacity()
+    }
 }
 
 impl Map for DynamicMap {
@@ -330,6 +386,12 @@ impl Map for DynamicMap {
             .indices
             .remove(&key.reflect_hash().expect(hash_error!(key)))?;
         let (_key, value) = self.values.remove(index);
+        // Entries after the removed one have shifted down by one
+        for other in self.indices.values_mut() {
+            if *other > index {
+                *other -= 1;
+            }
+        }
         Some(value)
     }
 }
@@ -565,11 +627,128 @@ pub fn map_try_apply<M: Map>(a: &mut M, b: &dyn Reflect) -> Result<(), ApplyErro
     Ok(())
 }
 
+/// An error returned by [`map_from_reflect_strict`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum FromReflectMapError {
+    /// The value to convert was not a map.
+    #[error("expected a map but found a {kind}")]
+    NotAMap {
+        /// The kind of the value that was given instead.
+        kind: ReflectKind,
+    },
+    /// The [`FromReflect`] implementation of the map type returned `None`,
+    /// usually because one of its keys or values could not be converted.
+    #[error("failed to convert the entries of the map into `{type_path}`")]
+    ConversionFailed {
+        /// The [type name] of the map type.
+        ///
+        /// [type name]: std::any::type_name
+        type_path: &'static str,
+    },
+    /// The map contained multiple entries for the same key.
+    #[error("duplicate key `{key}` in map")]
+    DuplicateKey {
+        /// The [debug] rendering of the duplicated key.
+        ///
+        /// [debug]: Reflect::debug
+        key: String,
+    },
+}
+
+/// Creates a map of type `M` from a reflected map, rejecting duplicate keys.
+///
+/// Dynamic maps may contain multiple entries that convert to the same key,
+/// such as a concrete key and a dynamic representation of it.
+/// [`FromReflect`] implementations for maps keep the last of such entries,
+/// whereas this function returns [`FromReflectMapError::DuplicateKey`] naming the first key
+/// equal to an earlier one.
+///
+/// # Errors
+///
+/// Returns [`FromReflectMapError::NotAMap`] if `reflect` is not a map,
+/// and [`FromReflectMapError::ConversionFailed`] if it could not be converted into `M`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{map_from_reflect_strict, DynamicEnum, DynamicMap, DynamicTuple, FromReflectMapError, Map};
+/// # use std::collections::HashMap;
+/// let mut dynamic = DynamicMap::default();
+/// dynamic.insert(Some(1_u32), 10_u32);
+///
+/// let map = map_from_reflect_strict::<HashMap<Option<u32>, u32>>(&dynamic);
+/// assert_eq!(Ok(HashMap::from([(Some(1), 10)])), map);
+///
+/// // A dynamic key which converts to the same key as an existing entry
+/// let mut key = DynamicTuple::default();
+/// key.insert(1_u32);
+/// dynamic.insert(DynamicEnum::new("Some", key), 20_u32);
+///
+/// let error = map_from_reflect_strict::<HashMap<Option<u32>, u32>>(&dynamic).unwrap_err();
+/// assert!(matches!(error, FromReflectMapError::DuplicateKey { .. }));
+/// ```
+pub fn map_from_reflect_strict<M: Map + FromReflect>(
+    reflect: &dyn Reflect,
+) -> Result<M, FromReflectMapError> {
+    let ReflectRef::Map(source) = reflect.reflect_ref() else {
+        return Err(FromReflectMapError::NotAMap {
+            kind: reflect.reflect_kind(),
+        });
+    };
+
+    let map = M::from_reflect(reflect).ok_or(FromReflectMapError::ConversionFailed {
+        type_path: std::any::type_name::<M>(),
+    })?;
+    if map.len() == source.len() {
+        return Ok(map);
+    }
+
+    // Some entries were merged, so find the first key equal to an earlier one
+    let keys = source.iter().map(|(key, _)| key).collect::<Vec<_>>();
+    let duplicate = keys
+        .iter()
+        .enumerate()
+        .find(|(index, key)| {
+            keys[..*index]
+                .iter()
+                .any(|other| other.reflect_partial_eq(**key) == Some(true))
+        })
+        .map_or(keys[keys.len() - 1], |(_, key)| *key);
+
+    Err(FromReflectMapError::DuplicateKey {
+        key: format!("{duplicate:?}"),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::DynamicMap;
     use super::Map;
     use crate::reflect::Reflect;
+    use crate::{
+        map_from_reflect_strict, DynamicEnum, DynamicTuple, FromReflect, FromReflectMapError,
+        ReflectKind,
+    };
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn remove_should_keep_later_entries_reachable() {
+        let mut map = DynamicMap::default();
+        map.insert(0_u32, "zero".to_string());
+        map.insert(1_u32, "one".to_string());
+        map.insert(2_u32, "two".to_string());
+
+        map.remove(&0_u32);
+        assert_eq!(2, map.len());
+        assert_eq!(
+            Some(&"one".to_string()),
+            map.get(&1_u32).and_then(|value| value.downcast_ref())
+        );
+        assert_eq!(
+            Some(&"two".to_string()),
+            map.get(&2_u32).and_then(|value| value.downcast_ref())
+        );
+    }
 
     #[test]
     fn test_into_iter() {
@@ -667,4 +846,77 @@ mod tests {
             assert_eq!(size, iter.index);
         }
     }
+
+    /// Creates a dynamic map with two entries for the `Some(1)` key,
+    /// as the dynamic key hashes differently from the concrete one.
+    fn duplicate_key_map() -> DynamicMap {
+        let mut key = DynamicTuple::default();
+        key.insert(1_u32);
+
+        let mut map = DynamicMap::default();
+        map.insert(Some(1_u32), String::from("first"));
+        map.insert(None::<u32>, String::from("none"));
+        map.insert(DynamicEnum::new("Some", key), String::from("last"));
+        assert_eq!(3, map.len());
+        map
+    }
+
+    #[test]
+    fn from_reflect_should_keep_last_duplicate_entry() {
+        let map = duplicate_key_map();
+
+        let hash_map = HashMap::<Option<u32>, String>::from_reflect(&map).unwrap();
+        assert_eq!(2, hash_map.len());
+        assert_eq!("last", hash_map[&Some(1)]);
+        assert_eq!("none", hash_map[&None]);
+
+        let btree_map = BTreeMap::<Option<u32>, String>::from_reflect(&map).unwrap();
+        assert_eq!(2, btree_map.len());
+        assert_eq!("last", btree_map[&Some(1)]);
+    }
+
+    #[test]
+    fn strict_from_reflect_should_reject_duplicate_keys() {
+        let map = duplicate_key_map();
+
+        let error = map_from_reflect_strict::<HashMap<Option<u32>, String>>(&map).unwrap_err();
+        assert_eq!(
+            FromReflectMapError::DuplicateKey {
+                key: String::from("DynamicEnum(Some(1))")
+            },
+            error
+        );
+        assert_eq!(
+            "duplicate key `DynamicEnum(Some(1))` in map",
+            error.to_string()
+        );
+
+        let mut map = DynamicMap::default();
+        map.insert(1_u32, 2_u32);
+        map.insert(3_u32, 4_u32);
+        let btree_map = map_from_reflect_strict::<BTreeMap<u32, u32>>(&map);
+        assert_eq!(Ok(BTreeMap::from([(1, 2), (3, 4)])), btree_map);
+    }
+
+    #[test]
+    fn strict_from_reflect_should_reject_invalid_maps() {
+        assert_eq!(
+            Err(FromReflectMapError::NotAMap {
+                kind: ReflectKind::Value
+            }),
+            map_from_reflect_strict::<BTreeMap<u32, u32>>(&123_u32)
+        );
+
+        let mut map = DynamicMap::default();
+        map.insert(1_u32, String::from("not a number"));
+        let error = map_from_reflect_strict::<BTreeMap<u32, u32>>(&map).unwrap_err();
+        assert!(matches!(
+            error,
+            FromReflectMapError::ConversionFailed { .. }
+        ));
+        assert_eq!(
+            "failed to convert the entries of the map into `alloc::collections::btree::map::BTreeMap<u32, u32>`",
+            error.to_string()
+        );
+    }
 }
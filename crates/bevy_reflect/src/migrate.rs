@@ -0,0 +1,559 @@
+use crate::serde::{to_untyped, BridgeError, DynamicDeserializer, TypedReflectDeserializer};
+use crate::{DynamicStruct, Reflect, ReflectFromReflect, ReflectKind, ReflectRef};
+use crate::{TypePath, TypeRegistration, TypeRegistry};
+use serde::de::DeserializeSeed;
+use std::any::TypeId;
+use thiserror::Error;
+
+/// A single migration step, upgrading a struct from a previous version to the next.
+pub type MigrationFn = fn(DynamicStruct) -> Result<DynamicStruct, MigrationError>;
+
+/// An error returned when [migrating] a value fails.
+///
+/// [migrating]: migrate_value
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MigrationError {
+    #[error("field `{field}`: {reason}")]
+    /// A migration step could not migrate a field.
+    ///
+    /// This is the error usually returned by a [`MigrationFn`].
+    Field { field: Box<str>, reason: Box<str> },
+
+    #[error("migration of `{type_path}` to version {version} failed: {error}")]
+    /// A migration step returned an error.
+    StepFailed {
+        type_path: Box<str>,
+        version: u32,
+        error: Box<MigrationError>,
+    },
+
+    #[error("cannot migrate `{type_path}` from version {recorded_version} as it is newer than the current version {current_version}")]
+    /// The recorded version is newer than the current version of the type.
+    UnsupportedVersion {
+        type_path: Box<str>,
+        recorded_version: u32,
+        current_version: u32,
+    },
+
+    #[error("expected a struct when migrating `{type_path}` but received `{kind}`")]
+    /// The value to migrate was not a struct.
+    MismatchedKind {
+        type_path: Box<str>,
+        kind: ReflectKind,
+    },
+
+    #[error("failed to convert `{type_path}` to or from its untyped form: {reason}")]
+    /// The value could not be converted to or from the untyped form used by migration steps.
+    UntypedConversionFailed {
+        type_path: Box<str>,
+        reason: Box<str>,
+    },
+
+    #[error("failed to convert the migrated value into `{type_path}`")]
+    /// The migrated value could not be converted via [`FromReflect`](crate::FromReflect).
+    FromReflectFailed { type_path: Box<str> },
+}
+
+impl MigrationError {
+    /// Creates a [`MigrationError::Field`] error for the given field.
+    pub fn field(field: impl Into<Box<str>>, reason: impl Into<Box<str>>) -> Self {
+        Self::Field {
+            field: field.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates a [`MigrationError::Field`] error for a field that is missing.
+    pub fn missing_field(field: impl Into<Box<str>>) -> Self {
+        Self::field(field, "missing field")
+    }
+}
+
+/// Type data containing the current version of a struct and the steps
+/// required to migrate values recorded against older versions.
+///
+/// Each step is registered for the version it upgrades values _to_.
+/// When [migrating] a value recorded at version `n`, every step registered
+/// for a version greater than `n` is applied in ascending version order.
+///
+/// # Untyped form
+///
+/// The types of an older version are not known, so steps receive the value in an untyped form.
+/// This is the same whether the value is passed to [`migrate_value`] directly
+/// or deserialized with [`TypedReflectDeserializer::with_version`]:
+///
+/// | Value                                       | Untyped form                             |
+/// | ------------------------------------------- | ---------------------------------------- |
+/// | `bool` and strings                          | `bool` and `String`                      |
+/// | non-negative integers                       | `u64`                                    |
+/// | negative integers                           | `i64`                                    |
+/// | floats                                      | `f64`                                    |
+/// | lists, arrays, tuples and tuple structs     | [`DynamicList`]                          |
+/// | structs and maps with string keys           | [`DynamicStruct`]                        |
+/// | other maps                                  | [`DynamicMap`]                           |
+/// | `Option`                                    | [`DynamicEnum`] named `Some` or `None`   |
+///
+/// Other enums become a [`DynamicEnum`] when migrating a value directly,
+/// but self-describing formats don't record their variants in a way that can be read
+/// without their type, so they can't be deserialized from an older version.
+///
+/// After the last step, the untyped fields are converted into the types of the current fields,
+/// so steps only need to produce values of a compatible untyped form.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{migrate_value, DynamicStruct, MigrationError, Reflect, ReflectMigrate, TypeRegistry};
+/// #[derive(Reflect, Debug, PartialEq)]
+/// struct Health {
+///     current: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Health>();
+/// registry.register_migrations::<Health>(ReflectMigrate::new(2).with_step(2, |mut value| {
+///     // Version 1 called this field `hp`
+///     let hp = value.remove("hp").ok_or(MigrationError::missing_field("hp"))?;
+///     value.insert_boxed("current", hp);
+///     Ok(value)
+/// }));
+///
+/// let mut old = DynamicStruct::default();
+/// old.insert("hp", 10.0_f32);
+///
+/// let registration = registry.get(std::any::TypeId::of::<Health>()).unwrap();
+/// let migrated = migrate_value(Box::new(old), 1, registration, &registry).unwrap();
+/// assert_eq!(Some(&Health { current: 10.0 }), migrated.downcast_ref::<Health>());
+/// ```
+///
+/// [migrating]: migrate_value
+/// [`TypedReflectDeserializer::with_version`]: crate::serde::TypedReflectDeserializer::with_version
+/// [`DynamicList`]: crate::DynamicList
+/// [`DynamicMap`]: crate::DynamicMap
+/// [`DynamicEnum`]: crate::DynamicEnum
+#[derive(Clone)]
+pub struct ReflectMigrate {
+    current_version: u32,
+    steps: Vec<(u32, MigrationFn)>,
+}
+
+impl ReflectMigrate {
+    /// Creates a new [`ReflectMigrate`] with the given current version and no steps.
+    pub fn new(current_version: u32) -> Self {
+        Self {
+            current_version,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Adds a step upgrading values to the given version.
+    ///
+    /// Steps registered for the same version are applied in the order they were added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `version` is greater than the current version.
+    pub fn with_step(mut self, version: u32, step: MigrationFn) -> Self {
+        self.add_step(version, step);
+        self
+    }
+
+    /// Adds a step upgrading values to the given version.
+    ///
+    /// See [`ReflectMigrate::with_step`] for details.
+    pub fn add_step(&mut self, version: u32, step: MigrationFn) {
+        assert!(
+            version <= self.current_version,
+            "attempted to add a migration step for version {version} which is newer than the current version {}",
+            self.current_version
+        );
+        let index = self
+            .steps
+            .partition_point(|(existing, _)| *existing <= version);
+        self.steps.insert(index, (version, step));
+    }
+
+    /// The current version of the type.
+    pub fn current_version(&self) -> u32 {
+        self.current_version
+    }
+
+    /// The registered steps, in the order they are applied.
+    pub fn steps(&self) -> &[(u32, MigrationFn)] {
+        &self.steps
+    }
+}
+
+/// Migrates a value recorded at `recorded_version` to the current version of the type
+/// described by `registration`.
+///
+/// If any steps of the type's [`ReflectMigrate`] need to be applied,
+/// the value is first converted into the untyped form described by [`ReflectMigrate`].
+/// After the steps have been applied, the result is converted back into the current type.
+/// Values already at the current version, or types without [`ReflectMigrate`],
+/// are only converted using [`ReflectFromReflect`], if registered.
+///
+/// # Errors
+///
+/// Returns an error if `recorded_version` is newer than the current version,
+/// if the value is not a struct, if a step fails, or if the conversion fails.
+pub fn migrate_value(
+    value: Box<dyn Reflect>,
+    recorded_version: u32,
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, MigrationError> {
+    if pending_steps(recorded_version, registration)?.is_empty() {
+        return convert_migrated(value, registration);
+    }
+
+    let value = to_untyped(value.as_ref(), registry)
+        .map_err(|error| untyped_conversion_error(registration, error))?;
+    migrate_untyped(value, recorded_version, registration, registry)
+}
+
+/// Applies the steps of the type's [`ReflectMigrate`] to a value in its untyped form,
+/// then converts the result into the current type.
+pub(crate) fn migrate_untyped(
+    value: Box<dyn Reflect>,
+    recorded_version: u32,
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, MigrationError> {
+    let type_info = registration.type_info();
+    let type_path = type_info.type_path();
+
+    let mut dynamic = match value.downcast::<DynamicStruct>() {
+        Ok(dynamic) => *dynamic,
+        Err(value) => match value.reflect_ref() {
+            ReflectRef::Struct(value) => value.clone_dynamic(),
+            other => {
+                return Err(MigrationError::MismatchedKind {
+                    type_path: type_path.into(),
+                    kind: other.kind(),
+                })
+            }
+        },
+    };
+
+    for (version, step) in pending_steps(recorded_version, registration)? {
+        dynamic = step(dynamic).map_err(|error| MigrationError::StepFailed {
+            type_path: type_path.into(),
+            version: *version,
+            error: Box::new(error),
+        })?;
+    }
+
+    let value = TypedReflectDeserializer::new(registration, registry)
+        .deserialize(DynamicDeserializer::new(&dynamic, registry))
+        .map_err(|error| untyped_conversion_error(registration, error))?;
+    convert_migrated(value, registration)
+}
+
+/// Returns the steps which need to be applied to a value recorded at `recorded_version`.
+fn pending_steps(
+    recorded_version: u32,
+    registration: &TypeRegistration,
+) -> Result<&[(u32, MigrationFn)], MigrationError> {
+    let Some(migrate) = registration.data::<ReflectMigrate>() else {
+        return Ok(&[]);
+    };
+
+    if recorded_version > migrate.current_version {
+        return Err(MigrationError::UnsupportedVersion {
+            type_path: registration.type_info().type_path().into(),
+            recorded_version,
+            current_version: migrate.current_version,
+        });
+    }
+
+    let start = migrate
+        .steps
+        .partition_point(|(version, _)| *version <= recorded_version);
+    Ok(&migrate.steps[start..])
+}
+
+fn untyped_conversion_error(registration: &TypeRegistration, error: BridgeError) -> MigrationError {
+    MigrationError::UntypedConversionFailed {
+        type_path: registration.type_info().type_path().into(),
+        reason: error.to_string().into(),
+    }
+}
+
+/// Converts a migrated value into the concrete type using [`ReflectFromReflect`], if registered.
+fn convert_migrated(
+    value: Box<dyn Reflect>,
+    registration: &TypeRegistration,
+) -> Result<Box<dyn Reflect>, MigrationError> {
+    if value.as_any().type_id() == registration.type_id() {
+        return Ok(value);
+    }
+
+    match registration.data::<ReflectFromReflect>() {
+        Some(from_reflect) => from_reflect.from_reflect(value.as_ref()).ok_or_else(|| {
+            MigrationError::FromReflectFailed {
+                type_path: registration.type_info().type_path().into(),
+            }
+        }),
+        None => Ok(value),
+    }
+}
+
+impl TypeRegistry {
+    /// Inserts the given [`ReflectMigrate`] into the registration of type `T`.
+    ///
+    /// Any existing migrations for `T` are replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` has not been registered.
+    pub fn register_migrations<T: Reflect + TypePath>(&mut self, migrate: ReflectMigrate) {
+        let registration = self.get_mut(TypeId::of::<T>()).unwrap_or_else(|| {
+            panic!(
+                "attempted to call `TypeRegistry::register_migrations` for type `{T}` without registering `{T}` first",
+                T = T::type_path(),
+            )
+        });
+        registration.insert(migrate);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::TypedReflectDeserializer;
+    use crate::Struct;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Health {
+        current: f32,
+        max: f32,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Health>();
+        registry.register_migrations::<Health>(
+            ReflectMigrate::new(3)
+                // Version 2 renamed `hp` to `current`
+                .with_step(2, |mut value| {
+                    let hp = value
+                        .remove("hp")
+                        .ok_or(MigrationError::missing_field("hp"))?;
+                    value.insert_boxed("current", hp);
+                    Ok(value)
+                })
+                // Version 3 changed `max` from `u32` to `f32`, rounding it up to the nearest ten
+                .with_step(3, |mut value| {
+                    let max = value
+                        .field("max")
+                        .ok_or(MigrationError::missing_field("max"))?;
+                    // Integers are always `u64` in the untyped form
+                    let max = *max
+                        .downcast_ref::<u64>()
+                        .ok_or(MigrationError::field("max", "expected an unsigned integer"))?;
+                    value.insert("max", (max as f64 / 10.0).ceil() * 10.0);
+                    Ok(value)
+                }),
+        );
+        registry
+    }
+
+    #[test]
+    fn should_apply_steps_in_order() {
+        let registry = registry();
+        let registration = registry.get(TypeId::of::<Health>()).unwrap();
+
+        let mut old = DynamicStruct::default();
+        old.insert("hp", 5.0_f32);
+        old.insert("max", 14_u32);
+
+        let migrated = migrate_value(Box::new(old), 1, registration, &registry).unwrap();
+        assert_eq!(
+            Some(&Health {
+                current: 5.0,
+                max: 20.0
+            }),
+            migrated.downcast_ref::<Health>()
+        );
+
+        // Only the steps after the recorded version are applied
+        let mut old = DynamicStruct::default();
+        old.insert("current", 5.0_f32);
+        old.insert("max", 14_u32);
+
+        let migrated = migrate_value(Box::new(old), 2, registration, &registry).unwrap();
+        assert_eq!(
+            Some(&Health {
+                current: 5.0,
+                max: 20.0
+            }),
+            migrated.downcast_ref::<Health>()
+        );
+    }
+
+    #[test]
+    fn should_give_steps_the_same_values_when_deserializing() {
+        let registry = registry();
+        let registration = registry.get(TypeId::of::<Health>()).unwrap();
+        let expected = Health {
+            current: 5.0,
+            max: 20.0,
+        };
+
+        let mut old = DynamicStruct::default();
+        old.insert("hp", 5.0_f32);
+        old.insert("max", 14_u32);
+        let migrated = migrate_value(Box::new(old), 1, registration, &registry).unwrap();
+        assert_eq!(Some(&expected), migrated.downcast_ref::<Health>());
+
+        let mut deserializer = ron::de::Deserializer::from_str("(hp: 5.0, max: 14)").unwrap();
+        let deserialized = TypedReflectDeserializer::new(registration, &registry)
+            .with_version(1)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Some(&expected), deserialized.downcast_ref::<Health>());
+    }
+
+    #[test]
+    fn should_not_migrate_current_version() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Health>();
+        registry.register_migrations::<Health>(ReflectMigrate::new(2).with_step(2, |_| {
+            Err(MigrationError::field("current", "should not be called"))
+        }));
+        let registration = registry.get(TypeId::of::<Health>()).unwrap();
+
+        let value = Health {
+            current: 1.0,
+            max: 2.0,
+        };
+        let migrated = migrate_value(Box::new(value), 2, registration, &registry).unwrap();
+        assert_eq!(
+            Some(&Health {
+                current: 1.0,
+                max: 2.0
+            }),
+            migrated.downcast_ref::<Health>()
+        );
+
+        let error = migrate_value(
+            Box::new(DynamicStruct::default()),
+            3,
+            registration,
+            &registry,
+        )
+        .unwrap_err();
+        assert_eq!(
+            MigrationError::UnsupportedVersion {
+                type_path: Health::type_path().into(),
+                recorded_version: 3,
+                current_version: 2,
+            },
+            error
+        );
+    }
+
+    #[test]
+    fn should_report_failing_step() {
+        let registry = registry();
+        let registration = registry.get(TypeId::of::<Health>()).unwrap();
+
+        let mut old = DynamicStruct::default();
+        old.insert("hp", 5.0_f32);
+        old.insert("max", 10.0_f64);
+
+        let error = migrate_value(Box::new(old), 1, registration, &registry).unwrap_err();
+        assert_eq!(
+            MigrationError::StepFailed {
+                type_path: Health::type_path().into(),
+                version: 3,
+                error: Box::new(MigrationError::field("max", "expected an unsigned integer")),
+            },
+            error
+        );
+        assert_eq!(
+            format!(
+                "migration of `{}` to version 3 failed: field `max`: expected an unsigned integer",
+                Health::type_path()
+            ),
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn should_migrate_when_deserializing() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Health>();
+        // Version 2 added `max`, which defaults to the current health
+        registry.register_migrations::<Health>(ReflectMigrate::new(2).with_step(2, |mut value| {
+            let current = value
+                .field("current")
+                .ok_or(MigrationError::missing_field("current"))?
+                .clone_value();
+            value.insert_boxed("max", current);
+            Ok(value)
+        }));
+        let registration = registry.get(TypeId::of::<Health>()).unwrap();
+
+        let mut deserializer = ron::de::Deserializer::from_str("(current: 3.0)").unwrap();
+        let value = TypedReflectDeserializer::new(registration, &registry)
+            .with_version(1)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            Some(&Health {
+                current: 3.0,
+                max: 3.0
+            }),
+            value.downcast_ref::<Health>()
+        );
+    }
+
+    #[test]
+    fn should_migrate_renamed_field_when_deserializing() {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Health>();
+        // Version 2 renamed `hp` to `current` and removed `regen`
+        registry.register_migrations::<Health>(ReflectMigrate::new(2).with_step(2, |mut value| {
+            let hp = value
+                .remove("hp")
+                .ok_or(MigrationError::missing_field("hp"))?;
+            value.insert_boxed("current", hp);
+            value.remove("regen");
+            Ok(value)
+        }));
+        let registration = registry.get(TypeId::of::<Health>()).unwrap();
+
+        let mut deserializer =
+            ron::de::Deserializer::from_str("(hp: 5.0, max: 10, regen: 0.5)").unwrap();
+        let value = TypedReflectDeserializer::new(registration, &registry)
+            .with_version(1)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            Some(&Health {
+                current: 5.0,
+                max: 10.0
+            }),
+            value.downcast_ref::<Health>()
+        );
+
+        // Data at the current version is deserialized against the current type
+        let mut deserializer =
+            ron::de::Deserializer::from_str("(current: 5.0, max: 10.0)").unwrap();
+        let value = TypedReflectDeserializer::new(registration, &registry)
+            .with_version(2)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(
+            Some(&Health {
+                current: 5.0,
+                max: 10.0
+            }),
+            value.downcast_ref::<Health>()
+        );
+    }
+}
@@ -0,0 +1,796 @@
+use crate::{
+    ApplyError, Reflect, ReflectKind, ReflectRef, ReflectTransparent, TypeInfo, TypeRegistry,
+    VariantField, VariantInfo,
+};
+use std::any::TypeId;
+use std::fmt::Write;
+use thiserror::Error;
+
+/// The kind of error returned by [`validate_patch`].
+#[derive(Error, Debug)]
+pub enum PatchValidationErrorKind {
+    #[error("expected `{expected}` but found `{received}`")]
+    /// The patch is a different [kind](ReflectKind) than the target, e.g. a struct applied to an enum.
+    MismatchedKinds {
+        expected: ReflectKind,
+        received: ReflectKind,
+    },
+
+    #[error("`{received}` is not `{expected}`")]
+    /// The patch is a value of a different type than the target.
+    MismatchedTypes {
+        expected: Box<str>,
+        received: Box<str>,
+    },
+
+    #[error("`{type_path}` has no field `{field}`")]
+    /// The patch contains a field that does not exist on the target.
+    MissingField {
+        type_path: Box<str>,
+        field: Box<str>,
+    },
+
+    #[error("`{enum_name}` has no variant `{variant_name}`")]
+    /// The patch is an enum variant that does not exist on the target.
+    UnknownVariant {
+        enum_name: Box<str>,
+        variant_name: Box<str>,
+    },
+
+    #[error("expected size {expected} but found size {received}")]
+    /// The patch is an array of a different size than the target,
+    /// or a list that exceeds the target's [capacity](crate::ListInfo::capacity).
+    DifferentSize { expected: usize, received: usize },
+
+    #[error("type info for `{type_path}` is not available")]
+    /// The type info for a nested type could not be found in the registry.
+    MissingTypeInfo { type_path: Box<str> },
+
+    #[error(transparent)]
+    /// The patch passed validation but could not be applied.
+    Apply(#[from] ApplyError),
+}
+
+/// An error returned by [`validate_patch`] when a patch cannot be cleanly applied.
+#[derive(Error, Debug)]
+#[error("invalid patch at `{path}`: {kind}")]
+pub struct PatchValidationError {
+    /// The [path] to the invalid part of the patch, relative to the target.
+    ///
+    /// This is empty if the root of the patch is invalid.
+    ///
+    /// [path]: crate::GetPath
+    pub path: String,
+    /// The reason the patch is invalid.
+    pub kind: PatchValidationErrorKind,
+}
+
+/// Checks whether `patch` can be [applied] to a value of the type described by `target_info`,
+/// without modifying anything.
+///
+/// Since [`Reflect::apply`] modifies its target as it goes,
+/// a patch that fails partway through can leave the target only partially modified.
+/// Validating the patch beforehand allows bad patches to be rejected up front.
+///
+/// The following are checked at every level of the patch:
+/// - The [kind](ReflectKind) of the patch matches the kind of the target.
+/// - Struct, tuple struct, and tuple fields exist on the target.
+/// - Array lengths match and lists do not exceed their capacity.
+/// - Enum variants and their fields exist on the target.
+/// - Values are of the same type as the target.
+///
+/// Type info for nested types (such as list items or map keys) is looked up in the given `registry`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{validate_patch, DynamicStruct, Reflect, Typed, TypeRegistry};
+/// #[derive(Reflect)]
+/// struct Player {
+///     health: u32,
+///     name: String,
+/// }
+///
+/// let registry = TypeRegistry::new();
+/// let mut player = Player { health: 100, name: String::from("Bevy") };
+///
+/// let mut patch = DynamicStruct::default();
+/// patch.insert("health", 50_u32);
+/// patch.insert("name", 123_i32);
+///
+/// // The patch is rejected without modifying the player
+/// assert!(validate_patch(Player::type_info(), &patch, &registry).is_err());
+/// assert_eq!(100, player.health);
+///
+/// let mut patch = DynamicStruct::default();
+/// patch.insert("health", 50_u32);
+///
+/// // A valid patch can then be applied as normal
+/// validate_patch(Player::type_info(), &patch, &registry).unwrap();
+/// player.apply(&patch);
+/// assert_eq!(50, player.health);
+/// ```
+///
+/// [applied]: Reflect::apply
+pub fn validate_patch(
+    target_info: &TypeInfo,
+    patch: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<(), PatchValidationError> {
+    let mut path = String::new();
+    PatchValidator { registry }
+        .validate(target_info, patch, &mut path)
+        .map_err(|kind| PatchValidationError { path, kind })
+}
+
+/// Validates `patch` against `target` using [`validate_patch`] and, if valid, applies it.
+///
+/// The target must [represent a type](Reflect::get_represented_type_info) in order to be validated.
+pub fn apply_validated(
+    target: &mut dyn Reflect,
+    patch: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<(), PatchValidationError> {
+    let target_info = target
+        .get_represented_type_info()
+        .ok_or_else(|| PatchValidationError {
+            path: String::new(),
+            kind: PatchValidationErrorKind::MissingTypeInfo {
+                type_path: target.reflect_type_path().into(),
+            },
+        })?;
+
+    validate_patch(target_info, patch, registry)?;
+
+    target.try_apply(patch).map_err(|err| PatchValidationError {
+        path: String::new(),
+        kind: err.into(),
+    })
+}
+
+struct PatchValidator<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> PatchValidator<'a> {
+    /// Validates `patch` against `info`, pushing to `path` as it descends.
+    ///
+    /// On error, `path` is left pointing at the invalid part of the patch.
+    fn validate(
+        &self,
+        info: &TypeInfo,
+        patch: &dyn Reflect,
+        path: &mut String,
+    ) -> Result<(), PatchValidationErrorKind> {
+        // A concrete value of the same type can always be applied
+        if !patch.is_dynamic() && patch.as_any().type_id() == info.type_id() {
+            return Ok(());
+        }
+
+        let expected = kind_of(info);
+        let received = patch.reflect_kind();
+        if expected != received {
+            return Err(PatchValidationErrorKind::MismatchedKinds { expected, received });
+        }
+
+        match (info, patch.reflect_ref()) {
+            (TypeInfo::Struct(info), ReflectRef::Struct(patch)) => {
+                for (index, value) in patch.iter_fields().enumerate() {
+                    let name = patch.name_at(index).unwrap();
+                    let field = info
+                        .field(name)
+                        .ok_or_else(|| missing_field(info.type_path(), name))?;
+                    self.validate_field(field.type_id(), field.type_path(), value, path, |path| {
+                        write!(path, ".{name}")
+                    })?;
+                }
+            }
+            (TypeInfo::TupleStruct(info), ReflectRef::TupleStruct(patch)) => {
+                for (index, value) in patch.iter_fields().enumerate() {
+                    let field = info
+                        .field_at(index)
+                        .ok_or_else(|| missing_field(info.type_path(), &index.to_string()))?;
+                    self.validate_field(field.type_id(), field.type_path(), value, path, |path| {
+                        write!(path, ".{index}")
+                    })?;
+                }
+            }
+            (TypeInfo::Tuple(info), ReflectRef::Tuple(patch)) => {
+                for (index, value) in patch.iter_fields().enumerate() {
+                    let field = info
+                        .field_at(index)
+                        .ok_or_else(|| missing_field(info.type_path(), &index.to_string()))?;
+                    self.validate_field(field.type_id(), field.type_path(), value, path, |path| {
+                        write!(path, ".{index}")
+                    })?;
+                }
+            }
+            (TypeInfo::List(info), ReflectRef::List(patch)) => {
+                if let Some(capacity) = info.capacity().filter(|&cap| patch.len() > cap) {
+                    return Err(PatchValidationErrorKind::DifferentSize {
+                        expected: capacity,
+                        received: patch.len(),
+                    });
+                }
+
+                // Lists of `Box<dyn Reflect>` may contain anything
+                if !info.has_dynamic_items() {
+                    for (index, value) in patch.iter().enumerate() {
+                        self.validate_field(
+                            info.item_type_id(),
+                            info.item_type_path_table().path(),
+                            value,
+                            path,
+                            |path| write!(path, "[{index}]"),
+                        )?;
+                    }
+                }
+            }
+            (TypeInfo::Array(info), ReflectRef::Array(patch)) => {
+                if info.capacity() != patch.len() {
+                    return Err(PatchValidationErrorKind::DifferentSize {
+                        expected: info.capacity(),
+                        received: patch.len(),
+                    });
+                }
+
+                for (index, value) in patch.iter().enumerate() {
+                    self.validate_field(
+                        info.item_type_id(),
+                        info.item_type_path_table().path(),
+                        value,
+                        path,
+                        |path| write!(path, "[{index}]"),
+                    )?;
+                }
+            }
+            (TypeInfo::Map(info), ReflectRef::Map(patch)) => {
+                for (key, value) in patch.iter() {
+                    self.validate_field(
+                        info.key_type_id(),
+                        info.key_type_path_table().path(),
+                        key,
+                        path,
+                        |path| write!(path, "[{key:?}]"),
+                    )?;
+                    self.validate_field(
+                        info.value_type_id(),
+                        info.value_type_path_table().path(),
+                        value,
+                        path,
+                        |path| write!(path, "[{key:?}]"),
+                    )?;
+                }
+            }
+            (TypeInfo::Enum(info), ReflectRef::Enum(patch)) => {
+                let variant_name = patch.variant_name();
+                let variant = info.variant(variant_name).ok_or_else(|| {
+                    PatchValidationErrorKind::UnknownVariant {
+                        enum_name: info.type_path().into(),
+                        variant_name: variant_name.into(),
+                    }
+                })?;
+
+                for (index, field) in patch.iter_fields().enumerate() {
+                    let field_info = match (variant, &field) {
+                        (VariantInfo::Struct(variant), VariantField::Struct(name, _)) => variant
+                            .field(name)
+                            .map(|field| (field.type_id(), field.type_path())),
+                        (VariantInfo::Tuple(variant), VariantField::Tuple(_)) => variant
+                            .field_at(index)
+                            .map(|field| (field.type_id(), field.type_path())),
+                        _ => None,
+                    };
+
+                    let (type_id, type_path) = field_info.ok_or_else(|| {
+                        let name = field.name().map_or_else(|| index.to_string(), String::from);
+                        missing_field(info.type_path(), &format!("{variant_name}::{name}"))
+                    })?;
+
+                    self.validate_field(
+                        type_id,
+                        type_path,
+                        field.value(),
+                        path,
+                        |path| match field.name() {
+                            Some(name) => write!(path, ".{name}"),
+                            None => write!(path, ".{index}"),
+                        },
+                    )?;
+                }
+            }
+            (TypeInfo::Value(info), ReflectRef::Value(patch)) => {
+                // Transparent values may also be applied from their inner value
+                let is_inner = self
+                    .registry
+                    .get_type_data::<ReflectTransparent>(info.type_id())
+                    .is_some_and(|data| data.inner_type_id() == patch.as_any().type_id());
+
+                if !is_inner {
+                    return Err(PatchValidationErrorKind::MismatchedTypes {
+                        expected: info.type_path().into(),
+                        received: patch.reflect_type_path().into(),
+                    });
+                }
+            }
+            // Kinds were already checked to match
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    /// Validates a nested `patch` against the type with the given [`TypeId`].
+    ///
+    /// The `push` function is used to append the field's segment to the `path`,
+    /// which is then removed if validation succeeds.
+    fn validate_field(
+        &self,
+        type_id: TypeId,
+        type_path: &str,
+        patch: &dyn Reflect,
+        path: &mut String,
+        push: impl FnOnce(&mut String) -> std::fmt::Result,
+    ) -> Result<(), PatchValidationErrorKind> {
+        let len = path.len();
+        push(path).unwrap();
+
+        // Fall back to the patch's own type info if the type was not registered
+        let info = self.registry.get_type_info(type_id).or_else(|| {
+            patch
+                .get_represented_type_info()
+                .filter(|info| info.type_id() == type_id)
+        });
+
+        let Some(info) = info else {
+            if !patch.is_dynamic() && patch.as_any().type_id() == type_id {
+                path.truncate(len);
+                return Ok(());
+            }
+
+            return Err(PatchValidationErrorKind::MissingTypeInfo {
+                type_path: type_path.into(),
+            });
+        };
+
+        self.validate(info, patch, path)?;
+        path.truncate(len);
+        Ok(())
+    }
+}
+
+/// Checks whether `partial` is a structural subset of `full`.
+///
+/// This is the precondition for [applying] `partial` onto `full` without changing anything:
+/// every part of `partial` must already be present in `full` with an equal value.
+/// Unlike [`Reflect::reflect_partial_eq`], parts of `full` that are missing from `partial` are ignored.
+///
+/// The following rules are applied at every level:
+/// - Structs, tuple structs, and tuples only check the fields present on `partial`.
+/// - Maps only check the keys present on `partial`.
+/// - Lists and arrays must have the same length, with each element of `partial` being a subset
+///   of the corresponding element of `full`.
+/// - Enums must be the same variant, with the fields present on `partial` being subsets
+///   of the corresponding fields of `full`.
+/// - Values are compared using [`Reflect::reflect_partial_eq`].
+///
+/// Returns [`None`] if a comparison could not be performed (such as between [opaque] values).
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{is_subset, DynamicStruct, Reflect};
+/// #[derive(Reflect)]
+/// struct Player {
+///     health: u32,
+///     name: String,
+/// }
+///
+/// let player = Player { health: 100, name: String::from("Bevy") };
+///
+/// let mut partial = DynamicStruct::default();
+/// partial.insert("health", 100_u32);
+/// assert_eq!(Some(true), is_subset(&partial, &player));
+///
+/// partial.insert("name", String::from("Ferris"));
+/// assert_eq!(Some(false), is_subset(&partial, &player));
+/// ```
+///
+/// [applying]: Reflect::apply
+/// [opaque]: crate::ValueInfo::is_opaque
+pub fn is_subset(partial: &dyn Reflect, full: &dyn Reflect) -> Option<bool> {
+    match (partial.reflect_ref(), full.reflect_ref()) {
+        (ReflectRef::Struct(partial), ReflectRef::Struct(full)) => {
+            all_subsets(partial.iter_fields().enumerate().map(|(index, value)| {
+                let name = partial.name_at(index)?;
+                Some((value, full.field(name)?))
+            }))
+        }
+        (ReflectRef::TupleStruct(partial), ReflectRef::TupleStruct(full)) => all_subsets(
+            partial
+                .iter_fields()
+                .enumerate()
+                .map(|(index, value)| Some((value, full.field(index)?))),
+        ),
+        (ReflectRef::Tuple(partial), ReflectRef::Tuple(full)) => all_subsets(
+            partial
+                .iter_fields()
+                .enumerate()
+                .map(|(index, value)| Some((value, full.field(index)?))),
+        ),
+        (ReflectRef::List(partial), ReflectRef::List(full)) => {
+            if partial.len() != full.len() {
+                return Some(false);
+            }
+            all_subsets(partial.iter().zip(full.iter()).map(Some))
+        }
+        (ReflectRef::Array(partial), ReflectRef::Array(full)) => {
+            if partial.len() != full.len() {
+                return Some(false);
+            }
+            all_subsets(partial.iter().zip(full.iter()).map(Some))
+        }
+        (ReflectRef::Map(partial), ReflectRef::Map(full)) => all_subsets(
+            partial
+                .iter()
+                .map(|(key, value)| Some((value, full.get(key)?))),
+        ),
+        (ReflectRef::Enum(partial), ReflectRef::Enum(full)) => {
+            if partial.variant_name() != full.variant_name() {
+                return Some(false);
+            }
+            all_subsets(partial.iter_fields().enumerate().map(|(index, field)| {
+                let full_value = match field.name() {
+                    Some(name) => full.field(name),
+                    None => full.field_at(index),
+                }?;
+                Some((field.value(), full_value))
+            }))
+        }
+        (ReflectRef::Value(partial), ReflectRef::Value(_)) => partial.reflect_partial_eq(full),
+        _ => Some(false),
+    }
+}
+
+/// Checks that each `partial` value is a subset of its `full` counterpart.
+///
+/// A missing counterpart (`None`) means the `partial` value is not a subset.
+fn all_subsets<'a>(
+    pairs: impl Iterator<Item = Option<(&'a dyn Reflect, &'a dyn Reflect)>>,
+) -> Option<bool> {
+    for pair in pairs {
+        let Some((partial, full)) = pair else {
+            return Some(false);
+        };
+        if let failed @ (Some(false) | None) = is_subset(partial, full) {
+            return failed;
+        }
+    }
+    Some(true)
+}
+
+fn missing_field(type_path: &str, field: &str) -> PatchValidationErrorKind {
+    PatchValidationErrorKind::MissingField {
+        type_path: type_path.into(),
+        field: field.into(),
+    }
+}
+
+fn kind_of(info: &TypeInfo) -> ReflectKind {
+    match info {
+        TypeInfo::Struct(_) => ReflectKind::Struct,
+        TypeInfo::TupleStruct(_) => ReflectKind::TupleStruct,
+        TypeInfo::Tuple(_) => ReflectKind::Tuple,
+        TypeInfo::List(_) => ReflectKind::List,
+        TypeInfo::Array(_) => ReflectKind::Array,
+        TypeInfo::Map(_) => ReflectKind::Map,
+        TypeInfo::Enum(_) => ReflectKind::Enum,
+        TypeInfo::Value(_) => ReflectKind::Value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{
+        DynamicEnum, DynamicList, DynamicStruct, DynamicTuple, DynamicTupleStruct, TupleStruct,
+        Typed,
+    };
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Foo {
+        a: u32,
+        bar: Bar,
+        list: Vec<Bar>,
+        map: HashMap<usize, f32>,
+        array: [u8; 2],
+        baz: Baz,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Bar(i32, String);
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Baz {
+        A(u32),
+        B { value: f32 },
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Foo>();
+        registry
+    }
+
+    fn get_foo() -> Foo {
+        Foo {
+            a: 1,
+            bar: Bar(2, String::from("hello")),
+            list: vec![Bar(3, String::from("world"))],
+            map: HashMap::from([(0, 1.0)]),
+            array: [4, 5],
+            baz: Baz::A(6),
+        }
+    }
+
+    fn validate(patch: &dyn Reflect) -> Result<(), PatchValidationError> {
+        validate_patch(Foo::type_info(), patch, &get_registry())
+    }
+
+    #[test]
+    fn should_reject_invalid_nested_field_without_modifying_target() {
+        let registry = get_registry();
+        let mut foo = get_foo();
+
+        let mut bar = DynamicTupleStruct::default();
+        bar.insert(123_i32);
+        bar.insert(456_u32);
+        let mut patch = DynamicStruct::default();
+        patch.insert("a", 10_u32);
+        patch.insert("bar", bar);
+
+        let error = apply_validated(&mut foo, &patch, &registry).unwrap_err();
+        assert_eq!(".bar.1", error.path);
+        assert!(matches!(
+            error.kind,
+            PatchValidationErrorKind::MismatchedTypes { .. }
+        ));
+        assert_eq!(get_foo(), foo);
+    }
+
+    #[test]
+    fn should_apply_valid_patch() {
+        let registry = get_registry();
+        let mut foo = get_foo();
+
+        let mut list = DynamicList::default();
+        list.push(Bar(7, String::from("!")));
+        let mut patch = DynamicStruct::default();
+        patch.insert("a", 10_u32);
+        patch.insert("list", list);
+        patch.insert("baz", DynamicEnum::from(Baz::B { value: 8.0 }));
+
+        apply_validated(&mut foo, &patch, &registry).unwrap();
+        assert_eq!(10, foo.a);
+        assert_eq!(vec![Bar(7, String::from("!"))], foo.list);
+        assert_eq!(Baz::B { value: 8.0 }, foo.baz);
+    }
+
+    #[test]
+    fn should_validate_fields() {
+        let mut patch = DynamicStruct::default();
+        patch.insert("missing", 1_u32);
+        let error = validate(&patch).unwrap_err();
+        assert_eq!("", error.path);
+        assert!(matches!(
+            error.kind,
+            PatchValidationErrorKind::MissingField { .. }
+        ));
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("bar", 1_u32);
+        let error = validate(&patch).unwrap_err();
+        assert_eq!(".bar", error.path);
+        assert!(matches!(
+            error.kind,
+            PatchValidationErrorKind::MismatchedKinds {
+                expected: ReflectKind::TupleStruct,
+                received: ReflectKind::Value,
+            }
+        ));
+    }
+
+    #[test]
+    fn should_validate_collections() {
+        let mut list = DynamicList::default();
+        list.push(Bar(1, String::new()));
+        list.push(1_u32);
+        let mut patch = DynamicStruct::default();
+        patch.insert("list", list);
+        let error = validate(&patch).unwrap_err();
+        assert_eq!(".list[1]", error.path);
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("array", [1_u8, 2, 3]);
+        let error = validate(&patch).unwrap_err();
+        assert_eq!(".array", error.path);
+        assert!(matches!(
+            error.kind,
+            PatchValidationErrorKind::DifferentSize {
+                expected: 2,
+                received: 3
+            }
+        ));
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("map", HashMap::from([(0_usize, 1.0_f64)]));
+        let error = validate(&patch).unwrap_err();
+        assert_eq!(".map[0]", error.path);
+    }
+
+    #[test]
+    fn should_validate_enum_variants() {
+        let mut patch = DynamicStruct::default();
+        patch.insert("baz", DynamicEnum::new("C", ()));
+        let error = validate(&patch).unwrap_err();
+        assert_eq!(".baz", error.path);
+        assert!(matches!(
+            error.kind,
+            PatchValidationErrorKind::UnknownVariant { .. }
+        ));
+
+        let mut patch = DynamicStruct::default();
+        let mut variant = DynamicStruct::default();
+        variant.insert("value", 1_u32);
+        patch.insert("baz", DynamicEnum::new("A", variant));
+        let error = validate(&patch).unwrap_err();
+        assert!(matches!(
+            error.kind,
+            PatchValidationErrorKind::MissingField { .. }
+        ));
+
+        let mut patch = DynamicStruct::default();
+        patch.insert("baz", DynamicEnum::from(Baz::B { value: 1.0 }));
+        assert!(validate(&patch).is_ok());
+    }
+
+    #[test]
+    fn should_check_subset_of_struct_fields() {
+        let foo = get_foo();
+
+        let mut partial = DynamicStruct::default();
+        partial.insert("a", 1_u32);
+        assert_eq!(Some(true), is_subset(&partial, &foo));
+        assert_eq!(Some(true), is_subset(&foo, &foo));
+
+        partial.insert("a", 2_u32);
+        assert_eq!(Some(false), is_subset(&partial, &foo));
+
+        let mut partial = DynamicStruct::default();
+        partial.insert("missing", 1_u32);
+        assert_eq!(Some(false), is_subset(&partial, &foo));
+
+        // The full value is not a subset of a partial one
+        let mut partial = DynamicStruct::default();
+        partial.insert("a", 1_u32);
+        assert_eq!(Some(false), is_subset(&foo, &partial));
+    }
+
+    #[test]
+    fn should_check_subset_of_tuple_struct_and_tuple_fields() {
+        let mut partial = DynamicTupleStruct::default();
+        partial.insert(2_i32);
+        assert_eq!(
+            Some(true),
+            is_subset(&partial, &Bar(2, String::from("hello")))
+        );
+        assert_eq!(
+            Some(false),
+            is_subset(&partial, &Bar(3, String::from("hello")))
+        );
+
+        let mut partial = DynamicTuple::default();
+        partial.insert(1_u8);
+        assert_eq!(Some(true), is_subset(&partial, &(1_u8, 2_u16)));
+        assert_eq!(Some(false), is_subset(&partial, &(2_u8, 2_u16)));
+    }
+
+    #[test]
+    fn should_check_subset_of_map_keys() {
+        let full = HashMap::from([(0_usize, 1.0_f32), (1, 2.0)]);
+
+        let partial = HashMap::from([(1_usize, 2.0_f32)]);
+        assert_eq!(Some(true), is_subset(&partial, &full));
+
+        let partial = HashMap::from([(1_usize, 3.0_f32)]);
+        assert_eq!(Some(false), is_subset(&partial, &full));
+
+        let partial = HashMap::from([(2_usize, 2.0_f32)]);
+        assert_eq!(Some(false), is_subset(&partial, &full));
+    }
+
+    #[test]
+    fn should_require_equal_list_and_array_lengths() {
+        let full = vec![Bar(1, String::from("a")), Bar(2, String::from("b"))];
+
+        let mut first = DynamicTupleStruct::default();
+        first.insert(1_i32);
+        let mut second = DynamicTupleStruct::default();
+        second.insert(2_i32);
+
+        let mut partial = DynamicList::default();
+        partial.push(first.clone_dynamic());
+        assert_eq!(Some(false), is_subset(&partial, &full));
+
+        partial.push(second);
+        assert_eq!(Some(true), is_subset(&partial, &full));
+
+        assert_eq!(Some(true), is_subset(&[4_u8, 5], &[4_u8, 5]));
+        assert_eq!(Some(false), is_subset(&[4_u8], &[4_u8, 5]));
+        assert_eq!(Some(false), is_subset(&[4_u8, 6], &[4_u8, 5]));
+    }
+
+    #[test]
+    fn should_check_subset_of_enum_variants() {
+        let full = Baz::B { value: 1.0 };
+
+        let mut variant = DynamicStruct::default();
+        variant.insert("value", 1.0_f32);
+        assert_eq!(
+            Some(true),
+            is_subset(&DynamicEnum::new("B", variant), &full)
+        );
+        assert_eq!(
+            Some(true),
+            is_subset(&DynamicEnum::new("B", DynamicStruct::default()), &full)
+        );
+        assert_eq!(Some(false), is_subset(&Baz::A(1), &full));
+        assert_eq!(Some(true), is_subset(&Baz::A(1), &Baz::A(1)));
+        assert_eq!(Some(false), is_subset(&Baz::A(2), &Baz::A(1)));
+    }
+
+    #[test]
+    fn should_check_nested_subsets() {
+        let foo = get_foo();
+
+        let mut bar = DynamicTupleStruct::default();
+        bar.insert(3_i32);
+        let mut list = DynamicList::default();
+        list.push(bar);
+        let mut partial = DynamicStruct::default();
+        partial.insert("list", list);
+        partial.insert("map", HashMap::<usize, f32>::new());
+        partial.insert("baz", Baz::A(6));
+        assert_eq!(Some(true), is_subset(&partial, &foo));
+
+        partial.insert("baz", Baz::A(7));
+        assert_eq!(Some(false), is_subset(&partial, &foo));
+    }
+
+    #[test]
+    fn should_propagate_indeterminate_subsets() {
+        #[derive(Reflect, Clone)]
+        #[reflect_value(opaque)]
+        struct Opaque;
+
+        #[derive(Reflect)]
+        struct Container {
+            id: u32,
+            opaque: Opaque,
+        }
+
+        let full = Container {
+            id: 1,
+            opaque: Opaque,
+        };
+
+        let mut partial = DynamicStruct::default();
+        partial.insert("id", 1_u32);
+        assert_eq!(Some(true), is_subset(&partial, &full));
+
+        partial.insert("opaque", Opaque);
+        assert_eq!(None, is_subset(&partial, &full));
+    }
+}
@@ -0,0 +1,233 @@
+use super::{PathResult, ReflectPath};
+use crate::Reflect;
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A trait for types which provide typed accessors for building reflection paths into their fields.
+///
+/// This trait is automatically implemented when deriving [`Reflect`] with `#[reflect(generate_paths)]`.
+///
+/// [`Reflect`]: derive@crate::Reflect
+pub trait GetFieldPaths {
+    /// The generated type containing the path accessors for this type's fields.
+    type FieldPaths;
+
+    /// Returns the path accessors for this type's fields, relative to the given path.
+    fn field_paths_from(prefix: FieldPath<Self>) -> Self::FieldPaths;
+}
+
+/// A reflection path to a value of type `T`.
+///
+/// These are usually created with the accessors generated by `#[reflect(generate_paths)]`,
+/// which ensures that renaming a field results in a compile error rather than a broken path.
+///
+/// `FieldPath` can be used anywhere a path string can, such as with [`GetPath`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{GetPath, Reflect};
+/// #[derive(Reflect)]
+/// #[reflect(generate_paths)]
+/// struct Transform {
+///     scale: f32,
+/// }
+///
+/// #[derive(Reflect)]
+/// #[reflect(generate_paths)]
+/// struct Player {
+///     transform: Transform,
+/// }
+///
+/// let path = Player::fields().transform().fields().scale();
+/// assert_eq!("transform.scale", path.as_str());
+///
+/// let player = Player { transform: Transform { scale: 2.0 } };
+/// assert_eq!(&2.0, player.path::<f32>(&path).unwrap());
+/// ```
+///
+/// [`GetPath`]: crate::GetPath
+pub struct FieldPath<T: ?Sized> {
+    path: Cow<'static, str>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: ?Sized> FieldPath<T> {
+    /// Creates a path to the root of a value of type `T`.
+    pub const fn root() -> Self {
+        Self {
+            path: Cow::Borrowed(""),
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a path from the given path string.
+    ///
+    /// Note that this does not validate that the path actually leads to a value of type `T`.
+    pub fn new(path: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            path: path.into(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a path to the field with the given name, relative to this path.
+    pub fn field<U: ?Sized>(&self, name: &'static str) -> FieldPath<U> {
+        if self.path.is_empty() {
+            FieldPath::new(name)
+        } else {
+            FieldPath::new(format!("{}.{name}", self.path))
+        }
+    }
+
+    /// Returns the path accessors for the fields of `T`, relative to this path.
+    pub fn fields(&self) -> T::FieldPaths
+    where
+        T: GetFieldPaths,
+    {
+        T::field_paths_from(self.clone())
+    }
+
+    /// Returns the path as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.path
+    }
+
+    /// Consumes the path, returning the path string.
+    pub fn into_string(self) -> String {
+        self.path.into_owned()
+    }
+}
+
+impl<T: ?Sized> Clone for FieldPath<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.path.clone())
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for FieldPath<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FieldPath").field(&self.path).finish()
+    }
+}
+
+impl<T: ?Sized> fmt::Display for FieldPath<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.path)
+    }
+}
+
+impl<T: ?Sized> AsRef<str> for FieldPath<T> {
+    fn as_ref(&self) -> &str {
+        &self.path
+    }
+}
+
+impl<'a, T: ?Sized> ReflectPath<'a> for &'a FieldPath<T> {
+    fn reflect_element(self, root: &dyn Reflect) -> PathResult<'a, &dyn Reflect> {
+        self.as_str().reflect_element(root)
+    }
+
+    fn reflect_element_mut(self, root: &mut dyn Reflect) -> PathResult<'a, &mut dyn Reflect> {
+        self.as_str().reflect_element_mut(root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_reflect;
+    use crate::{GetPath, Reflect, Struct};
+
+    #[derive(Reflect)]
+    #[reflect(generate_paths)]
+    struct Vec2 {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Reflect)]
+    #[reflect(generate_paths)]
+    struct Transform {
+        translation: Vec2,
+        scale: f32,
+    }
+
+    #[derive(Reflect)]
+    #[reflect(generate_paths)]
+    struct Player {
+        #[reflect(ignore)]
+        _id: u64,
+        name: String,
+        transform: Transform,
+    }
+
+    fn player() -> Player {
+        Player {
+            _id: 0,
+            name: String::from("hero"),
+            transform: Transform {
+                translation: Vec2 { x: 1.0, y: 2.0 },
+                scale: 3.0,
+            },
+        }
+    }
+
+    #[test]
+    fn should_generate_path_constants() {
+        let player = player();
+        assert_eq!(Some(player_fields::NAME), player.name_at(0));
+        assert_eq!(Some(player_fields::TRANSFORM), player.name_at(1));
+        assert_eq!(player_fields::NAME, Player::path_name());
+        assert_eq!(player_fields::TRANSFORM, Player::path_transform());
+
+        let transform = &player.transform;
+        assert_eq!(Some(transform_fields::TRANSLATION), transform.name_at(0));
+        assert_eq!(Some(transform_fields::SCALE), transform.name_at(1));
+    }
+
+    #[test]
+    fn should_compose_nested_paths() {
+        let mut player = player();
+
+        let path = Player::fields()
+            .transform()
+            .fields()
+            .translation()
+            .fields()
+            .y();
+        assert_eq!("transform.translation.y", path.as_str());
+        assert_eq!(&2.0, player.path::<f32>(&path).unwrap());
+
+        *player.path_mut::<f32>(&path).unwrap() = 5.0;
+        assert_eq!(5.0, player.transform.translation.y);
+
+        let path = Player::fields().name();
+        assert_eq!("hero", player.path::<String>(&path).unwrap());
+        assert_eq!(
+            "transform.scale",
+            Player::fields().transform().fields().scale().to_string()
+        );
+    }
+
+    #[test]
+    fn should_support_any_field_name() {
+        #[derive(Reflect)]
+        #[reflect(generate_paths)]
+        struct HTTPRequest {
+            default: u32,
+            r#type: String,
+        }
+
+        let request = HTTPRequest {
+            default: 123,
+            r#type: String::from("GET"),
+        };
+        assert_eq!(Some(http_request_fields::DEFAULT), request.name_at(0));
+        assert_eq!(Some(http_request_fields::TYPE), request.name_at(1));
+        assert_eq!("type", HTTPRequest::path_type());
+
+        let path = HTTPRequest::fields().default();
+        assert_eq!(&123, request.path::<u32>(&path).unwrap());
+    }
+}
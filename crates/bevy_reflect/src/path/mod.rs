@@ -4,6 +4,9 @@ pub use access::*;
 mod error;
 pub use error::*;
 
+mod field_paths;
+pub use field_paths::*;
+
 mod parse;
 pub use parse::ParseError;
 use parse::PathParser;
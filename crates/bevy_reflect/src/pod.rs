@@ -0,0 +1,174 @@
+use crate::{FromType, Reflect};
+
+/// A reflected struct made up entirely of plain-old-data fields.
+///
+/// This trait is automatically implemented when using `#[reflect(pod)]`
+/// on a struct whose fields are all primitives, or arrays or tuples of primitives.
+///
+/// Applying a value of the same type onto such a struct is done with a single assignment
+/// rather than by recursively applying each field,
+/// which is what [`Reflect::apply`] and [`Reflect::try_apply`] do automatically for these types.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{PodStruct, Reflect};
+/// #[derive(Reflect, Debug, PartialEq)]
+/// #[reflect(pod)]
+/// struct Snapshot {
+///     position: [f32; 3],
+///     health: u32,
+/// }
+///
+/// let mut value = Snapshot { position: [0.0; 3], health: 100 };
+/// value.pod_apply(&Snapshot { position: [1.0, 2.0, 3.0], health: 50 });
+/// assert_eq!(Snapshot { position: [1.0, 2.0, 3.0], health: 50 }, value);
+/// ```
+pub trait PodStruct: Reflect {
+    /// Overwrites every field of `self` with the corresponding field of `other`.
+    fn pod_apply(&mut self, other: &Self);
+}
+
+/// Type data for [`PodStruct`] types.
+///
+/// This allows a value to be applied onto another of the same plain-old-data type
+/// without knowing their concrete type.
+///
+/// A [`ReflectPod`] for type `T` can be obtained via [`FromType::from_type`].
+#[derive(Clone)]
+pub struct ReflectPod {
+    apply: fn(&mut dyn Reflect, &dyn Reflect) -> bool,
+}
+
+impl ReflectPod {
+    /// Applies `value` onto `target` with a single assignment.
+    ///
+    /// Returns `false`, leaving `target` untouched,
+    /// if either value is not of the type this data was created for.
+    pub fn apply(&self, target: &mut dyn Reflect, value: &dyn Reflect) -> bool {
+        (self.apply)(target, value)
+    }
+}
+
+impl<T: PodStruct> FromType<T> for ReflectPod {
+    fn from_type() -> Self {
+        ReflectPod {
+            apply: |target, value| match target.downcast_mut::<T>() {
+                Some(target) => try_apply_pod(target, value),
+                None => false,
+            },
+        }
+    }
+}
+
+/// Applies `value` onto `target` with [`PodStruct::pod_apply`] if it is also a `T`.
+///
+/// Returns `false` if `value` is of a different type,
+/// in which case it should be applied field by field instead.
+pub(crate) fn try_apply_pod<T: PodStruct>(target: &mut T, value: &dyn Reflect) -> bool {
+    let Some(value) = value.downcast_ref::<T>() else {
+        return false;
+    };
+
+    #[cfg(test)]
+    tests::POD_APPLY_COUNT.with(|count| count.set(count.get() + 1));
+
+    target.pod_apply(value);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{DynamicStruct, DynamicTupleStruct, GetTypeRegistration, Struct};
+    use std::cell::Cell;
+
+    thread_local! {
+        /// The number of times the fast path was taken on the current thread.
+        pub(super) static POD_APPLY_COUNT: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn pod_apply_count() -> usize {
+        POD_APPLY_COUNT.with(Cell::get)
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(pod)]
+    struct Snapshot {
+        position: [f32; 3],
+        velocity: (f32, f32),
+        health: u32,
+        alive: bool,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect(pod)]
+    struct Id(u64, char);
+
+    fn snapshot(offset: f32) -> Snapshot {
+        Snapshot {
+            position: [offset, offset + 1.0, offset + 2.0],
+            velocity: (-offset, offset),
+            health: offset as u32,
+            alive: offset > 0.0,
+        }
+    }
+
+    #[test]
+    fn should_apply_same_as_dynamic() {
+        let source = snapshot(5.0);
+
+        let mut fast = snapshot(0.0);
+        fast.apply(&source);
+
+        // Dynamic values are not `Snapshot`, so they take the field-by-field path
+        let mut slow = snapshot(0.0);
+        slow.apply(&source.clone_dynamic());
+
+        assert_eq!(source, fast);
+        assert_eq!(fast, slow);
+
+        let mut id = Id(1, 'a');
+        id.apply(&Id(2, 'b'));
+        assert_eq!(Id(2, 'b'), id);
+
+        let mut dynamic = DynamicTupleStruct::default();
+        dynamic.insert(3_u64);
+        dynamic.insert('c');
+        id.apply(&dynamic);
+        assert_eq!(Id(3, 'c'), id);
+    }
+
+    #[test]
+    fn should_take_fast_path_for_same_type() {
+        let start = pod_apply_count();
+
+        let mut value = snapshot(0.0);
+        for i in 1..=10 {
+            value.apply(&snapshot(i as f32));
+        }
+        assert_eq!(snapshot(10.0), value);
+        assert_eq!(start + 10, pod_apply_count());
+
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("health", 42_u32);
+        value.apply(&dynamic);
+        assert_eq!(42, value.health);
+        assert_eq!(start + 10, pod_apply_count());
+    }
+
+    #[test]
+    fn should_apply_via_type_data() {
+        let registration = Snapshot::get_type_registration();
+        let reflect_pod = registration.data::<ReflectPod>().unwrap();
+
+        let mut value = snapshot(0.0);
+        assert!(reflect_pod.apply(&mut value, &snapshot(3.0)));
+        assert_eq!(snapshot(3.0), value);
+
+        assert!(!reflect_pod.apply(&mut value, &Id(1, 'a')));
+        assert!(!reflect_pod.apply(&mut Id(1, 'a'), &snapshot(3.0)));
+        assert_eq!(snapshot(3.0), value);
+    }
+}
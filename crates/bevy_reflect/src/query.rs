@@ -0,0 +1,386 @@
+//! Searching reflected values for nested values of a given type or matching a predicate.
+//!
+//! Each search walks the entire tree in depth-first order,
+//! visiting a value before its fields and continuing into values that match.
+//! This includes the fields of structs, tuples, and enums,
+//! the elements of lists and arrays, and both the keys and values of maps.
+//!
+//! Every match is reported alongside its path from the root,
+//! written in the same syntax as [`GetPath`] so it can be resolved again later.
+//! The root itself is reported with an empty path.
+//!
+//! Maps have no [`GetPath`] syntax, so their entries are written using the [`Debug`] output of their key:
+//! `["a"]` for the value stored under the key `"a"`, and `{"a"}` for the key itself.
+//! Paths passing through a map cannot be resolved with [`GetPath`].
+//!
+//! [`GetPath`]: crate::GetPath
+
+use crate::{Reflect, ReflectMut, ReflectRef};
+use std::fmt::Write;
+
+/// Returns every value in `root` whose concrete type is `T`, along with its path.
+///
+/// Dynamic types, such as a [`DynamicStruct`] representing `T`, are not matched.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{query::find_all, Reflect};
+/// #[derive(Reflect)]
+/// struct Player {
+///     name: String,
+///     inventory: Vec<Item>,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Item {
+///     name: String,
+/// }
+///
+/// let player = Player {
+///     name: "Ferris".to_string(),
+///     inventory: vec![Item { name: "Sword".to_string() }],
+/// };
+///
+/// let names = find_all::<String>(&player);
+/// assert_eq!(
+///     vec![
+///         (".name".to_string(), &"Ferris".to_string()),
+///         (".inventory[0].name".to_string(), &"Sword".to_string()),
+///     ],
+///     names
+/// );
+/// ```
+///
+/// [`DynamicStruct`]: crate::DynamicStruct
+pub fn find_all<T: Reflect>(root: &dyn Reflect) -> Vec<(String, &T)> {
+    let mut found = Vec::new();
+    walk(root, &mut String::new(), &mut |path, value| {
+        if let Some(value) = value.downcast_ref::<T>() {
+            found.push((path.to_string(), value));
+        }
+    });
+    found
+}
+
+/// Returns every value in `root` for which `predicate` returns `true`, along with its path.
+pub fn find_where(
+    root: &dyn Reflect,
+    predicate: impl Fn(&dyn Reflect) -> bool,
+) -> Vec<(String, &dyn Reflect)> {
+    let mut found = Vec::new();
+    walk(root, &mut String::new(), &mut |path, value| {
+        if predicate(value) {
+            found.push((path.to_string(), value));
+        }
+    });
+    found
+}
+
+/// Calls `f` with the path and a mutable reference to every value in `root` whose concrete type is `T`.
+///
+/// Map keys cannot be mutated, so only map values are visited.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{query::for_each_of_type_mut, Reflect};
+/// let mut names = vec![("Ferris".to_string(), 1_u32), ("Corro".to_string(), 2_u32)];
+///
+/// for_each_of_type_mut::<String>(&mut names, |_, name| name.make_ascii_uppercase());
+/// assert_eq!("FERRIS", names[0].0);
+/// assert_eq!("CORRO", names[1].0);
+/// ```
+pub fn for_each_of_type_mut<T: Reflect>(root: &mut dyn Reflect, mut f: impl FnMut(&str, &mut T)) {
+    walk_mut(root, &mut String::new(), &mut |path, value| {
+        if let Some(value) = value.downcast_mut::<T>() {
+            f(path, value);
+        }
+    });
+}
+
+/// Calls `f` with the path and a mutable reference to every value in `root` for which `predicate` returns `true`.
+///
+/// Map keys cannot be mutated, so only map values are visited.
+pub fn for_each_where_mut(
+    root: &mut dyn Reflect,
+    predicate: impl Fn(&dyn Reflect) -> bool,
+    mut f: impl FnMut(&str, &mut dyn Reflect),
+) {
+    walk_mut(root, &mut String::new(), &mut |path, value| {
+        if predicate(value) {
+            f(path, value);
+        }
+    });
+}
+
+fn walk<'a>(
+    value: &'a dyn Reflect,
+    path: &mut String,
+    visit: &mut dyn FnMut(&str, &'a dyn Reflect),
+) {
+    visit(path, value);
+
+    let len = path.len();
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                write!(path, ".{}", value.name_at(index).unwrap()).unwrap();
+                walk(field, path, visit);
+                path.truncate(len);
+            }
+        }
+        ReflectRef::TupleStruct(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                write!(path, ".{index}").unwrap();
+                walk(field, path, visit);
+                path.truncate(len);
+            }
+        }
+        ReflectRef::Tuple(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                write!(path, ".{index}").unwrap();
+                walk(field, path, visit);
+                path.truncate(len);
+            }
+        }
+        ReflectRef::List(value) => {
+            for (index, item) in value.iter().enumerate() {
+                write!(path, "[{index}]").unwrap();
+                walk(item, path, visit);
+                path.truncate(len);
+            }
+        }
+        ReflectRef::Array(value) => {
+            for (index, item) in value.iter().enumerate() {
+                write!(path, "[{index}]").unwrap();
+                walk(item, path, visit);
+                path.truncate(len);
+            }
+        }
+        ReflectRef::Map(value) => {
+            for (key, item) in value.iter() {
+                write!(path, "{{{key:?}}}").unwrap();
+                walk(key, path, visit);
+                path.truncate(len);
+
+                write!(path, "[{key:?}]").unwrap();
+                walk(item, path, visit);
+                path.truncate(len);
+            }
+        }
+        ReflectRef::Enum(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                match field.name() {
+                    Some(name) => write!(path, ".{name}").unwrap(),
+                    None => write!(path, ".{index}").unwrap(),
+                }
+                walk(field.value(), path, visit);
+                path.truncate(len);
+            }
+        }
+        ReflectRef::Value(_) => {}
+    }
+}
+
+fn walk_mut(
+    value: &mut dyn Reflect,
+    path: &mut String,
+    visit: &mut dyn FnMut(&str, &mut dyn Reflect),
+) {
+    visit(path, value);
+
+    let len = path.len();
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => {
+            for index in 0..value.field_len() {
+                write!(path, ".{}", value.name_at(index).unwrap()).unwrap();
+                walk_mut(value.field_at_mut(index).unwrap(), path, visit);
+                path.truncate(len);
+            }
+        }
+        ReflectMut::TupleStruct(value) => {
+            for index in 0..value.field_len() {
+                write!(path, ".{index}").unwrap();
+                walk_mut(value.field_mut(index).unwrap(), path, visit);
+                path.truncate(len);
+            }
+        }
+        ReflectMut::Tuple(value) => {
+            for index in 0..value.field_len() {
+                write!(path, ".{index}").unwrap();
+                walk_mut(value.field_mut(index).unwrap(), path, visit);
+                path.truncate(len);
+            }
+        }
+        ReflectMut::List(value) => {
+            for index in 0..value.len() {
+                write!(path, "[{index}]").unwrap();
+                walk_mut(value.get_mut(index).unwrap(), path, visit);
+                path.truncate(len);
+            }
+        }
+        ReflectMut::Array(value) => {
+            for index in 0..value.len() {
+                write!(path, "[{index}]").unwrap();
+                walk_mut(value.get_mut(index).unwrap(), path, visit);
+                path.truncate(len);
+            }
+        }
+        ReflectMut::Map(value) => {
+            for index in 0..value.len() {
+                let (key, item) = value.get_at_mut(index).unwrap();
+                write!(path, "[{key:?}]").unwrap();
+                walk_mut(item, path, visit);
+                path.truncate(len);
+            }
+        }
+        ReflectMut::Enum(value) => {
+            for index in 0..value.field_len() {
+                match value.name_at(index) {
+                    Some(name) => write!(path, ".{name}").unwrap(),
+                    None => write!(path, ".{index}").unwrap(),
+                }
+                walk_mut(value.field_at_mut(index).unwrap(), path, visit);
+                path.truncate(len);
+            }
+        }
+        ReflectMut::Value(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::GetPath;
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Scene {
+        name: String,
+        root: Node,
+        tags: HashMap<u32, String>,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Node {
+        label: Label,
+        children: Vec<(u32, Label)>,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum Label {
+        None,
+        Named(String),
+        Described { title: String, id: u32 },
+    }
+
+    fn scene() -> Scene {
+        Scene {
+            name: "level".to_string(),
+            root: Node {
+                label: Label::Named("player".to_string()),
+                children: vec![
+                    (0, Label::None),
+                    (
+                        1,
+                        Label::Described {
+                            title: "player_camera".to_string(),
+                            id: 7,
+                        },
+                    ),
+                    (2, Label::Named("enemy".to_string())),
+                ],
+            },
+            tags: HashMap::from([(5, "boss".to_string())]),
+        }
+    }
+
+    #[test]
+    fn should_find_all_of_type() {
+        let scene = scene();
+        let found = find_all::<String>(&scene);
+
+        let paths = found
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ".name",
+                ".root.label.0",
+                ".root.children[1].1.title",
+                ".root.children[2].1.0",
+                ".tags[5]",
+            ],
+            paths
+        );
+
+        // Paths outside of maps resolve back to the same values
+        for (path, value) in &found[..4] {
+            assert_eq!(Ok(*value), scene.path::<String>(path.as_str()));
+        }
+        assert_eq!("boss", found[4].1);
+    }
+
+    #[test]
+    fn should_find_where() {
+        let scene = scene();
+        let found = find_where(&scene, |value| {
+            value
+                .downcast_ref::<String>()
+                .is_some_and(|value| value.contains("player"))
+        });
+
+        assert_eq!(2, found.len());
+        assert_eq!(".root.label.0", found[0].0);
+        assert_eq!(".root.children[1].1.title", found[1].0);
+        assert_eq!(
+            Some(&"player_camera".to_string()),
+            found[1].1.downcast_ref()
+        );
+
+        let keys = find_where(&scene, |value| value.is::<u32>());
+        let paths = keys
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ".root.children[0].0",
+                ".root.children[1].0",
+                ".root.children[1].1.id",
+                ".root.children[2].0",
+                ".tags{5}",
+            ],
+            paths
+        );
+    }
+
+    #[test]
+    fn should_mutate_each_of_type() {
+        let mut scene = scene();
+        let mut paths = Vec::new();
+        for_each_of_type_mut::<String>(&mut scene, |path, value| {
+            paths.push(path.to_string());
+            value.make_ascii_uppercase();
+        });
+
+        assert_eq!(5, paths.len());
+        assert_eq!("LEVEL", scene.name);
+        assert_eq!(Label::Named("PLAYER".to_string()), scene.root.label);
+        assert_eq!(
+            Label::Described {
+                title: "PLAYER_CAMERA".to_string(),
+                id: 7,
+            },
+            scene.root.children[1].1
+        );
+        assert_eq!(Label::Named("ENEMY".to_string()), scene.root.children[2].1);
+        assert_eq!("BOSS", scene.tags[&5]);
+        assert!(find_all::<String>(&scene)
+            .iter()
+            .all(|(_, value)| value.chars().all(|c| !c.is_ascii_lowercase())));
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
 };
 use std::{
     any::{Any, TypeId},
+    cmp::Ordering,
     fmt::Debug,
 };
 
@@ -135,13 +136,55 @@ pub enum ApplyError {
         enum_name: Box<str>,
         variant_name: Box<str>,
     },
+
+    #[error("the lock of `{type_path}` is poisoned")]
+    /// Attempted to apply to or from a lock type, such as a `Mutex`, whose lock is poisoned.
+    PoisonedLock { type_path: Box<str> },
+
+    #[error("cannot apply `{patch_kind}` to the fields of variant `{expected_variant}`")]
+    /// Attempted to apply a struct or tuple patch to the fields of an enum
+    /// whose current variant has a different shape.
+    VariantShapeMismatch {
+        expected_variant: Box<str>,
+        patch_kind: ReflectKind,
+    },
+}
+
+/// The error returned by `try_take` and `try_downcast` on `dyn Reflect`
+/// when the value is not of the expected type.
+///
+/// The original value can be recovered with [`TakeError::into_inner`].
+#[derive(Error, Debug)]
+#[error("expected value of type `{expected}` but found `{actual}`")]
+pub struct TakeError {
+    /// The [type name] of the expected type.
+    ///
+    /// [type name]: std::any::type_name
+    pub expected: &'static str,
+    /// The [type path] of the value that was actually found.
+    ///
+    /// [type path]: crate::DynamicTypePath::reflect_type_path
+    pub actual: String,
+    value: Box<dyn Reflect>,
+}
+
+impl TakeError {
+    /// Returns a reference to the value that failed to be taken.
+    pub fn inner(&self) -> &dyn Reflect {
+        &*self.value
+    }
+
+    /// Returns the value that failed to be taken.
+    pub fn into_inner(self) -> Box<dyn Reflect> {
+        self.value
+    }
 }
 
 /// A zero-sized enumuration of the "kinds" of a reflected type.
 ///
 /// A [`ReflectKind`] is obtained via [`Reflect::reflect_kind`],
 /// or via [`ReflectRef::kind`],[`ReflectMut::kind`] or [`ReflectOwned::kind`].
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum ReflectKind {
     Struct,
     TupleStruct,
@@ -325,24 +368,25 @@ pub trait Reflect: DynamicTypePath + Any + Send + Sync {
         None
     }
 
+    /// Returns a "partial ordering" comparison result.
+    ///
+    /// If the underlying type does not support ordering,
+    /// or if `value` is not of a comparable type, returns `None`.
+    fn reflect_partial_cmp(&self, _value: &dyn Reflect) -> Option<Ordering> {
+        None
+    }
+
     /// Debug formatter for the value.
     ///
     /// Any value that is not an implementor of other `Reflect` subtraits
     /// (e.g. [`List`], [`Map`]), will default to the format: `"Reflect(type_path)"`,
     /// where `type_path` is the [type path] of the underlying type.
+    /// [Opaque] values instead use the format: `"<type_path (opaque)>"`.
     ///
     /// [type path]: TypePath::type_path
+    /// [Opaque]: ValueInfo::is_opaque
     fn debug(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.reflect_ref() {
-            ReflectRef::Struct(dyn_struct) => struct_debug(dyn_struct, f),
-            ReflectRef::TupleStruct(dyn_tuple_struct) => tuple_struct_debug(dyn_tuple_struct, f),
-            ReflectRef::Tuple(dyn_tuple) => tuple_debug(dyn_tuple, f),
-            ReflectRef::List(dyn_list) => list_debug(dyn_list, f),
-            ReflectRef::Array(dyn_array) => array_debug(dyn_array, f),
-            ReflectRef::Map(dyn_map) => map_debug(dyn_map, f),
-            ReflectRef::Enum(dyn_enum) => enum_debug(dyn_enum, f),
-            _ => write!(f, "Reflect({})", self.reflect_type_path()),
-        }
+        reflect_debug(self, f)
     }
 
     /// Returns a serializable version of the value.
@@ -369,6 +413,31 @@ pub trait Reflect: DynamicTypePath + Any + Send + Sync {
     }
 }
 
+/// The default implementation of [`Reflect::debug`].
+///
+/// This is also used by types deriving `Reflect` with `#[reflect(auto)]`
+/// that turn out not to implement [`Debug`].
+pub(crate) fn reflect_debug<T: Reflect + ?Sized>(
+    value: &T,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    match value.reflect_ref() {
+        ReflectRef::Struct(dyn_struct) => struct_debug(dyn_struct, f),
+        ReflectRef::TupleStruct(dyn_tuple_struct) => tuple_struct_debug(dyn_tuple_struct, f),
+        ReflectRef::Tuple(dyn_tuple) => tuple_debug(dyn_tuple, f),
+        ReflectRef::List(dyn_list) => list_debug(dyn_list, f),
+        ReflectRef::Array(dyn_array) => array_debug(dyn_array, f),
+        ReflectRef::Map(dyn_map) => map_debug(dyn_map, f),
+        ReflectRef::Enum(dyn_enum) => enum_debug(dyn_enum, f),
+        _ => match value.get_represented_type_info() {
+            Some(TypeInfo::Value(info)) if info.is_opaque() => {
+                write!(f, "<{} (opaque)>", info.type_path())
+            }
+            _ => write!(f, "Reflect({})", value.reflect_type_path()),
+        },
+    }
+}
+
 impl Debug for dyn Reflect {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.debug(f)
@@ -415,6 +484,28 @@ impl dyn Reflect {
         self.downcast::<T>().map(|value| *value)
     }
 
+    /// Downcasts the value to type `T`, consuming the trait object.
+    ///
+    /// This is the same as [`downcast`](Self::downcast),
+    /// but the returned [`TakeError`] contains the names of both the expected and actual types
+    /// along with the original value.
+    pub fn try_downcast<T: Reflect>(self: Box<dyn Reflect>) -> Result<Box<T>, TakeError> {
+        self.downcast::<T>().map_err(|value| TakeError {
+            expected: std::any::type_name::<T>(),
+            actual: value.reflect_type_path().to_string(),
+            value,
+        })
+    }
+
+    /// Downcasts the value to type `T`, unboxing and consuming the trait object.
+    ///
+    /// This is the same as [`take`](Self::take),
+    /// but the returned [`TakeError`] contains the names of both the expected and actual types
+    /// along with the original value.
+    pub fn try_take<T: Reflect>(self: Box<dyn Reflect>) -> Result<T, TakeError> {
+        self.try_downcast::<T>().map(|value| *value)
+    }
+
     /// Returns `true` if the underlying value represents a value of type `T`, or `false`
     /// otherwise.
     ///
@@ -0,0 +1,1008 @@
+//! Bridging opaque values to dynamic reflection types through their serde representation.
+//!
+//! Types reflected with `#[reflect_value(Serialize, Deserialize)]` have no reflected structure,
+//! which leaves inspectors unable to show or edit them.
+//! [`to_dynamic_via_serde`] serializes such a value into dynamic types mirroring the serde data model,
+//! and [`from_dynamic_via_serde`] feeds an edited copy back through the type's [`ReflectDeserialize`].
+//!
+//! The bridge supports any serde representation built from primitives, strings, sequences,
+//! tuples, structs, maps, options and enum variants.
+//! Opaque values nested within a dynamic value are bridged recursively when converting back.
+//!
+//! It deliberately does not:
+//! * change the reflection category of the type or register anything new:
+//!   the dynamic value does not represent the original type and is only meaningful to the bridge,
+//! * preserve serde type hints: struct names, newtype wrappers and unit structs are erased,
+//!   and bytes become a list of `u8`.
+//!   Deserialization goes through [`deserialize_any`](Deserializer::deserialize_any),
+//!   so representations that only round-trip through non-self-describing formats are rejected,
+//! * support map keys which cannot be hashed, such as struct keys,
+//!   since they cannot be stored in a [`DynamicMap`].
+
+use crate::{
+    DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple, DynamicTupleStruct,
+    DynamicVariant, Enum, Map, Reflect, ReflectDeserialize, ReflectRef, ReflectSerialize,
+    TypeRegistration, TypeRegistry, VariantType,
+};
+use serde::de::{
+    self, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess,
+    Visitor,
+};
+use serde::ser::{
+    self, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+    SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+};
+use serde::Deserializer;
+use std::fmt::Display;
+use thiserror::Error;
+
+/// An error returned when bridging a value between its serde representation
+/// and dynamic reflection types.
+///
+/// These are returned by [`to_dynamic_via_serde`] and [`from_dynamic_via_serde`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum BridgeError {
+    #[error("`{type_path}` does not represent any registered type")]
+    /// The value does not represent a type registered in the [`TypeRegistry`].
+    UnregisteredType { type_path: String },
+
+    #[error("`{type_path}` does not register `ReflectSerialize`")]
+    /// The type does not register [`ReflectSerialize`].
+    MissingReflectSerialize { type_path: String },
+
+    #[error("`{type_path}` does not register `ReflectDeserialize`")]
+    /// The type does not register [`ReflectDeserialize`].
+    MissingReflectDeserialize { type_path: String },
+
+    #[error("map key of type `{type_path}` cannot be hashed")]
+    /// The serde representation contains a map whose keys cannot be stored in a [`DynamicMap`],
+    /// such as a map keyed by structs.
+    UnhashableKey { type_path: String },
+
+    #[error("{0}")]
+    /// An error raised by the type's serde implementation.
+    ///
+    /// Errors raised while deserializing are always reported as this variant,
+    /// since they pass through the type-erased [`ReflectDeserialize`].
+    Custom(String),
+}
+
+impl ser::Error for BridgeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+impl de::Error for BridgeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+/// Converts a value into dynamic reflection types by way of its serde representation.
+///
+/// Values registered with `#[reflect_value(Serialize, Deserialize)]` have no reflected fields,
+/// but their serde representation is often perfectly structured.
+/// This function serializes the value with its registered [`ReflectSerialize`]
+/// and builds the result out of dynamic types, giving it an editable structure:
+///
+/// | Serde data model                 | Result                                     |
+/// | -------------------------------- | ------------------------------------------ |
+/// | primitives, strings and `()`     | the primitive value, `String` or `()`      |
+/// | bytes, sequences                 | [`DynamicList`]                            |
+/// | tuples                           | [`DynamicTuple`]                           |
+/// | tuple structs                    | [`DynamicTupleStruct`]                     |
+/// | structs                          | [`DynamicStruct`]                          |
+/// | maps                             | [`DynamicMap`]                             |
+/// | enum variants and options        | [`DynamicEnum`]                            |
+/// | newtype structs and unit structs | the inner value and `()` respectively      |
+///
+/// The result does not represent any type, since its structure is that of the serde representation
+/// rather than the reflected type.
+/// Use [`from_dynamic_via_serde`] to turn it back into a concrete value.
+///
+/// # Errors
+///
+/// Returns an error if the value's type is not registered with [`ReflectSerialize`],
+/// or if its serde representation cannot be expressed with dynamic types,
+/// such as a map keyed by structs.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{ReflectMut, Struct, TypeRegistry};
+/// # use bevy_reflect::serde::{from_dynamic_via_serde, to_dynamic_via_serde};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Reflect, Clone, Serialize, Deserialize, PartialEq, Debug)]
+/// #[reflect_value(Serialize, Deserialize, PartialEq)]
+/// struct Color {
+///     name: String,
+///     alpha: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Color>();
+///
+/// let color = Color { name: String::from("red"), alpha: 1.0 };
+/// let mut dynamic = to_dynamic_via_serde(&color, &registry).unwrap();
+///
+/// let ReflectMut::Struct(fields) = dynamic.reflect_mut() else { panic!() };
+/// *fields.get_field_mut::<f32>("alpha").unwrap() = 0.5;
+///
+/// let registration = registry.get(std::any::TypeId::of::<Color>()).unwrap();
+/// let updated = from_dynamic_via_serde(&*dynamic, registration, &registry).unwrap();
+/// assert_eq!(
+///     &Color { name: String::from("red"), alpha: 0.5 },
+///     updated.downcast_ref::<Color>().unwrap()
+/// );
+/// ```
+pub fn to_dynamic_via_serde(
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, BridgeError> {
+    let registration = value
+        .get_represented_type_info()
+        .and_then(|info| registry.get(info.type_id()))
+        .ok_or_else(|| BridgeError::UnregisteredType {
+            type_path: value.reflect_type_path().to_string(),
+        })?;
+    let reflect_serialize = registration.data::<ReflectSerialize>().ok_or_else(|| {
+        BridgeError::MissingReflectSerialize {
+            type_path: registration.type_info().type_path().to_string(),
+        }
+    })?;
+
+    let serializable = reflect_serialize.get_serializable(value);
+    serializable.borrow().serialize(DynamicSerializer)
+}
+
+/// Reconstructs a concrete value from its serde representation expressed with dynamic types.
+///
+/// This is the inverse of [`to_dynamic_via_serde`]:
+/// the dynamic value is fed to the [`ReflectDeserialize`] of the given registration.
+///
+/// Values in the dynamic representation which are not primitives are converted
+/// with [`to_dynamic_via_serde`] first, using the given registry.
+///
+/// # Errors
+///
+/// Returns an error if the registration does not contain [`ReflectDeserialize`],
+/// or if the type's serde implementation rejects the dynamic value.
+/// The latter includes values in the dynamic representation which cannot be bridged,
+/// such as values of types not registering [`ReflectSerialize`].
+pub fn from_dynamic_via_serde(
+    dynamic: &dyn Reflect,
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, BridgeError> {
+    let reflect_deserialize = registration.data::<ReflectDeserialize>().ok_or_else(|| {
+        BridgeError::MissingReflectDeserialize {
+            type_path: registration.type_info().type_path().to_string(),
+        }
+    })?;
+
+    reflect_deserialize.deserialize(DynamicDeserializer {
+        value: dynamic,
+        registry,
+    })
+}
+
+/// A [`Serializer`](ser::Serializer) which builds dynamic reflection types.
+struct DynamicSerializer;
+
+impl ser::Serializer for DynamicSerializer {
+    type Ok = Box<dyn Reflect>;
+    type Error = BridgeError;
+    type SerializeSeq = ListBuilder;
+    type SerializeTuple = TupleBuilder;
+    type SerializeTupleStruct = TupleStructBuilder;
+    type SerializeTupleVariant = TupleVariantBuilder;
+    type SerializeMap = MapBuilder;
+    type SerializeStruct = StructBuilder;
+    type SerializeStructVariant = StructVariantBuilder;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        let mut list = DynamicList::default();
+        for byte in v {
+            list.push(*byte);
+        }
+        Ok(Box::new(list))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(DynamicEnum::new("None", DynamicVariant::Unit)))
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        let mut tuple = DynamicTuple::default();
+        tuple.insert_boxed(value.serialize(DynamicSerializer)?);
+        Ok(Box::new(DynamicEnum::new("Some", tuple)))
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(()))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(DynamicEnum::new_with_index(
+            variant_index as usize,
+            variant,
+            DynamicVariant::Unit,
+        )))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        let mut tuple = DynamicTuple::default();
+        tuple.insert_boxed(value.serialize(DynamicSerializer)?);
+        Ok(Box::new(DynamicEnum::new_with_index(
+            variant_index as usize,
+            variant,
+            tuple,
+        )))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(ListBuilder(DynamicList::default()))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Ok(TupleBuilder(DynamicTuple::default()))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Ok(TupleStructBuilder(DynamicTupleStruct::default()))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(TupleVariantBuilder {
+            variant,
+            variant_index,
+            fields: DynamicTuple::default(),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(MapBuilder {
+            map: DynamicMap::default(),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(StructBuilder(DynamicStruct::default()))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(StructVariantBuilder {
+            variant,
+            variant_index,
+            fields: DynamicStruct::default(),
+        })
+    }
+}
+
+struct ListBuilder(DynamicList);
+
+impl SerializeSeq for ListBuilder {
+    type Ok = Box<dyn Reflect>;
+    type Error = BridgeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.push_box(value.serialize(DynamicSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(self.0))
+    }
+}
+
+struct TupleBuilder(DynamicTuple);
+
+impl SerializeTuple for TupleBuilder {
+    type Ok = Box<dyn Reflect>;
+    type Error = BridgeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.insert_boxed(value.serialize(DynamicSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(self.0))
+    }
+}
+
+struct TupleStructBuilder(DynamicTupleStruct);
+
+impl SerializeTupleStruct for TupleStructBuilder {
+    type Ok = Box<dyn Reflect>;
+    type Error = BridgeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.0.insert_boxed(value.serialize(DynamicSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(self.0))
+    }
+}
+
+struct TupleVariantBuilder {
+    variant: &'static str,
+    variant_index: u32,
+    fields: DynamicTuple,
+}
+
+impl SerializeTupleVariant for TupleVariantBuilder {
+    type Ok = Box<dyn Reflect>;
+    type Error = BridgeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.fields
+            .insert_boxed(value.serialize(DynamicSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(DynamicEnum::new_with_index(
+            self.variant_index as usize,
+            self.variant,
+            self.fields,
+        )))
+    }
+}
+
+struct MapBuilder {
+    map: DynamicMap,
+    key: Option<Box<dyn Reflect>>,
+}
+
+impl SerializeMap for MapBuilder {
+    type Ok = Box<dyn Reflect>;
+    type Error = BridgeError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        let key = key.serialize(DynamicSerializer)?;
+        if key.reflect_hash().is_none() {
+            return Err(BridgeError::UnhashableKey {
+                type_path: key.reflect_type_path().to_string(),
+            });
+        }
+        self.key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| BridgeError::Custom(String::from("map value without a key")))?;
+        self.map
+            .insert_boxed(key, value.serialize(DynamicSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(self.map))
+    }
+}
+
+struct StructBuilder(DynamicStruct);
+
+impl SerializeStruct for StructBuilder {
+    type Ok = Box<dyn Reflect>;
+    type Error = BridgeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0
+            .insert_boxed(key, value.serialize(DynamicSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(self.0))
+    }
+}
+
+struct StructVariantBuilder {
+    variant: &'static str,
+    variant_index: u32,
+    fields: DynamicStruct,
+}
+
+impl SerializeStructVariant for StructVariantBuilder {
+    type Ok = Box<dyn Reflect>;
+    type Error = BridgeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.fields
+            .insert_boxed(key, value.serialize(DynamicSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Box::new(DynamicEnum::new_with_index(
+            self.variant_index as usize,
+            self.variant,
+            self.fields,
+        )))
+    }
+}
+
+/// A self-describing [`Deserializer`] over dynamic reflection types.
+///
+/// The deserializer does not borrow from its input,
+/// so that values bridged on the fly can be deserialized as well.
+pub(crate) struct DynamicDeserializer<'a> {
+    value: &'a dyn Reflect,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> DynamicDeserializer<'a> {
+    pub(crate) fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
+        Self { value, registry }
+    }
+
+    /// Visits a value which has no structure of its own.
+    fn deserialize_value<'de, V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, BridgeError> {
+        macro_rules! visit_primitives {
+            ($($ty:ty => $visit:ident),* $(,)?) => {
+                $(
+                    if let Some(value) = self.value.downcast_ref::<$ty>() {
+                        return visitor.$visit(*value);
+                    }
+                )*
+            };
+        }
+
+        visit_primitives!(
+            bool => visit_bool,
+            i8 => visit_i8,
+            i16 => visit_i16,
+            i32 => visit_i32,
+            i64 => visit_i64,
+            i128 => visit_i128,
+            u8 => visit_u8,
+            u16 => visit_u16,
+            u32 => visit_u32,
+            u64 => visit_u64,
+            u128 => visit_u128,
+            f32 => visit_f32,
+            f64 => visit_f64,
+            char => visit_char,
+        );
+
+        if let Some(value) = self.value.downcast_ref::<String>() {
+            return visitor.visit_str(value);
+        }
+        if self.value.is::<()>() {
+            return visitor.visit_unit();
+        }
+
+        // Opaque values are bridged through their own serde representation.
+        let bridged = to_dynamic_via_serde(self.value, self.registry)?;
+        DynamicDeserializer::new(&*bridged, self.registry).deserialize_any(visitor)
+    }
+}
+
+impl<'a, 'de> Deserializer<'de> for DynamicDeserializer<'a> {
+    type Error = BridgeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let registry = self.registry;
+        match self.value.reflect_ref() {
+            ReflectRef::Struct(value) => visitor.visit_map(StructDeserializer {
+                fields: Box::new(
+                    (0..value.field_len())
+                        .filter_map(|index| Some((value.name_at(index)?, value.field_at(index)?))),
+                ),
+                pending: None,
+                registry,
+            }),
+            ReflectRef::TupleStruct(value) => visitor.visit_seq(SeqDeserializer {
+                items: Box::new(value.iter_fields()),
+                registry,
+            }),
+            ReflectRef::Tuple(value) => visitor.visit_seq(SeqDeserializer {
+                items: Box::new(value.iter_fields()),
+                registry,
+            }),
+            ReflectRef::List(value) => visitor.visit_seq(SeqDeserializer {
+                items: Box::new(value.iter()),
+                registry,
+            }),
+            ReflectRef::Array(value) => visitor.visit_seq(SeqDeserializer {
+                items: Box::new(value.iter()),
+                registry,
+            }),
+            ReflectRef::Map(value) => visitor.visit_map(MapDeserializer {
+                entries: Box::new(value.iter()),
+                pending: None,
+                registry,
+            }),
+            ReflectRef::Enum(value) => visitor.visit_enum(EnumDeserializer { value, registry }),
+            ReflectRef::Value(_) => self.deserialize_value(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value.reflect_ref() {
+            ReflectRef::Enum(value) if value.variant_name() == "None" => visitor.visit_none(),
+            ReflectRef::Enum(value) if value.variant_name() == "Some" && value.field_len() == 1 => {
+                visitor.visit_some(DynamicDeserializer::new(
+                    value.field_at(0).unwrap(),
+                    self.registry,
+                ))
+            }
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct SeqDeserializer<'a> {
+    items: Box<dyn Iterator<Item = &'a dyn Reflect> + 'a>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> SeqAccess<'de> for SeqDeserializer<'a> {
+    type Error = BridgeError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        self.items
+            .next()
+            .map(|item| seed.deserialize(DynamicDeserializer::new(item, self.registry)))
+            .transpose()
+    }
+}
+
+struct StructDeserializer<'a> {
+    fields: Box<dyn Iterator<Item = (&'a str, &'a dyn Reflect)> + 'a>,
+    pending: Option<&'a dyn Reflect>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> MapAccess<'de> for StructDeserializer<'a> {
+    type Error = BridgeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let Some((name, field)) = self.fields.next() else {
+            return Ok(None);
+        };
+        self.pending = Some(field);
+        seed.deserialize(name.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let field = self
+            .pending
+            .take()
+            .ok_or_else(|| BridgeError::Custom(String::from("struct field without a name")))?;
+        seed.deserialize(DynamicDeserializer::new(field, self.registry))
+    }
+}
+
+struct MapDeserializer<'a> {
+    entries: Box<dyn Iterator<Item = (&'a dyn Reflect, &'a dyn Reflect)> + 'a>,
+    pending: Option<&'a dyn Reflect>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> MapAccess<'de> for MapDeserializer<'a> {
+    type Error = BridgeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.pending = Some(value);
+        seed.deserialize(DynamicDeserializer::new(key, self.registry))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let value = self
+            .pending
+            .take()
+            .ok_or_else(|| BridgeError::Custom(String::from("map value without a key")))?;
+        seed.deserialize(DynamicDeserializer::new(value, self.registry))
+    }
+}
+
+struct EnumDeserializer<'a> {
+    value: &'a dyn Enum,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> EnumAccess<'de> for EnumDeserializer<'a> {
+    type Error = BridgeError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let variant = seed.deserialize(self.value.variant_name().into_deserializer())?;
+        Ok((variant, self))
+    }
+}
+
+impl<'a, 'de> VariantAccess<'de> for EnumDeserializer<'a> {
+    type Error = BridgeError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        match self.value.variant_type() {
+            VariantType::Unit => Ok(()),
+            _ => Err(de::Error::invalid_type(
+                de::Unexpected::Other("non-unit variant"),
+                &"unit variant",
+            )),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        match (self.value.variant_type(), self.value.field_len()) {
+            (VariantType::Tuple, 1) => seed.deserialize(DynamicDeserializer::new(
+                self.value.field_at(0).unwrap(),
+                self.registry,
+            )),
+            _ => Err(de::Error::invalid_type(
+                de::Unexpected::Other("non-newtype variant"),
+                &"newtype variant",
+            )),
+        }
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(SeqDeserializer {
+            items: Box::new(self.value.iter_fields().map(|field| field.value())),
+            registry: self.registry,
+        })
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(StructDeserializer {
+            fields: Box::new(
+                self.value
+                    .iter_fields()
+                    .map(|field| (field.name().unwrap_or_default(), field.value())),
+            ),
+            pending: None,
+            registry: self.registry,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::serde::{from_dynamic_via_serde, to_dynamic_via_serde, BridgeError};
+    use crate::{
+        self as bevy_reflect, DynamicMap, DynamicStruct, GetField, Reflect, ReflectDeserialize,
+        ReflectRef, ReflectSerialize, Struct, TypeRegistry,
+    };
+    use bevy_utils::HashMap;
+    use serde::{Deserialize, Serialize};
+    use std::any::TypeId;
+
+    #[derive(Reflect, Clone, Serialize, Deserialize, PartialEq, Debug)]
+    #[reflect_value(Serialize, Deserialize, PartialEq)]
+    struct Color {
+        name: String,
+        alpha: f32,
+    }
+
+    #[derive(Reflect, Clone, Serialize, Deserialize, PartialEq, Debug)]
+    #[reflect_value(Serialize, Deserialize, PartialEq)]
+    enum Shape {
+        Point,
+        Circle(f32),
+        Rect { width: f32, height: f32 },
+    }
+
+    #[derive(Reflect, Clone, Serialize, Deserialize, PartialEq, Debug)]
+    #[reflect_value(Serialize, Deserialize, PartialEq)]
+    struct Palette {
+        colors: Vec<Color>,
+        shapes: HashMap<String, Shape>,
+        highlight: Option<(u8, u8)>,
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Color>();
+        registry.register::<Shape>();
+        registry.register::<Palette>();
+        registry
+    }
+
+    #[test]
+    fn should_edit_value_type_through_dynamic_struct() {
+        let registry = registry();
+        let color = Color {
+            name: String::from("red"),
+            alpha: 1.0,
+        };
+
+        let mut dynamic = to_dynamic_via_serde(&color, &registry).unwrap();
+        let dynamic_struct = dynamic.downcast_mut::<DynamicStruct>().unwrap();
+        assert_eq!(2, dynamic_struct.field_len());
+        assert_eq!(Some("name"), dynamic_struct.name_at(0));
+        assert_eq!("red", dynamic_struct.get_field::<String>("name").unwrap());
+
+        *dynamic_struct.get_field_mut::<f32>("alpha").unwrap() = 0.25;
+
+        let registration = registry.get(TypeId::of::<Color>()).unwrap();
+        let updated = from_dynamic_via_serde(&*dynamic, registration, &registry).unwrap();
+        assert_eq!(
+            &Color {
+                name: String::from("red"),
+                alpha: 0.25,
+            },
+            updated.downcast_ref::<Color>().unwrap()
+        );
+    }
+
+    #[test]
+    fn should_round_trip_nested_representations() {
+        let registry = registry();
+        let mut shapes = HashMap::default();
+        shapes.insert(String::from("point"), Shape::Point);
+        shapes.insert(String::from("circle"), Shape::Circle(2.0));
+        shapes.insert(
+            String::from("rect"),
+            Shape::Rect {
+                width: 1.0,
+                height: 3.0,
+            },
+        );
+        let palette = Palette {
+            colors: vec![Color {
+                name: String::from("blue"),
+                alpha: 0.5,
+            }],
+            shapes,
+            highlight: Some((1, 2)),
+        };
+
+        let dynamic = to_dynamic_via_serde(&palette, &registry).unwrap();
+        let ReflectRef::Struct(fields) = dynamic.reflect_ref() else {
+            panic!("expected a struct");
+        };
+        let ReflectRef::Enum(highlight) = fields.field("highlight").unwrap().reflect_ref() else {
+            panic!("expected an enum");
+        };
+        assert_eq!("Some", highlight.variant_name());
+
+        let registration = registry.get(TypeId::of::<Palette>()).unwrap();
+        let output = from_dynamic_via_serde(&*dynamic, registration, &registry).unwrap();
+        assert_eq!(&palette, output.downcast_ref::<Palette>().unwrap());
+    }
+
+    #[test]
+    fn should_bridge_opaque_values_within_dynamic() {
+        let registry = registry();
+        let mut palette = DynamicStruct::default();
+        palette.insert(
+            "colors",
+            vec![Color {
+                name: String::from("green"),
+                alpha: 0.75,
+            }],
+        );
+        palette.insert("shapes", DynamicMap::default());
+        palette.insert("highlight", Option::<(u8, u8)>::None);
+
+        let registration = registry.get(TypeId::of::<Palette>()).unwrap();
+        let output = from_dynamic_via_serde(&palette, registration, &registry).unwrap();
+        assert_eq!(
+            &Palette {
+                colors: vec![Color {
+                    name: String::from("green"),
+                    alpha: 0.75,
+                }],
+                shapes: HashMap::default(),
+                highlight: None,
+            },
+            output.downcast_ref::<Palette>().unwrap()
+        );
+    }
+
+    #[test]
+    fn should_fail_for_unrepresentable_values() {
+        #[derive(Reflect, Clone)]
+        #[reflect_value]
+        struct Opaque;
+
+        #[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+        struct Key {
+            id: u32,
+        }
+
+        #[derive(Reflect, Clone, Serialize, Deserialize)]
+        #[reflect_value(Serialize, Deserialize)]
+        struct KeyedByStruct(HashMap<Key, u8>);
+
+        let mut registry = registry();
+        registry.register::<Opaque>();
+        registry.register::<KeyedByStruct>();
+
+        let result = to_dynamic_via_serde(&Opaque, &registry);
+        assert!(matches!(
+            result,
+            Err(BridgeError::MissingReflectSerialize { .. })
+        ));
+
+        let mut keyed = HashMap::default();
+        keyed.insert(Key { id: 1 }, 1);
+        let result = to_dynamic_via_serde(&KeyedByStruct(keyed), &registry);
+        assert!(matches!(result, Err(BridgeError::UnhashableKey { .. })));
+
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("name", Opaque);
+        dynamic.insert("alpha", 1.0_f32);
+        let registration = registry.get(TypeId::of::<Color>()).unwrap();
+        let result = from_dynamic_via_serde(&dynamic, registration, &registry);
+        assert!(matches!(
+            result,
+            Err(BridgeError::Custom(message)) if message.contains("ReflectSerialize")
+        ));
+
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("name", 1.0_f32);
+        let result = from_dynamic_via_serde(&dynamic, registration, &registry);
+        assert!(matches!(result, Err(BridgeError::Custom(_))));
+    }
+}
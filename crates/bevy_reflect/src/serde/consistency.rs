@@ -0,0 +1,277 @@
+use crate::serde::SerializationData;
+use crate::{ReflectDeserialize, ReflectSerialize, TypeInfo, TypeRegistration, TypeRegistry};
+use thiserror::Error;
+
+/// A latent inconsistency in how a type is (de)serialized through reflection.
+///
+/// These are returned by [`verify_serialization_consistency`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SerializationWarning {
+    #[error("`{type_path}` registers `ReflectSerialize`, so its skipped fields {skipped_fields:?} are not skipped when serializing")]
+    /// The type registers both [`ReflectSerialize`] and a non-empty [`SerializationData`].
+    ///
+    /// The serde implementation always takes precedence when serializing,
+    /// so the fields marked `#[reflect(skip_serializing)]` will still be serialized
+    /// unless the serde implementation skips them as well.
+    SkippedFieldsOverridden {
+        type_path: &'static str,
+        /// The names of the skipped fields (or their indices for tuple structs).
+        skipped_fields: Vec<String>,
+    },
+
+    #[error("`{type_path}` registers `ReflectSerialize` but not `ReflectDeserialize`")]
+    /// The type is serialized with its serde implementation but deserialized structurally.
+    MissingReflectDeserialize { type_path: &'static str },
+
+    #[error("`{type_path}` registers `ReflectDeserialize` but not `ReflectSerialize`")]
+    /// The type is serialized structurally but deserialized with its serde implementation.
+    MissingReflectSerialize { type_path: &'static str },
+
+    #[error("field `{field}` of `{type_path}` has type `{field_type}`, which is not registered")]
+    /// A field which is serialized structurally has a type missing from the registry,
+    /// so it cannot be deserialized.
+    UnregisteredField {
+        type_path: &'static str,
+        /// The name of the field (or its index for tuple structs).
+        field: String,
+        field_type: &'static str,
+    },
+}
+
+/// Checks the given registration for latent inconsistencies between how it is serialized
+/// and how it is deserialized through reflection.
+///
+/// # Precedence
+///
+/// When a type registers [`ReflectSerialize`], its serde implementation is always used to serialize it,
+/// and its [`SerializationData`] is not consulted.
+/// Likewise, a type registering [`ReflectDeserialize`] is deserialized with its serde implementation,
+/// unless [`TypedReflectDeserializer::prefer_structural`] is used to accept both shapes.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::TypeRegistry;
+/// # use bevy_reflect::serde::{verify_serialization_consistency, SerializationWarning};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Reflect, Serialize, Deserialize)]
+/// #[reflect(Serialize, Deserialize)]
+/// struct Foo {
+///     a: u32,
+///     #[reflect(skip_serializing)]
+///     b: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Foo>();
+///
+/// let registration = registry.get(std::any::TypeId::of::<Foo>()).unwrap();
+/// let warnings = verify_serialization_consistency(registration, &registry);
+/// assert!(matches!(
+///     warnings.as_slice(),
+///     [SerializationWarning::SkippedFieldsOverridden { .. }]
+/// ));
+/// ```
+///
+/// [`TypedReflectDeserializer::prefer_structural`]: crate::serde::TypedReflectDeserializer::prefer_structural
+pub fn verify_serialization_consistency(
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+) -> Vec<SerializationWarning> {
+    let type_info = registration.type_info();
+    let type_path = type_info.type_path();
+    let has_serialize = registration.data::<ReflectSerialize>().is_some();
+    let has_deserialize = registration.data::<ReflectDeserialize>().is_some();
+    let serialization_data = registration.data::<SerializationData>();
+
+    let mut warnings = Vec::new();
+
+    match (has_serialize, has_deserialize) {
+        (true, false) => {
+            warnings.push(SerializationWarning::MissingReflectDeserialize { type_path });
+        }
+        (false, true) => {
+            warnings.push(SerializationWarning::MissingReflectSerialize { type_path });
+        }
+        _ => {}
+    }
+
+    let field_name = |index: usize| match type_info {
+        TypeInfo::Struct(info) => info.field_at(index).map(|field| field.name().to_string()),
+        _ => Some(index.to_string()),
+    };
+
+    if has_serialize {
+        if let Some(serialization_data) = serialization_data.filter(|data| !data.is_empty()) {
+            let mut skipped = serialization_data
+                .iter_skipped()
+                .map(|(index, _)| *index)
+                .collect::<Vec<_>>();
+            skipped.sort_unstable();
+            warnings.push(SerializationWarning::SkippedFieldsOverridden {
+                type_path,
+                skipped_fields: skipped.into_iter().filter_map(field_name).collect(),
+            });
+        }
+        return warnings;
+    }
+
+    let fields = match type_info {
+        TypeInfo::Struct(info) => info
+            .iter()
+            .enumerate()
+            .map(|(index, field)| (index, field.type_id(), field.type_path()))
+            .collect::<Vec<_>>(),
+        TypeInfo::TupleStruct(info) => info
+            .iter()
+            .enumerate()
+            .map(|(index, field)| (index, field.type_id(), field.type_path()))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    for (index, type_id, field_type) in fields {
+        let is_skipped = serialization_data.is_some_and(|data| data.is_field_skipped(index));
+        if !is_skipped && registry.get(type_id).is_none() {
+            warnings.push(SerializationWarning::UnregisteredField {
+                type_path,
+                field: field_name(index).unwrap_or_default(),
+                field_type,
+            });
+        }
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::{ReflectSerializer, TypedReflectDeserializer};
+    use crate::{FromReflect, GetTypeRegistration, Reflect, TypePath};
+    use bincode::Options;
+    use serde::de::DeserializeSeed;
+    use serde::{Deserialize, Serialize};
+    use std::any::TypeId;
+
+    #[derive(Reflect, Serialize, Deserialize, Debug, PartialEq)]
+    #[reflect(Serialize, Deserialize)]
+    struct Conflicting {
+        a: u32,
+        #[reflect(skip_serializing)]
+        b: u32,
+    }
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Conflicting>();
+        registry
+    }
+
+    #[test]
+    fn should_warn_on_conflicting_registration() {
+        let registry = get_registry();
+        let registration = registry.get(TypeId::of::<Conflicting>()).unwrap();
+
+        let warnings = verify_serialization_consistency(registration, &registry);
+        assert_eq!(
+            vec![SerializationWarning::SkippedFieldsOverridden {
+                type_path: Conflicting::type_path(),
+                skipped_fields: vec![String::from("b")],
+            }],
+            warnings
+        );
+    }
+
+    #[test]
+    fn should_warn_on_asymmetric_registration() {
+        #[derive(Reflect, Serialize)]
+        #[reflect(Serialize)]
+        struct SerializeOnly(u32);
+
+        #[derive(Reflect)]
+        struct Unregistered(u32);
+
+        #[derive(Reflect)]
+        struct Structural {
+            a: u32,
+            b: Unregistered,
+            #[reflect(skip_serializing)]
+            c: Option<Unregistered>,
+        }
+
+        // Add the registrations directly to avoid registering their dependencies
+        let mut registry = TypeRegistry::empty();
+        registry.add_registration(SerializeOnly::get_type_registration());
+        registry.add_registration(Structural::get_type_registration());
+        registry.register::<u32>();
+
+        let registration = registry.get(TypeId::of::<SerializeOnly>()).unwrap();
+        assert_eq!(
+            vec![SerializationWarning::MissingReflectDeserialize {
+                type_path: SerializeOnly::type_path(),
+            }],
+            verify_serialization_consistency(registration, &registry)
+        );
+
+        let registration = registry.get(TypeId::of::<Structural>()).unwrap();
+        assert_eq!(
+            vec![SerializationWarning::UnregisteredField {
+                type_path: Structural::type_path(),
+                field: String::from("b"),
+                field_type: Unregistered::type_path(),
+            }],
+            verify_serialization_consistency(registration, &registry)
+        );
+    }
+
+    #[test]
+    fn should_use_serde_implementation_by_default() {
+        let registry = get_registry();
+        let registration = registry.get(TypeId::of::<Conflicting>()).unwrap();
+
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str("(a:1,b:2)").unwrap();
+        let output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+        assert_eq!(
+            Some(&Conflicting { a: 1, b: 2 }),
+            output.downcast_ref::<Conflicting>()
+        );
+    }
+
+    #[test]
+    fn should_deserialize_both_shapes() {
+        let registry = get_registry();
+        let registration = registry.get(TypeId::of::<Conflicting>()).unwrap();
+        let expected = Conflicting { a: 1, b: 0 };
+
+        // Shape written by the serde implementation
+        let serde_shape = ron::ser::to_string(&Conflicting { a: 1, b: 2 }).unwrap();
+        // Shape written by the structural serializer
+        let structural_shape = "(a:1)";
+
+        for input in [serde_shape.as_str(), structural_shape] {
+            let reflect_deserializer =
+                TypedReflectDeserializer::new(registration, &registry).prefer_structural();
+            let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+            let output = reflect_deserializer
+                .deserialize(&mut ron_deserializer)
+                .unwrap();
+            assert_eq!(expected, Conflicting::from_reflect(&*output).unwrap());
+        }
+
+        // Non-human-readable formats continue to use the serde implementation
+        let input = Conflicting { a: 1, b: 2 };
+        let bytes = bincode::serialize(&ReflectSerializer::new(&input, &registry)).unwrap();
+        let reflect_deserializer = crate::serde::ReflectDeserializer::new(&registry);
+        let output = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(reflect_deserializer, &bytes)
+            .unwrap();
+        assert_eq!(input, Conflicting::from_reflect(&*output).unwrap());
+    }
+}
@@ -1,9 +1,11 @@
-use crate::serde::SerializationData;
+use crate::migrate::migrate_untyped;
+use crate::serde::{BridgeError, DynamicDeserializer, RedactionData, SerializationData};
 use crate::{
-    ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
-    DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField, Reflect,
-    ReflectDeserialize, StructInfo, StructVariantInfo, TupleInfo, TupleStructInfo,
-    TupleVariantInfo, TypeInfo, TypeRegistration, TypeRegistry, VariantInfo,
+    migrate_value, ArrayInfo, DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct,
+    DynamicTuple, DynamicTupleStruct, DynamicVariant, EnumInfo, ListInfo, Map, MapInfo, NamedField,
+    Reflect, ReflectDeserialize, ReflectLock, ReflectMigrate, ReflectRef, ReflectTransparent,
+    Struct, StructInfo, StructVariantInfo, TupleInfo, TupleStructInfo, TupleVariantInfo, TypeInfo,
+    TypeRegistration, TypeRegistry, VariantInfo, VariantType,
 };
 use erased_serde::Deserializer;
 use serde::de::{
@@ -244,7 +246,8 @@ impl<'de> Deserialize<'de> for Ident {
 ///
 /// This will return a [`&TypeRegistration`] corresponding to the given type.
 /// This deserializer expects a string containing the _full_ [type path] of the
-/// type to find the `TypeRegistration` of.
+/// type to find the `TypeRegistration` of,
+/// or any other name accepted by [`TypeRegistry::get_with_name`].
 ///
 /// [`&TypeRegistration`]: TypeRegistration
 /// [type path]: crate::TypePath::type_path
@@ -274,12 +277,12 @@ impl<'a, 'de> DeserializeSeed<'de> for TypeRegistrationDeserializer<'a> {
                 formatter.write_str("string containing `type` entry for the reflected value")
             }
 
-            fn visit_str<E>(self, type_path: &str) -> Result<Self::Value, E>
+            fn visit_str<E>(self, type_name: &str) -> Result<Self::Value, E>
             where
                 E: Error,
             {
-                self.0.get_with_type_path(type_path).ok_or_else(|| {
-                    Error::custom(format_args!("No registration found for `{type_path}`"))
+                self.0.get_with_name(type_name).ok_or_else(|| {
+                    Error::custom(format_args!("No registration found for `{type_name}`"))
                 })
             }
         }
@@ -300,6 +303,9 @@ impl<'a, 'de> DeserializeSeed<'de> for TypeRegistrationDeserializer<'a> {
 /// where the key is the _full_ [type path] of the reflected type
 /// and the value is the serialized data.
 ///
+/// The key may also be any other name accepted by [`TypeRegistry::get_with_name`],
+/// that is, a registered [alias].
+///
 /// # Output
 ///
 /// This deserializer will return a [`Box<dyn Reflect>`] containing the deserialized data.
@@ -360,6 +366,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypeRegistrationDeserializer<'a> {
 ///
 /// [`ReflectSerializer`]: crate::serde::ReflectSerializer
 /// [type path]: crate::TypePath::type_path
+/// [alias]: crate::TypeRegistry::register_alias
 /// [`Box<dyn Reflect>`]: crate::Reflect
 /// [`ReflectKind::Value`]: crate::ReflectKind::Value
 /// [`ReflectDeserialize`]: crate::ReflectDeserialize
@@ -407,6 +414,8 @@ impl<'a, 'de> DeserializeSeed<'de> for ReflectDeserializer<'a> {
                 let value = map.next_value_seed(TypedReflectDeserializer {
                     registration,
                     registry: self.registry,
+                    version: None,
+                    prefer_structural: false,
                 })?;
 
                 if map.next_key::<IgnoredAny>()?.is_some() {
@@ -502,6 +511,8 @@ impl<'a, 'de> DeserializeSeed<'de> for ReflectDeserializer<'a> {
 pub struct TypedReflectDeserializer<'a> {
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    version: Option<u32>,
+    prefer_structural: bool,
 }
 
 impl<'a> TypedReflectDeserializer<'a> {
@@ -509,8 +520,44 @@ impl<'a> TypedReflectDeserializer<'a> {
         Self {
             registration,
             registry,
+            version: None,
+            prefer_structural: false,
         }
     }
+
+    /// Deserializes the value structurally, even if it registers [`ReflectDeserialize`],
+    /// when it is a named struct with skipped fields and the format is human-readable.
+    ///
+    /// In human-readable formats the structural shape is a subset of the serde shape,
+    /// so this accepts data written either way (see [`verify_serialization_consistency`]).
+    /// Skipped fields are always generated from their defaults.
+    ///
+    /// This only applies to the value itself, not to any of its fields.
+    ///
+    /// [`ReflectDeserialize`]: crate::ReflectDeserialize
+    /// [`verify_serialization_consistency`]: crate::serde::verify_serialization_consistency
+    pub fn prefer_structural(mut self) -> Self {
+        self.prefer_structural = true;
+        self
+    }
+
+    /// Sets the version the data was recorded against, such as one stored in the document wrapper.
+    ///
+    /// The deserialized value is then [migrated] from this version to the current version
+    /// of the type and converted into the concrete type.
+    ///
+    /// Data recorded against an older version is deserialized without using the current type,
+    /// so that fields which have since been renamed, removed, or changed can still be read.
+    /// The migration steps receive it in the untyped form described by [`ReflectMigrate`],
+    /// just as they do when calling [`migrate_value`] directly.
+    /// This requires a self-describing format, such as RON or JSON.
+    ///
+    /// [migrated]: crate::migrate_value
+    /// [`migrate_value`]: crate::migrate_value
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.version = Some(version);
+        self
+    }
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
@@ -520,16 +567,60 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
     where
         D: serde::Deserializer<'de>,
     {
+        if let Some(version) = self.version {
+            let is_outdated = self
+                .registration
+                .data::<ReflectMigrate>()
+                .is_some_and(|migrate| migrate.current_version() != version);
+
+            if !is_outdated {
+                let value = TypedReflectDeserializer::new(self.registration, self.registry)
+                    .deserialize(deserializer)?;
+                return migrate_value(value, version, self.registration, self.registry)
+                    .map_err(Error::custom);
+            }
+
+            let value = UntypedValueDeserializer.deserialize(deserializer)?;
+            return migrate_untyped(value, version, self.registration, self.registry)
+                .map_err(Error::custom);
+        }
+
         let type_path = self.registration.type_info().type_path();
 
+        // Unlike when serializing, a container's length is only known once it has been read,
+        // so it is recorded by the container arms below
+        #[cfg(feature = "trace")]
+        let span = bevy_utils::tracing::info_span!(
+            "reflect_deserialize",
+            type_path,
+            len = bevy_utils::tracing::field::Empty,
+        )
+        .entered();
+
+        let prefer_structural = self.prefer_structural
+            && deserializer.is_human_readable()
+            && matches!(self.registration.type_info(), TypeInfo::Struct(_))
+            && self
+                .registration
+                .data::<SerializationData>()
+                .is_some_and(|data| !data.is_empty());
+
         // Handle both Value case and types that have a custom `ReflectDeserialize`
-        if let Some(deserialize_reflect) = self.registration.data::<ReflectDeserialize>() {
+        if let Some(deserialize_reflect) = self
+            .registration
+            .data::<ReflectDeserialize>()
+            .filter(|_| !prefer_structural)
+        {
             let value = deserialize_reflect.deserialize(deserializer)?;
             return Ok(value);
         }
 
         match self.registration.type_info() {
             TypeInfo::Struct(struct_info) => {
+                // Like the serializer, only positional formats use the stable order
+                let stable_order = struct_info
+                    .stable_order()
+                    .filter(|_| !deserializer.is_human_readable());
                 let mut dynamic_struct = deserializer.deserialize_struct(
                     struct_info.type_path_table().ident().unwrap(),
                     struct_info.field_names(),
@@ -537,6 +628,7 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                         struct_info,
                         registration: self.registration,
                         registry: self.registry,
+                        stable_order,
                     },
                 )?;
                 dynamic_struct.set_represented_type(Some(self.registration.type_info()));
@@ -556,11 +648,20 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                 Ok(Box::new(dynamic_tuple_struct))
             }
             TypeInfo::List(list_info) => {
-                let mut dynamic_list = deserializer.deserialize_seq(ListVisitor {
+                let visitor = ListVisitor {
                     list_info,
                     registry: self.registry,
-                })?;
+                };
+                // Lists of bytes may have been serialized as a single contiguous value
+                let mut dynamic_list =
+                    if !list_info.has_dynamic_items() && list_info.item_is::<u8>() {
+                        deserializer.deserialize_byte_buf(visitor)?
+                    } else {
+                        deserializer.deserialize_seq(visitor)?
+                    };
                 dynamic_list.set_represented_type(Some(self.registration.type_info()));
+                #[cfg(feature = "trace")]
+                span.record("len", crate::List::len(&dynamic_list));
                 Ok(Box::new(dynamic_list))
             }
             TypeInfo::Array(array_info) => {
@@ -572,6 +673,8 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                     },
                 )?;
                 dynamic_array.set_represented_type(Some(self.registration.type_info()));
+                #[cfg(feature = "trace")]
+                span.record("len", crate::Array::len(&dynamic_array));
                 Ok(Box::new(dynamic_array))
             }
             TypeInfo::Map(map_info) => {
@@ -580,6 +683,8 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                     registry: self.registry,
                 })?;
                 dynamic_map.set_represented_type(Some(self.registration.type_info()));
+                #[cfg(feature = "trace")]
+                span.record("len", Map::len(&dynamic_map));
                 Ok(Box::new(dynamic_map))
             }
             TypeInfo::Tuple(tuple_info) => {
@@ -617,7 +722,45 @@ impl<'a, 'de> DeserializeSeed<'de> for TypedReflectDeserializer<'a> {
                 dynamic_enum.set_represented_type(Some(self.registration.type_info()));
                 Ok(Box::new(dynamic_enum))
             }
-            TypeInfo::Value(_) => {
+            TypeInfo::Value(value_info) => {
+                // Locks are deserialized as their guarded value
+                if let Some(lock) = self.registration.data::<ReflectLock>() {
+                    let registration = get_registration(
+                        lock.inner_type_id(),
+                        lock.inner_type_path(),
+                        self.registry,
+                    )?;
+                    let inner = TypedReflectDeserializer::new(registration, self.registry)
+                        .deserialize(deserializer)?;
+                    return lock.from_inner(inner.as_ref()).ok_or_else(|| {
+                        Error::custom(format_args!(
+                            "failed to create `{type_path}` from its inner value",
+                        ))
+                    });
+                }
+
+                // Transparent values are deserialized as their inner value
+                if let Some(transparent) = self.registration.data::<ReflectTransparent>() {
+                    let registration = get_registration(
+                        transparent.inner_type_id(),
+                        transparent.inner_type_path(),
+                        self.registry,
+                    )?;
+                    let inner = TypedReflectDeserializer::new(registration, self.registry)
+                        .deserialize(deserializer)?;
+                    return transparent.from_inner(inner.as_ref()).ok_or_else(|| {
+                        Error::custom(format_args!(
+                            "failed to create `{type_path}` from its inner value",
+                        ))
+                    });
+                }
+
+                if value_info.is_opaque() {
+                    return Err(Error::custom(format_args!(
+                        "cannot deserialize opaque value `{type_path}`: register `ReflectDeserialize` for it in order to deserialize it",
+                    )));
+                }
+
                 // This case should already be handled
                 Err(Error::custom(format_args!(
                     "the TypeRegistration for {type_path} doesn't have ReflectDeserialize",
@@ -631,6 +774,8 @@ struct StructVisitor<'a> {
     struct_info: &'static StructInfo,
     registration: &'a TypeRegistration,
     registry: &'a TypeRegistry,
+    /// The order in which fields are expected when deserialized as a sequence, if not declaration order.
+    stable_order: Option<&'static [usize]>,
 }
 
 impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
@@ -644,7 +789,13 @@ impl<'a, 'de> Visitor<'de> for StructVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
-        visit_struct_seq(&mut seq, self.struct_info, self.registration, self.registry)
+        visit_struct_seq(
+            &mut seq,
+            self.struct_info,
+            self.registration,
+            self.registry,
+            self.stable_order,
+        )
     }
 
     fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
@@ -728,6 +879,8 @@ impl<'a, 'de> Visitor<'de> for ArrayVisitor<'a> {
         while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
             registration,
             registry: self.registry,
+            version: None,
+            prefer_structural: false,
         })? {
             vec.push(value);
         }
@@ -760,6 +913,16 @@ impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
         V: SeqAccess<'de>,
     {
         let mut list = DynamicList::default();
+
+        if self.list_info.has_dynamic_items() {
+            while let Some(value) =
+                seq.next_element_seed(ReflectDeserializer::new(self.registry))?
+            {
+                list.push_box(value);
+            }
+            return Ok(list);
+        }
+
         let registration = get_registration(
             self.list_info.item_type_id(),
             self.list_info.item_type_path_table().path(),
@@ -768,11 +931,24 @@ impl<'a, 'de> Visitor<'de> for ListVisitor<'a> {
         while let Some(value) = seq.next_element_seed(TypedReflectDeserializer {
             registration,
             registry: self.registry,
+            version: None,
+            prefer_structural: false,
         })? {
             list.push_box(value);
         }
         Ok(list)
     }
+
+    fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let mut list = DynamicList::default();
+        for byte in bytes {
+            list.push(*byte);
+        }
+        Ok(list)
+    }
 }
 
 struct MapVisitor<'a> {
@@ -805,10 +981,14 @@ impl<'a, 'de> Visitor<'de> for MapVisitor<'a> {
         while let Some(key) = map.next_key_seed(TypedReflectDeserializer {
             registration: key_registration,
             registry: self.registry,
+            version: None,
+            prefer_structural: false,
         })? {
             let value = map.next_value_seed(TypedReflectDeserializer {
                 registration: value_registration,
                 registry: self.registry,
+                version: None,
+                prefer_structural: false,
             })?;
             dynamic_map.insert_boxed(key, value);
         }
@@ -856,6 +1036,8 @@ impl<'a, 'de> Visitor<'de> for EnumVisitor<'a> {
                 let value = variant.newtype_variant_seed(TypedReflectDeserializer {
                     registration,
                     registry: self.registry,
+                    version: None,
+                    prefer_structural: false,
                 })?;
                 let mut dynamic_tuple = DynamicTuple::default();
                 dynamic_tuple.insert_boxed(value);
@@ -951,7 +1133,13 @@ impl<'a, 'de> Visitor<'de> for StructVariantVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
-        visit_struct_seq(&mut seq, self.struct_info, self.registration, self.registry)
+        visit_struct_seq(
+            &mut seq,
+            self.struct_info,
+            self.registration,
+            self.registry,
+            None,
+        )
     }
 
     fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
@@ -1018,6 +1206,8 @@ impl<'a, 'de> Visitor<'de> for OptionVisitor<'a> {
                 let de = TypedReflectDeserializer {
                     registration,
                     registry: self.registry,
+                    version: None,
+                    prefer_structural: false,
                 };
                 let mut value = DynamicTuple::default();
                 value.insert_boxed(de.deserialize(deserializer)?);
@@ -1044,6 +1234,7 @@ where
     V: MapAccess<'de>,
 {
     let mut dynamic_struct = DynamicStruct::default();
+    let redaction_data = registration.data::<RedactionData>();
     while let Some(Ident(key)) = map.next_key::<Ident>()? {
         let field = info.get_field(&key).ok_or_else(|| {
             let fields = info.iter_fields().map(|field| field.name());
@@ -1053,10 +1244,17 @@ where
                 ExpectedValues(fields.collect())
             ))
         })?;
+        if field.is_redacted() && redaction_data.is_some() {
+            // Whatever was written for a redacted field is discarded in favor of its default
+            map.next_value::<IgnoredAny>()?;
+            continue;
+        }
         let registration = get_registration(field.type_id(), field.type_path(), registry)?;
         let value = map.next_value_seed(TypedReflectDeserializer {
             registration,
             registry,
+            version: None,
+            prefer_structural: false,
         })?;
         dynamic_struct.insert_boxed(&key, value);
     }
@@ -1070,6 +1268,15 @@ where
         }
     }
 
+    if let Some(redaction_data) = redaction_data {
+        for (redacted_index, redacted_field) in redaction_data.iter_redacted() {
+            let Some(field) = info.field_at(*redacted_index) else {
+                continue;
+            };
+            dynamic_struct.insert_boxed(field.name(), redacted_field.generate_default());
+        }
+    }
+
     Ok(dynamic_struct)
 }
 
@@ -1093,6 +1300,7 @@ where
     }
 
     let serialization_data = registration.data::<SerializationData>();
+    let redaction_data = registration.data::<RedactionData>();
 
     for index in 0..len {
         if let Some(value) = serialization_data.and_then(|data| data.generate_default(index)) {
@@ -1100,10 +1308,19 @@ where
             continue;
         }
 
+        if let Some(value) = redaction_data.and_then(|data| data.generate_default(index)) {
+            // Redacted fields are written positionally as a placeholder string
+            seq.next_element::<String>()?;
+            tuple.insert_boxed(value);
+            continue;
+        }
+
         let value = seq
             .next_element_seed(TypedReflectDeserializer {
                 registration: info.get_field_registration(index, registry)?,
                 registry,
+                version: None,
+                prefer_structural: false,
             })?
             .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
         tuple.insert_boxed(value);
@@ -1117,6 +1334,7 @@ fn visit_struct_seq<'de, T, V>(
     info: &T,
     registration: &TypeRegistration,
     registry: &TypeRegistry,
+    stable_order: Option<&[usize]>,
 ) -> Result<DynamicStruct, V::Error>
 where
     T: StructLikeInfo + Container,
@@ -1132,17 +1350,27 @@ where
     }
 
     let serialization_data = registration.data::<SerializationData>();
+    let redaction_data = registration.data::<RedactionData>();
 
-    for index in 0..len {
-        let name = info.field_at(index).unwrap().name();
+    // Fields may be serialized in their stable order,
+    // so collect them first in order to insert them in declaration order
+    let mut values: Vec<Option<Box<dyn Reflect>>> = (0..len).map(|_| None).collect();
+
+    for position in 0..len {
+        let index = stable_order.map_or(position, |order| order[position]);
 
         if serialization_data
             .map(|data| data.is_field_skipped(index))
             .unwrap_or_default()
         {
-            if let Some(value) = serialization_data.unwrap().generate_default(index) {
-                dynamic_struct.insert_boxed(name, value);
-            }
+            values[index] = serialization_data.unwrap().generate_default(index);
+            continue;
+        }
+
+        if let Some(value) = redaction_data.and_then(|data| data.generate_default(index)) {
+            // Redacted fields are written positionally as a placeholder string
+            seq.next_element::<String>()?;
+            values[index] = Some(value);
             continue;
         }
 
@@ -1150,22 +1378,235 @@ where
             .next_element_seed(TypedReflectDeserializer {
                 registration: info.get_field_registration(index, registry)?,
                 registry,
+                version: None,
+                prefer_structural: false,
             })?
-            .ok_or_else(|| Error::invalid_length(index, &len.to_string().as_str()))?;
-        dynamic_struct.insert_boxed(name, value);
+            .ok_or_else(|| Error::invalid_length(position, &len.to_string().as_str()))?;
+        values[index] = Some(value);
+    }
+
+    for (index, value) in values.into_iter().enumerate() {
+        if let Some(value) = value {
+            dynamic_struct.insert_boxed(info.field_at(index).unwrap().name(), value);
+        }
     }
 
     Ok(dynamic_struct)
 }
 
+/// Deserializes a value without knowing its type, for data recorded against an older version.
+///
+/// This produces the untyped form described by [`ReflectMigrate`].
+///
+/// This relies on [`serde::Deserializer::deserialize_any`], so it only supports self-describing formats.
+pub(crate) struct UntypedValueDeserializer;
+
+impl<'de> DeserializeSeed<'de> for UntypedValueDeserializer {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for UntypedValueDeserializer {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("any self-describing value")
+    }
+
+    fn visit_bool<E: Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        // Self-describing formats usually only know the sign of an integer,
+        // so non-negative integers are always treated as unsigned
+        match u64::try_from(v) {
+            Ok(v) => Ok(Box::new(v)),
+            Err(_) => Ok(Box::new(v)),
+        }
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Box::new(v.to_string()))
+    }
+
+    fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(Box::new(v))
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        let mut list = DynamicList::default();
+        for byte in v {
+            list.push(*byte);
+        }
+        Ok(Box::new(list))
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Box::new(DynamicTuple::default()))
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+        Ok(Box::new(DynamicEnum::new("None", ())))
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let mut tuple = DynamicTuple::default();
+        tuple.insert_boxed(self.deserialize(deserializer)?);
+        Ok(Box::new(DynamicEnum::new("Some", tuple)))
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.deserialize(deserializer)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut list = DynamicList::default();
+        while let Some(value) = seq.next_element_seed(UntypedValueDeserializer)? {
+            list.push_box(value);
+        }
+        Ok(Box::new(list))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut dynamic_struct = DynamicStruct::default();
+        let mut dynamic_map = DynamicMap::default();
+        while let Some(key) = map.next_key_seed(UntypedValueDeserializer)? {
+            let value = map.next_value_seed(UntypedValueDeserializer)?;
+            match key.downcast::<String>() {
+                Ok(name) if dynamic_map.is_empty() => dynamic_struct.insert_boxed(*name, value),
+                Ok(name) => {
+                    dynamic_map.insert_boxed(name, value);
+                }
+                Err(_) if dynamic_struct.field_len() > 0 => {
+                    return Err(Error::custom("cannot mix string and non-string map keys"));
+                }
+                Err(key) => {
+                    dynamic_map.insert_boxed(key, value);
+                }
+            }
+        }
+
+        if dynamic_map.is_empty() {
+            Ok(Box::new(dynamic_struct))
+        } else {
+            Ok(Box::new(dynamic_map))
+        }
+    }
+}
+
+/// Converts a reflected value into the untyped form produced by [`UntypedValueDeserializer`].
+///
+/// Tuples, tuple structs, and arrays become [`DynamicList`]s, and maps with `String` keys
+/// become [`DynamicStruct`]s, just as they would when deserialized without their type.
+/// Enums other than `Option` become [`DynamicEnum`]s with untyped fields.
+/// Other values are passed through their serde representation.
+pub(crate) fn to_untyped(
+    value: &dyn Reflect,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn Reflect>, BridgeError> {
+    let list = |items: &mut dyn Iterator<Item = &dyn Reflect>| {
+        let mut list = DynamicList::default();
+        for item in items {
+            list.push_box(to_untyped(item, registry)?);
+        }
+        Ok::<_, BridgeError>(Box::new(list) as Box<dyn Reflect>)
+    };
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            let mut dynamic_struct = DynamicStruct::default();
+            for (index, field) in value.iter_fields().enumerate() {
+                let name = value.name_at(index).unwrap();
+                dynamic_struct.insert_boxed(name, to_untyped(field, registry)?);
+            }
+            Ok(Box::new(dynamic_struct))
+        }
+        ReflectRef::TupleStruct(value) => list(&mut value.iter_fields()),
+        ReflectRef::Tuple(value) => list(&mut value.iter_fields()),
+        ReflectRef::List(value) => list(&mut value.iter()),
+        ReflectRef::Array(value) => list(&mut value.iter()),
+        ReflectRef::Map(value) => {
+            let entries = value
+                .iter()
+                .map(|(key, value)| Ok((to_untyped(key, registry)?, to_untyped(value, registry)?)))
+                .collect::<Result<Vec<_>, BridgeError>>()?;
+
+            if entries.iter().all(|(key, _)| key.is::<String>()) {
+                let mut dynamic_struct = DynamicStruct::default();
+                for (key, value) in entries {
+                    dynamic_struct.insert_boxed(key.take::<String>().unwrap(), value);
+                }
+                Ok(Box::new(dynamic_struct))
+            } else {
+                let mut dynamic_map = DynamicMap::default();
+                for (key, value) in entries {
+                    dynamic_map.insert_boxed(key, value);
+                }
+                Ok(Box::new(dynamic_map))
+            }
+        }
+        ReflectRef::Enum(value) => {
+            let variant = match value.variant_type() {
+                VariantType::Unit => DynamicVariant::Unit,
+                VariantType::Tuple => {
+                    let mut tuple = DynamicTuple::default();
+                    for field in value.iter_fields() {
+                        tuple.insert_boxed(to_untyped(field.value(), registry)?);
+                    }
+                    DynamicVariant::Tuple(tuple)
+                }
+                VariantType::Struct => {
+                    let mut dynamic_struct = DynamicStruct::default();
+                    for field in value.iter_fields() {
+                        let name = field.name().unwrap();
+                        dynamic_struct.insert_boxed(name, to_untyped(field.value(), registry)?);
+                    }
+                    DynamicVariant::Struct(dynamic_struct)
+                }
+            };
+            Ok(Box::new(DynamicEnum::new(value.variant_name(), variant)))
+        }
+        ReflectRef::Value(value) => {
+            UntypedValueDeserializer.deserialize(DynamicDeserializer::new(value, registry))
+        }
+    }
+}
+
 fn get_registration<'a, E: Error>(
     type_id: TypeId,
     type_path: &str,
     registry: &'a TypeRegistry,
 ) -> Result<&'a TypeRegistration, E> {
-    let registration = registry.get(type_id).ok_or_else(|| {
-        Error::custom(format_args!("no registration found for type `{type_path}`"))
-    })?;
+    let registration = registry
+        .get(type_id)
+        .ok_or_else(|| Error::custom(registry.describe_missing_registration(type_id, type_path)))?;
     Ok(registration)
 }
 
@@ -1181,7 +1622,10 @@ mod tests {
     use bevy_utils::HashMap;
 
     use crate as bevy_reflect;
-    use crate::serde::{ReflectDeserializer, ReflectSerializer, TypedReflectDeserializer};
+    use crate::serde::{
+        RedactionMode, ReflectDeserializer, ReflectSerializer, TypedReflectDeserializer,
+        TypedReflectSerializer,
+    };
     use crate::{DynamicEnum, FromReflect, Reflect, ReflectDeserialize, TypeRegistry};
 
     #[derive(Reflect, Debug, PartialEq)]
@@ -1632,4 +2076,226 @@ mod tests {
         let output = <MyStruct as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
         assert_eq!(expected, output);
     }
+
+    #[test]
+    fn should_deserialize_redacted_fields() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Account {
+            name: String,
+            #[reflect(redact)]
+            token: String,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Pin(u8, #[reflect(redact)] u32);
+
+        let mut registry = get_registry();
+        registry.register::<Account>();
+        registry.register::<Pin>();
+
+        let expected = Account {
+            name: String::from("Bevy"),
+            token: String::new(),
+        };
+
+        // === Placeholder === //
+        let input = r#"{
+            "bevy_reflect::serde::de::tests::Account": (
+                name: "Bevy",
+                token: "<redacted>",
+            ),
+        }"#;
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let dynamic_output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+        let output = <Account as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        assert_eq!(expected, output);
+
+        // === Skip === //
+        let input = r#"{
+            "bevy_reflect::serde::de::tests::Account": (
+                name: "Bevy",
+            ),
+        }"#;
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let dynamic_output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+        let output = <Account as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        assert_eq!(expected, output);
+
+        // === Positional === //
+        let input = Pin(7, 1234);
+        let serializer = ReflectSerializer::new(&input, &registry);
+        let bytes = bincode::serialize(&serializer).unwrap();
+
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+        let dynamic_output = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(reflect_deserializer, &bytes)
+            .unwrap();
+        let output = <Pin as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        assert_eq!(Pin(7, 0), output);
+
+        // === Positional Skip === //
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Session {
+            #[reflect(redact)]
+            token: String,
+            user: u32,
+            pin: Pin,
+        }
+        registry.register::<Session>();
+
+        let input = Session {
+            token: String::from("hunter2"),
+            user: 42,
+            pin: Pin(7, 1234),
+        };
+        let serializer =
+            ReflectSerializer::new(&input, &registry).with_redaction(RedactionMode::Skip);
+        let bytes = bincode::serialize(&serializer).unwrap();
+
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+        let dynamic_output = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(reflect_deserializer, &bytes)
+            .unwrap();
+        let output = <Session as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        let expected = Session {
+            token: String::new(),
+            user: 42,
+            pin: Pin(7, 0),
+        };
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn should_not_deserialize_duplicate_map_keys() {
+        let mut registry = get_registry();
+        registry.register::<HashMap<u32, u32>>();
+        let registration = registry.get(TypeId::of::<HashMap<u32, u32>>()).unwrap();
+
+        let expected = HashMap::from([(1_u32, 2_u32), (3, 4)]);
+        let serializer = TypedReflectSerializer::new(&expected, &registry);
+        let input = ron::ser::to_string(&serializer).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(&input).unwrap();
+        let output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+        let output = crate::map_from_reflect_strict::<HashMap<u32, u32>>(&*output);
+        assert_eq!(Ok(expected), output);
+
+        // Repeated keys in the document replace earlier entries
+        let input = "{1: 2, 1: 3}";
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+        let output = crate::map_from_reflect_strict::<HashMap<u32, u32>>(&*output);
+        assert_eq!(Ok(HashMap::from([(1, 3)])), output);
+    }
+
+    #[test]
+    fn should_deserialize_byte_lists() {
+        let mut registry = get_registry();
+        registry.register::<Vec<u8>>();
+        registry.register::<Vec<f32>>();
+        let registration = registry.get(TypeId::of::<Vec<u8>>()).unwrap();
+
+        let expected: Vec<u8> = vec![0, 1, 2, 254, 255];
+        let serializer = TypedReflectSerializer::new(&expected, &registry);
+
+        // === RON === //
+        let input = ron::ser::to_string(&serializer).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(&input).unwrap();
+        let output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+        assert_eq!(expected, <Vec<u8>>::from_reflect(&*output).unwrap());
+
+        // === Legacy RON === //
+        let input = "[0, 1, 2, 254, 255]";
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+        assert_eq!(expected, <Vec<u8>>::from_reflect(&*output).unwrap());
+
+        // === Bincode === //
+        let input = bincode::serialize(&serializer).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let output = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(reflect_deserializer, &input)
+            .unwrap();
+        assert_eq!(expected, <Vec<u8>>::from_reflect(&*output).unwrap());
+
+        // === MessagePack === //
+        let input = rmp_serde::to_vec(&serializer).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let output = reflect_deserializer
+            .deserialize(&mut rmp_serde::Deserializer::new(input.as_slice()))
+            .unwrap();
+        assert_eq!(expected, <Vec<u8>>::from_reflect(&*output).unwrap());
+
+        // === JSON === //
+        let input = serde_json::to_string(&serializer).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let output = reflect_deserializer
+            .deserialize(&mut serde_json::Deserializer::from_str(&input))
+            .unwrap();
+        assert_eq!(expected, <Vec<u8>>::from_reflect(&*output).unwrap());
+
+        // === Other primitives === //
+        let expected: Vec<f32> = vec![1.0, 2.5];
+        let serializer = TypedReflectSerializer::new(&expected, &registry);
+        let input = ron::ser::to_string(&serializer).unwrap();
+        let registration = registry.get(TypeId::of::<Vec<f32>>()).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(&input).unwrap();
+        let output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+        assert_eq!(expected, <Vec<f32>>::from_reflect(&*output).unwrap());
+    }
+
+    #[test]
+    fn should_deserialize_with_alias() {
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Stat<T>(T);
+
+        type Health = Stat<f32>;
+
+        let mut registry = get_registry();
+        registry.register::<Health>();
+        registry.register_alias::<Health>("Health").unwrap();
+
+        let input = r#"{"Health":(10.0)}"#;
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+        assert_eq!(Stat(10.0), <Health>::from_reflect(&*output).unwrap());
+
+        // Short type paths are not accepted
+        let input = r#"{"Stat<f32>":(5.0)}"#;
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let error = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap_err();
+        assert_eq!(
+            ron::Error::Message(String::from("No registration found for `Stat<f32>`")),
+            error
+        );
+    }
 }
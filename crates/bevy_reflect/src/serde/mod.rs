@@ -1,7 +1,11 @@
+mod bridge;
+mod consistency;
 mod de;
 mod ser;
 mod type_data;
 
+pub use bridge::*;
+pub use consistency::*;
 pub use de::*;
 pub use ser::*;
 pub use type_data::*;
@@ -12,8 +16,9 @@ mod tests {
     use crate::{
         serde::{ReflectDeserializer, ReflectSerializer},
         type_registry::TypeRegistry,
-        DynamicStruct, FromReflect, Reflect,
+        DynamicStruct, FromReflect, Reflect, Typed,
     };
+    use bincode::Options;
     use serde::de::DeserializeSeed;
 
     #[test]
@@ -136,6 +141,120 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_serialize_binary_in_stable_index_order() {
+        mod v1 {
+            use crate as bevy_reflect;
+            use crate::Reflect;
+
+            #[derive(Reflect, Debug, PartialEq)]
+            #[type_path = "save"]
+            pub struct Save {
+                #[reflect(index = 0)]
+                pub health: u32,
+                #[reflect(index = 1)]
+                pub name: String,
+                #[reflect(index = 2)]
+                pub level: u8,
+            }
+        }
+
+        mod v2 {
+            use crate as bevy_reflect;
+            use crate::Reflect;
+
+            #[derive(Reflect, Debug, PartialEq)]
+            #[type_path = "save"]
+            pub struct Save {
+                #[reflect(index = 2)]
+                pub level: u8,
+                #[reflect(index = 0)]
+                pub health: u32,
+                #[reflect(index = 1)]
+                pub name: String,
+            }
+        }
+
+        let mut v1_registry = TypeRegistry::default();
+        v1_registry.register::<v1::Save>();
+        let mut v2_registry = TypeRegistry::default();
+        v2_registry.register::<v2::Save>();
+
+        let v1_value = v1::Save {
+            health: 100,
+            name: String::from("Player"),
+            level: 7,
+        };
+        let v2_value = v2::Save {
+            level: 7,
+            health: 100,
+            name: String::from("Player"),
+        };
+
+        let v1_bytes =
+            bincode::serialize(&ReflectSerializer::new(&v1_value, &v1_registry)).unwrap();
+        let v2_bytes =
+            bincode::serialize(&ReflectSerializer::new(&v2_value, &v2_registry)).unwrap();
+        assert_eq!(v1_bytes, v2_bytes);
+
+        // Data saved with the old layout can be loaded with the new one
+        let value = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(ReflectDeserializer::new(&v2_registry), &v1_bytes)
+            .unwrap();
+        let dynamic = value.take::<DynamicStruct>().unwrap();
+        assert_eq!(dynamic.name_at(0), Some("level"));
+        assert_eq!(
+            v2_value,
+            <v2::Save as FromReflect>::from_reflect(&dynamic).unwrap()
+        );
+
+        // Dynamic values are matched by field name rather than position
+        let mut reordered = DynamicStruct::default();
+        reordered.insert("name", String::from("Player"));
+        reordered.insert("health", 100_u32);
+        reordered.insert("level", 7_u8);
+        reordered.set_represented_type(Some(<v2::Save as Typed>::type_info()));
+        let bytes = bincode::serialize(&ReflectSerializer::new(&reordered, &v2_registry)).unwrap();
+        assert_eq!(v2_bytes, bytes);
+
+        reordered.remove("name");
+        let result = bincode::serialize(&ReflectSerializer::new(&reordered, &v2_registry));
+        assert_eq!(
+            "missing field `name` on struct save::Save",
+            result.unwrap_err().to_string()
+        );
+
+        // Human-readable formats still use declaration order
+        let output = ron::ser::to_string(&ReflectSerializer::new(&v2_value, &v2_registry)).unwrap();
+        assert_eq!(
+            r#"{"save::Save":(level:7,health:100,name:"Player")}"#,
+            output
+        );
+
+        // Other non-human-readable formats round trip through the stable order
+        let bytes = rmp_serde::to_vec(&ReflectSerializer::new(&v2_value, &v2_registry)).unwrap();
+        let mut rmp_deserializer = rmp_serde::Deserializer::new(bytes.as_slice());
+        let value = ReflectDeserializer::new(&v2_registry)
+            .deserialize(&mut rmp_deserializer)
+            .unwrap();
+        assert_eq!(
+            v2_value,
+            <v2::Save as FromReflect>::from_reflect(&*value).unwrap()
+        );
+
+        // Human-readable formats read sequences in declaration order, matching the serializer
+        let mut json_deserializer =
+            serde_json::Deserializer::from_str(r#"{"save::Save":[7,100,"Player"]}"#);
+        let value = ReflectDeserializer::new(&v2_registry)
+            .deserialize(&mut json_deserializer)
+            .unwrap();
+        assert_eq!(
+            v2_value,
+            <v2::Save as FromReflect>::from_reflect(&*value).unwrap()
+        );
+    }
+
     #[test]
     #[should_panic(
         expected = "cannot serialize dynamic value without represented type: bevy_reflect::DynamicStruct"
@@ -181,4 +300,159 @@ mod tests {
 
         assert!(expected.reflect_partial_eq(&result).unwrap());
     }
+
+    #[cfg(feature = "trace")]
+    #[test]
+    fn should_trace_nested_serialization() {
+        use bevy_utils::tracing::{
+            field::{Field, Visit},
+            span::{Attributes, Id, Record},
+            subscriber, Event, Metadata, Subscriber,
+        };
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Debug)]
+        struct CollectedSpan {
+            name: &'static str,
+            parent: Option<usize>,
+            fields: Vec<(&'static str, String)>,
+        }
+
+        struct FieldCollector<'a>(&'a mut Vec<(&'static str, String)>);
+
+        impl<'a> Visit for FieldCollector<'a> {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0.push((field.name(), value.to_string()));
+            }
+
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.push((field.name(), format!("{value:?}")));
+            }
+        }
+
+        /// A subscriber recording every span along with the span that was entered when it was created.
+        #[derive(Default, Clone)]
+        struct SpanCollector {
+            spans: Arc<Mutex<Vec<CollectedSpan>>>,
+            stack: Arc<Mutex<Vec<usize>>>,
+        }
+
+        impl Subscriber for SpanCollector {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+                let mut fields = Vec::new();
+                attributes.record(&mut FieldCollector(&mut fields));
+
+                let mut spans = self.spans.lock().unwrap();
+                spans.push(CollectedSpan {
+                    name: attributes.metadata().name(),
+                    parent: self.stack.lock().unwrap().last().copied(),
+                    fields,
+                });
+                Id::from_u64(spans.len() as u64)
+            }
+
+            fn record(&self, span: &Id, values: &Record<'_>) {
+                let mut spans = self.spans.lock().unwrap();
+                let span = &mut spans[span.into_u64() as usize - 1];
+                values.record(&mut FieldCollector(&mut span.fields));
+            }
+
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+            fn event(&self, _event: &Event<'_>) {}
+
+            fn enter(&self, span: &Id) {
+                self.stack
+                    .lock()
+                    .unwrap()
+                    .push(span.into_u64() as usize - 1);
+            }
+
+            fn exit(&self, _span: &Id) {
+                self.stack.lock().unwrap().pop();
+            }
+        }
+
+        #[derive(Reflect)]
+        struct Inventory {
+            items: Vec<u32>,
+        }
+
+        #[derive(Reflect)]
+        struct Player {
+            inventory: Inventory,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+
+        let player = Player {
+            inventory: Inventory {
+                items: vec![1, 2, 3],
+            },
+        };
+
+        let collector = SpanCollector::default();
+        let output = subscriber::with_default(collector.clone(), || {
+            let serializer = ReflectSerializer::new(&player, &registry);
+            let output = ron::ser::to_string(&serializer).unwrap();
+
+            let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+            ReflectDeserializer::new(&registry)
+                .deserialize(&mut deserializer)
+                .unwrap();
+            output
+        });
+        assert_eq!(
+            r#"{"bevy_reflect::serde::tests::Player":(inventory:(items:[1,2,3]))}"#,
+            output
+        );
+
+        let spans = collector.spans.lock().unwrap();
+        let type_path = |index: usize| spans[index].fields[0].1.as_str();
+        let serialize = spans
+            .iter()
+            .enumerate()
+            .filter(|(_, span)| span.name == "reflect_serialize")
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        // Lists of primitives serialize their elements directly
+        assert_eq!(3, serialize.len());
+        assert_eq!(
+            "bevy_reflect::serde::tests::Player",
+            type_path(serialize[0])
+        );
+        assert_eq!(None, spans[serialize[0]].parent);
+        assert_eq!(
+            "bevy_reflect::serde::tests::Inventory",
+            type_path(serialize[1])
+        );
+        assert_eq!(Some(serialize[0]), spans[serialize[1]].parent);
+        assert_eq!("alloc::vec::Vec<u32>", type_path(serialize[2]));
+        assert_eq!(Some(serialize[1]), spans[serialize[2]].parent);
+        assert_eq!(("len", String::from("3")), spans[serialize[2]].fields[1]);
+
+        let deserialize = spans
+            .iter()
+            .enumerate()
+            .filter(|(_, span)| span.name == "reflect_deserialize")
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        // Player > Inventory > Vec<u32> > u32 (x3)
+        assert_eq!(6, deserialize.len());
+        assert_eq!(
+            "bevy_reflect::serde::tests::Player",
+            type_path(deserialize[0])
+        );
+        assert_eq!("alloc::vec::Vec<u32>", type_path(deserialize[2]));
+        assert_eq!(Some(deserialize[1]), spans[deserialize[2]].parent);
+        assert_eq!(("len", String::from("3")), spans[deserialize[2]].fields[1]);
+        assert_eq!("u32", type_path(deserialize[3]));
+        assert_eq!(Some(deserialize[2]), spans[deserialize[3]].parent);
+    }
 }
@@ -1,6 +1,7 @@
 use crate::{
-    Array, Enum, List, Map, Reflect, ReflectRef, ReflectSerialize, Struct, Tuple, TupleStruct,
-    TypeInfo, TypeRegistry, VariantInfo, VariantType,
+    Array, Enum, List, ListInfo, Map, Reflect, ReflectLock, ReflectRef, ReflectSerialize,
+    ReflectTransparent, Struct, Tuple, TupleStruct, TypeInfo, TypeRegistry, VariantInfo,
+    VariantType,
 };
 use serde::ser::{
     Error, SerializeStruct, SerializeStructVariant, SerializeTuple, SerializeTupleStruct,
@@ -11,7 +12,8 @@ use serde::{
     Serialize,
 };
 
-use super::SerializationData;
+use super::{RedactionData, RedactionMode, SerializationData};
+use std::cell::RefCell;
 
 pub enum Serializable<'a> {
     Owned(Box<dyn erased_serde::Serialize + 'a>),
@@ -32,22 +34,74 @@ fn get_serializable<'a, E: Error>(
     reflect_value: &'a dyn Reflect,
     type_registry: &TypeRegistry,
 ) -> Result<Serializable<'a>, E> {
-    let info = reflect_value.get_represented_type_info().ok_or_else(|| {
-        Error::custom(format_args!(
+    find_serializable(reflect_value, type_registry).ok_or_else(|| serializable_error(reflect_value))
+}
+
+/// Returns the value as a [`Serializable`] if its type registered [`ReflectSerialize`].
+///
+/// Unlike [`get_serializable`], this doesn't build an error when the type data is missing,
+/// which is the common case for structs, lists, and other containers.
+fn find_serializable<'a>(
+    reflect_value: &'a dyn Reflect,
+    type_registry: &TypeRegistry,
+) -> Option<Serializable<'a>> {
+    let info = reflect_value.get_represented_type_info()?;
+    let reflect_serialize = type_registry.get_type_data::<ReflectSerialize>(info.type_id())?;
+    Some(reflect_serialize.get_serializable(reflect_value))
+}
+
+/// The error returned when [`find_serializable`] fails for the given value.
+fn serializable_error<E: Error>(reflect_value: &dyn Reflect) -> E {
+    match reflect_value.get_represented_type_info() {
+        None => Error::custom(format_args!(
             "Type '{}' does not represent any type",
             reflect_value.reflect_type_path(),
-        ))
-    })?;
+        )),
+        Some(info) => Error::custom(format_args!(
+            "Type '{}' did not register ReflectSerialize",
+            info.type_path(),
+        )),
+    }
+}
 
-    let reflect_serialize = type_registry
-        .get_type_data::<ReflectSerialize>(info.type_id())
-        .ok_or_else(|| {
-            Error::custom(format_args!(
-                "Type '{}' did not register ReflectSerialize",
-                info.type_path(),
-            ))
-        })?;
-    Ok(reflect_serialize.get_serializable(reflect_value))
+/// Reusable buffers for the reflection serializers.
+///
+/// A few values can't be streamed straight to the underlying serializer,
+/// such as lists of bytes that aren't stored contiguously.
+/// Passing the same scratch to multiple serializers,
+/// with [`ReflectSerializer::with_scratch`], [`TypedReflectSerializer::with_scratch`],
+/// or the `with_scratch` method of any of the other reflection serializers,
+/// lets batch workloads reuse these buffers instead of allocating them for every value.
+///
+/// The output is the same whether or not a scratch is used.
+///
+/// # Example
+///
+/// ```
+/// # use std::collections::VecDeque;
+/// # use bevy_reflect::TypeRegistry;
+/// # use bevy_reflect::serde::{SerializerScratch, TypedReflectSerializer};
+/// let registry = TypeRegistry::default();
+/// let scratch = SerializerScratch::default();
+///
+/// for len in 0..4_u8 {
+///     let bytes = (0..len).collect::<VecDeque<u8>>();
+///     let serializer = TypedReflectSerializer::new(&bytes, &registry).with_scratch(&scratch);
+///     ron::to_string(&serializer).unwrap();
+/// }
+/// ```
+#[derive(Default)]
+pub struct SerializerScratch {
+    bytes: RefCell<Vec<u8>>,
+}
+
+impl SerializerScratch {
+    /// Creates a scratch whose byte buffer can hold `bytes` bytes without reallocating.
+    pub fn with_capacity(bytes: usize) -> Self {
+        Self {
+            bytes: RefCell::new(Vec::with_capacity(bytes)),
+        }
+    }
 }
 
 /// A general purpose serializer for reflected types.
@@ -62,6 +116,9 @@ fn get_serializable<'a, E: Error>(
 /// where the key is the _full_ [type path] of the reflected type
 /// and the value is the serialized data.
 ///
+/// With [`with_compact_names`](Self::with_compact_names), the key is instead
+/// the most compact name which identifies the type, as returned by [`TypeRegistry::compact_name`].
+///
 /// # Example
 ///
 /// ```
@@ -89,11 +146,58 @@ fn get_serializable<'a, E: Error>(
 pub struct ReflectSerializer<'a> {
     pub value: &'a dyn Reflect,
     pub registry: &'a TypeRegistry,
+    redaction: RedactionMode,
+    compact_names: bool,
+    scratch: Option<&'a SerializerScratch>,
 }
 
 impl<'a> ReflectSerializer<'a> {
     pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
-        ReflectSerializer { value, registry }
+        ReflectSerializer {
+            value,
+            registry,
+            redaction: RedactionMode::default(),
+            compact_names: false,
+            scratch: None,
+        }
+    }
+
+    /// Sets whether the type key should be the type's most compact name rather than its full type path.
+    ///
+    /// The compact name is the type's [alias] if it has exactly one,
+    /// or else its full type path.
+    /// Both are accepted by [`ReflectDeserializer`].
+    ///
+    /// [alias]: TypeRegistry::register_alias
+    /// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
+    pub fn with_compact_names(mut self, compact_names: bool) -> Self {
+        self.compact_names = compact_names;
+        self
+    }
+
+    /// Sets how fields marked as `#[reflect(redact)]` should be serialized.
+    ///
+    /// By default, redacted fields are replaced with [`RedactionMode::DEFAULT_PLACEHOLDER`].
+    pub fn with_redaction(mut self, redaction: RedactionMode) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Sets the [`SerializerScratch`] used for values that need a temporary buffer.
+    pub fn with_scratch(mut self, scratch: &'a SerializerScratch) -> Self {
+        self.scratch = Some(scratch);
+        self
+    }
+
+    /// Sets the redaction mode and scratch for a nested value.
+    fn with_options(
+        mut self,
+        redaction: RedactionMode,
+        scratch: Option<&'a SerializerScratch>,
+    ) -> Self {
+        self.redaction = redaction;
+        self.scratch = scratch;
+        self
     }
 }
 
@@ -102,25 +206,32 @@ impl<'a> Serialize for ReflectSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        let type_info = self.value.get_represented_type_info().ok_or_else(|| {
+            if self.value.is_dynamic() {
+                Error::custom(format_args!(
+                    "cannot serialize dynamic value without represented type: {}",
+                    self.value.reflect_type_path()
+                ))
+            } else {
+                Error::custom(format_args!(
+                    "cannot get type info for {}",
+                    self.value.reflect_type_path()
+                ))
+            }
+        })?;
+        let type_name = if self.compact_names {
+            self.registry
+                .compact_name(type_info.type_id())
+                .unwrap_or_else(|| type_info.type_path())
+        } else {
+            type_info.type_path()
+        };
+
         let mut state = serializer.serialize_map(Some(1))?;
         state.serialize_entry(
-            self.value
-                .get_represented_type_info()
-                .ok_or_else(|| {
-                    if self.value.is_dynamic() {
-                        Error::custom(format_args!(
-                            "cannot serialize dynamic value without represented type: {}",
-                            self.value.reflect_type_path()
-                        ))
-                    } else {
-                        Error::custom(format_args!(
-                            "cannot get type info for {}",
-                            self.value.reflect_type_path()
-                        ))
-                    }
-                })?
-                .type_path(),
-            &TypedReflectSerializer::new(self.value, self.registry),
+            type_name,
+            &TypedReflectSerializer::new(self.value, self.registry)
+                .with_options(self.redaction, self.scratch),
         )?;
         state.end()
     }
@@ -167,11 +278,43 @@ impl<'a> Serialize for ReflectSerializer<'a> {
 pub struct TypedReflectSerializer<'a> {
     pub value: &'a dyn Reflect,
     pub registry: &'a TypeRegistry,
+    redaction: RedactionMode,
+    scratch: Option<&'a SerializerScratch>,
 }
 
 impl<'a> TypedReflectSerializer<'a> {
     pub fn new(value: &'a dyn Reflect, registry: &'a TypeRegistry) -> Self {
-        TypedReflectSerializer { value, registry }
+        TypedReflectSerializer {
+            value,
+            registry,
+            redaction: RedactionMode::default(),
+            scratch: None,
+        }
+    }
+
+    /// Sets how fields marked as `#[reflect(redact)]` should be serialized.
+    ///
+    /// By default, redacted fields are replaced with [`RedactionMode::DEFAULT_PLACEHOLDER`].
+    pub fn with_redaction(mut self, redaction: RedactionMode) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Sets the [`SerializerScratch`] used for values that need a temporary buffer.
+    pub fn with_scratch(mut self, scratch: &'a SerializerScratch) -> Self {
+        self.scratch = Some(scratch);
+        self
+    }
+
+    /// Sets the redaction mode and scratch for a nested value.
+    fn with_options(
+        mut self,
+        redaction: RedactionMode,
+        scratch: Option<&'a SerializerScratch>,
+    ) -> Self {
+        self.redaction = redaction;
+        self.scratch = scratch;
+        self
     }
 }
 
@@ -180,9 +323,27 @@ impl<'a> Serialize for TypedReflectSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        #[cfg(feature = "trace")]
+        let _span = {
+            let span = bevy_utils::tracing::info_span!(
+                "reflect_serialize",
+                type_path = self.value.reflect_type_path(),
+                len = bevy_utils::tracing::field::Empty,
+            );
+            let len = match self.value.reflect_ref() {
+                ReflectRef::List(value) => Some(value.len()),
+                ReflectRef::Array(value) => Some(value.len()),
+                ReflectRef::Map(value) => Some(value.len()),
+                _ => None,
+            };
+            if let Some(len) = len {
+                span.record("len", len);
+            }
+            span.entered()
+        };
+
         // Handle both Value case and types that have a custom `Serialize`
-        let serializable = get_serializable::<S::Error>(self.value, self.registry);
-        if let Ok(serializable) = serializable {
+        if let Some(serializable) = find_serializable(self.value, self.registry) {
             return serializable.borrow().serialize(serializer);
         }
 
@@ -190,43 +351,121 @@ impl<'a> Serialize for TypedReflectSerializer<'a> {
             ReflectRef::Struct(value) => StructSerializer {
                 struct_value: value,
                 registry: self.registry,
+                redaction: self.redaction,
+                scratch: self.scratch,
             }
             .serialize(serializer),
             ReflectRef::TupleStruct(value) => TupleStructSerializer {
                 tuple_struct: value,
                 registry: self.registry,
+                redaction: self.redaction,
+                scratch: self.scratch,
             }
             .serialize(serializer),
             ReflectRef::Tuple(value) => TupleSerializer {
                 tuple: value,
                 registry: self.registry,
+                redaction: self.redaction,
+                scratch: self.scratch,
             }
             .serialize(serializer),
             ReflectRef::List(value) => ListSerializer {
                 list: value,
                 registry: self.registry,
+                redaction: self.redaction,
+                scratch: self.scratch,
             }
             .serialize(serializer),
             ReflectRef::Array(value) => ArraySerializer {
                 array: value,
                 registry: self.registry,
+                redaction: self.redaction,
+                scratch: self.scratch,
             }
             .serialize(serializer),
             ReflectRef::Map(value) => MapSerializer {
                 map: value,
                 registry: self.registry,
+                redaction: self.redaction,
+                scratch: self.scratch,
             }
             .serialize(serializer),
             ReflectRef::Enum(value) => EnumSerializer {
                 enum_value: value,
                 registry: self.registry,
+                redaction: self.redaction,
+                scratch: self.scratch,
             }
             .serialize(serializer),
-            ReflectRef::Value(_) => Err(serializable.err().unwrap()),
+            ReflectRef::Value(value) => {
+                // Locks are serialized as a snapshot of their guarded value
+                let lock = value.get_represented_type_info().and_then(|info| {
+                    self.registry
+                        .get_type_data::<ReflectLock>(info.type_id())?
+                        .snapshot(value)
+                });
+                if let Some(snapshot) = lock {
+                    let snapshot = snapshot.map_err(Error::custom)?;
+                    return TypedReflectSerializer::new(snapshot.as_ref(), self.registry)
+                        .with_options(self.redaction, self.scratch)
+                        .serialize(serializer);
+                }
+
+                // Transparent values are serialized as their inner value
+                let inner = value.get_represented_type_info().and_then(|info| {
+                    self.registry
+                        .get_type_data::<ReflectTransparent>(info.type_id())?
+                        .inner(value)
+                });
+                match inner {
+                    Some(inner) => TypedReflectSerializer::new(inner, self.registry)
+                        .with_options(self.redaction, self.scratch)
+                        .serialize(serializer),
+                    None => match value.get_represented_type_info() {
+                        Some(TypeInfo::Value(info)) if info.is_opaque() => {
+                            Err(Error::custom(format_args!(
+                                "cannot serialize opaque value `{}`: register `ReflectSerialize` for it in order to serialize it",
+                                info.type_path()
+                            )))
+                        }
+                        _ => Err(serializable_error(self.value)),
+                    },
+                }
+            }
         }
     }
 }
 
+/// Returns the redaction mode to use for fields which are written positionally.
+///
+/// A positional field can't be left out without shifting every field after it,
+/// so [`RedactionMode::Skip`] falls back to the default placeholder.
+fn positional_redaction(redaction: RedactionMode) -> RedactionMode {
+    match redaction {
+        RedactionMode::Skip => RedactionMode::default(),
+        redaction => redaction,
+    }
+}
+
+/// Returns the number of redacted fields that will be left out of the output.
+///
+/// Fields which are already skipped via [`SerializationData`] are not counted twice.
+fn redacted_len(
+    serialization_data: Option<&SerializationData>,
+    redaction_data: Option<&RedactionData>,
+    redaction: RedactionMode,
+) -> usize {
+    match (redaction, redaction_data) {
+        (RedactionMode::Skip, Some(redaction_data)) => redaction_data
+            .iter_redacted()
+            .filter(|(index, _)| {
+                !serialization_data.is_some_and(|data| data.is_field_skipped(**index))
+            })
+            .count(),
+        _ => 0,
+    }
+}
+
 pub struct ReflectValueSerializer<'a> {
     pub registry: &'a TypeRegistry,
     pub value: &'a dyn Reflect,
@@ -246,6 +485,33 @@ impl<'a> Serialize for ReflectValueSerializer<'a> {
 pub struct StructSerializer<'a> {
     pub struct_value: &'a dyn Struct,
     pub registry: &'a TypeRegistry,
+    redaction: RedactionMode,
+    scratch: Option<&'a SerializerScratch>,
+}
+
+impl<'a> StructSerializer<'a> {
+    pub fn new(struct_value: &'a dyn Struct, registry: &'a TypeRegistry) -> Self {
+        StructSerializer {
+            struct_value,
+            registry,
+            redaction: RedactionMode::default(),
+            scratch: None,
+        }
+    }
+
+    /// Sets how fields marked as `#[reflect(redact)]` should be serialized.
+    ///
+    /// By default, redacted fields are replaced with [`RedactionMode::DEFAULT_PLACEHOLDER`].
+    pub fn with_redaction(mut self, redaction: RedactionMode) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Sets the [`SerializerScratch`] used for values that need a temporary buffer.
+    pub fn with_scratch(mut self, scratch: &'a SerializerScratch) -> Self {
+        self.scratch = Some(scratch);
+        self
+    }
 }
 
 impl<'a> Serialize for StructSerializer<'a> {
@@ -276,21 +542,76 @@ impl<'a> Serialize for StructSerializer<'a> {
             .registry
             .get(type_info.type_id())
             .and_then(|registration| registration.data::<SerializationData>());
-        let ignored_len = serialization_data.map(|data| data.len()).unwrap_or(0);
+        let redaction_data = self
+            .registry
+            .get(type_info.type_id())
+            .and_then(|registration| registration.data::<RedactionData>());
+
+        // Positional formats rely on field order, so use the stable order if one was given
+        let positional = !serializer.is_human_readable();
+        let stable_order = struct_info.stable_order().filter(|_| positional);
+        let redaction = if positional {
+            positional_redaction(self.redaction)
+        } else {
+            self.redaction
+        };
+
+        let ignored_len = serialization_data.map(|data| data.len()).unwrap_or(0)
+            + redacted_len(serialization_data, redaction_data, redaction);
+
+        // Dynamic values may hold fewer fields than the represented type, or hold them in another order
+        let field_len = stable_order.map_or(self.struct_value.field_len(), <[usize]>::len);
+
         let mut state = serializer.serialize_struct(
             struct_info.type_path_table().ident().unwrap(),
-            self.struct_value.field_len() - ignored_len,
+            field_len.saturating_sub(ignored_len),
         )?;
 
-        for (index, value) in self.struct_value.iter_fields().enumerate() {
+        for position in 0..field_len {
+            let index = stable_order.map_or(position, |order| order[position]);
             if serialization_data
                 .map(|data| data.is_field_skipped(index))
                 .unwrap_or(false)
             {
                 continue;
             }
-            let key = struct_info.field_at(index).unwrap().name();
-            state.serialize_field(key, &TypedReflectSerializer::new(value, self.registry))?;
+            let key = struct_info
+                .field_at(index)
+                .ok_or_else(|| {
+                    Error::custom(format_args!(
+                        "no field at index {index} on struct {}",
+                        struct_info.type_path()
+                    ))
+                })?
+                .name();
+            if redaction_data
+                .map(|data| data.is_field_redacted(index))
+                .unwrap_or(false)
+            {
+                match redaction {
+                    RedactionMode::Placeholder(placeholder) => {
+                        state.serialize_field(key, placeholder)?;
+                    }
+                    RedactionMode::Skip => {}
+                }
+                continue;
+            }
+            let value = if self.struct_value.is_dynamic() {
+                self.struct_value.field(key)
+            } else {
+                self.struct_value.field_at(index)
+            }
+            .ok_or_else(|| {
+                Error::custom(format_args!(
+                    "missing field `{key}` on struct {}",
+                    struct_info.type_path()
+                ))
+            })?;
+            state.serialize_field(
+                key,
+                &TypedReflectSerializer::new(value, self.registry)
+                    .with_options(self.redaction, self.scratch),
+            )?;
         }
         state.end()
     }
@@ -299,6 +620,33 @@ impl<'a> Serialize for StructSerializer<'a> {
 pub struct TupleStructSerializer<'a> {
     pub tuple_struct: &'a dyn TupleStruct,
     pub registry: &'a TypeRegistry,
+    redaction: RedactionMode,
+    scratch: Option<&'a SerializerScratch>,
+}
+
+impl<'a> TupleStructSerializer<'a> {
+    pub fn new(tuple_struct: &'a dyn TupleStruct, registry: &'a TypeRegistry) -> Self {
+        TupleStructSerializer {
+            tuple_struct,
+            registry,
+            redaction: RedactionMode::default(),
+            scratch: None,
+        }
+    }
+
+    /// Sets how fields marked as `#[reflect(redact)]` should be serialized.
+    ///
+    /// By default, redacted fields are replaced with [`RedactionMode::DEFAULT_PLACEHOLDER`].
+    pub fn with_redaction(mut self, redaction: RedactionMode) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Sets the [`SerializerScratch`] used for values that need a temporary buffer.
+    pub fn with_scratch(mut self, scratch: &'a SerializerScratch) -> Self {
+        self.scratch = Some(scratch);
+        self
+    }
 }
 
 impl<'a> Serialize for TupleStructSerializer<'a> {
@@ -329,7 +677,14 @@ impl<'a> Serialize for TupleStructSerializer<'a> {
             .registry
             .get(type_info.type_id())
             .and_then(|registration| registration.data::<SerializationData>());
-        let ignored_len = serialization_data.map(|data| data.len()).unwrap_or(0);
+        let redaction_data = self
+            .registry
+            .get(type_info.type_id())
+            .and_then(|registration| registration.data::<RedactionData>());
+        // Tuple struct fields are always written positionally
+        let redaction = positional_redaction(self.redaction);
+        let ignored_len = serialization_data.map(|data| data.len()).unwrap_or(0)
+            + redacted_len(serialization_data, redaction_data, redaction);
         let mut state = serializer.serialize_tuple_struct(
             tuple_struct_info.type_path_table().ident().unwrap(),
             self.tuple_struct.field_len() - ignored_len,
@@ -342,7 +697,22 @@ impl<'a> Serialize for TupleStructSerializer<'a> {
             {
                 continue;
             }
-            state.serialize_field(&TypedReflectSerializer::new(value, self.registry))?;
+            if redaction_data
+                .map(|data| data.is_field_redacted(index))
+                .unwrap_or(false)
+            {
+                match redaction {
+                    RedactionMode::Placeholder(placeholder) => {
+                        state.serialize_field(placeholder)?;
+                    }
+                    RedactionMode::Skip => {}
+                }
+                continue;
+            }
+            state.serialize_field(
+                &TypedReflectSerializer::new(value, self.registry)
+                    .with_options(self.redaction, self.scratch),
+            )?;
         }
         state.end()
     }
@@ -351,6 +721,33 @@ impl<'a> Serialize for TupleStructSerializer<'a> {
 pub struct EnumSerializer<'a> {
     pub enum_value: &'a dyn Enum,
     pub registry: &'a TypeRegistry,
+    redaction: RedactionMode,
+    scratch: Option<&'a SerializerScratch>,
+}
+
+impl<'a> EnumSerializer<'a> {
+    pub fn new(enum_value: &'a dyn Enum, registry: &'a TypeRegistry) -> Self {
+        EnumSerializer {
+            enum_value,
+            registry,
+            redaction: RedactionMode::default(),
+            scratch: None,
+        }
+    }
+
+    /// Sets how fields marked as `#[reflect(redact)]` should be serialized.
+    ///
+    /// By default, redacted fields are replaced with [`RedactionMode::DEFAULT_PLACEHOLDER`].
+    pub fn with_redaction(mut self, redaction: RedactionMode) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Sets the [`SerializerScratch`] used for values that need a temporary buffer.
+    pub fn with_scratch(mut self, scratch: &'a SerializerScratch) -> Self {
+        self.scratch = Some(scratch);
+        self
+    }
 }
 
 impl<'a> Serialize for EnumSerializer<'a> {
@@ -417,7 +814,8 @@ impl<'a> Serialize for EnumSerializer<'a> {
                     let field_info = struct_info.field_at(index).unwrap();
                     state.serialize_field(
                         field_info.name(),
-                        &TypedReflectSerializer::new(field.value(), self.registry),
+                        &TypedReflectSerializer::new(field.value(), self.registry)
+                            .with_options(self.redaction, self.scratch),
                     )?;
                 }
                 state.end()
@@ -428,13 +826,17 @@ impl<'a> Serialize for EnumSerializer<'a> {
                 if type_info.type_path_table().module_path() == Some("core::option")
                     && type_info.type_path_table().ident() == Some("Option")
                 {
-                    serializer.serialize_some(&TypedReflectSerializer::new(field, self.registry))
+                    serializer.serialize_some(
+                        &TypedReflectSerializer::new(field, self.registry)
+                            .with_options(self.redaction, self.scratch),
+                    )
                 } else {
                     serializer.serialize_newtype_variant(
                         enum_name,
                         variant_index,
                         variant_name,
-                        &TypedReflectSerializer::new(field, self.registry),
+                        &TypedReflectSerializer::new(field, self.registry)
+                            .with_options(self.redaction, self.scratch),
                     )
                 }
             }
@@ -446,10 +848,10 @@ impl<'a> Serialize for EnumSerializer<'a> {
                     field_len,
                 )?;
                 for field in self.enum_value.iter_fields() {
-                    state.serialize_field(&TypedReflectSerializer::new(
-                        field.value(),
-                        self.registry,
-                    ))?;
+                    state.serialize_field(
+                        &TypedReflectSerializer::new(field.value(), self.registry)
+                            .with_options(self.redaction, self.scratch),
+                    )?;
                 }
                 state.end()
             }
@@ -460,6 +862,33 @@ impl<'a> Serialize for EnumSerializer<'a> {
 pub struct TupleSerializer<'a> {
     pub tuple: &'a dyn Tuple,
     pub registry: &'a TypeRegistry,
+    redaction: RedactionMode,
+    scratch: Option<&'a SerializerScratch>,
+}
+
+impl<'a> TupleSerializer<'a> {
+    pub fn new(tuple: &'a dyn Tuple, registry: &'a TypeRegistry) -> Self {
+        TupleSerializer {
+            tuple,
+            registry,
+            redaction: RedactionMode::default(),
+            scratch: None,
+        }
+    }
+
+    /// Sets how fields marked as `#[reflect(redact)]` should be serialized.
+    ///
+    /// By default, redacted fields are replaced with [`RedactionMode::DEFAULT_PLACEHOLDER`].
+    pub fn with_redaction(mut self, redaction: RedactionMode) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Sets the [`SerializerScratch`] used for values that need a temporary buffer.
+    pub fn with_scratch(mut self, scratch: &'a SerializerScratch) -> Self {
+        self.scratch = Some(scratch);
+        self
+    }
 }
 
 impl<'a> Serialize for TupleSerializer<'a> {
@@ -470,7 +899,10 @@ impl<'a> Serialize for TupleSerializer<'a> {
         let mut state = serializer.serialize_tuple(self.tuple.field_len())?;
 
         for value in self.tuple.iter_fields() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_element(
+                &TypedReflectSerializer::new(value, self.registry)
+                    .with_options(self.redaction, self.scratch),
+            )?;
         }
         state.end()
     }
@@ -479,6 +911,33 @@ impl<'a> Serialize for TupleSerializer<'a> {
 pub struct MapSerializer<'a> {
     pub map: &'a dyn Map,
     pub registry: &'a TypeRegistry,
+    redaction: RedactionMode,
+    scratch: Option<&'a SerializerScratch>,
+}
+
+impl<'a> MapSerializer<'a> {
+    pub fn new(map: &'a dyn Map, registry: &'a TypeRegistry) -> Self {
+        MapSerializer {
+            map,
+            registry,
+            redaction: RedactionMode::default(),
+            scratch: None,
+        }
+    }
+
+    /// Sets how fields marked as `#[reflect(redact)]` should be serialized.
+    ///
+    /// By default, redacted fields are replaced with [`RedactionMode::DEFAULT_PLACEHOLDER`].
+    pub fn with_redaction(mut self, redaction: RedactionMode) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Sets the [`SerializerScratch`] used for values that need a temporary buffer.
+    pub fn with_scratch(mut self, scratch: &'a SerializerScratch) -> Self {
+        self.scratch = Some(scratch);
+        self
+    }
 }
 
 impl<'a> Serialize for MapSerializer<'a> {
@@ -489,8 +948,10 @@ impl<'a> Serialize for MapSerializer<'a> {
         let mut state = serializer.serialize_map(Some(self.map.len()))?;
         for (key, value) in self.map.iter() {
             state.serialize_entry(
-                &TypedReflectSerializer::new(key, self.registry),
-                &TypedReflectSerializer::new(value, self.registry),
+                &TypedReflectSerializer::new(key, self.registry)
+                    .with_options(self.redaction, self.scratch),
+                &TypedReflectSerializer::new(value, self.registry)
+                    .with_options(self.redaction, self.scratch),
             )?;
         }
         state.end()
@@ -500,6 +961,33 @@ impl<'a> Serialize for MapSerializer<'a> {
 pub struct ListSerializer<'a> {
     pub list: &'a dyn List,
     pub registry: &'a TypeRegistry,
+    redaction: RedactionMode,
+    scratch: Option<&'a SerializerScratch>,
+}
+
+impl<'a> ListSerializer<'a> {
+    pub fn new(list: &'a dyn List, registry: &'a TypeRegistry) -> Self {
+        ListSerializer {
+            list,
+            registry,
+            redaction: RedactionMode::default(),
+            scratch: None,
+        }
+    }
+
+    /// Sets how fields marked as `#[reflect(redact)]` should be serialized.
+    ///
+    /// By default, redacted fields are replaced with [`RedactionMode::DEFAULT_PLACEHOLDER`].
+    pub fn with_redaction(mut self, redaction: RedactionMode) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Sets the [`SerializerScratch`] used for values that need a temporary buffer.
+    pub fn with_scratch(mut self, scratch: &'a SerializerScratch) -> Self {
+        self.scratch = Some(scratch);
+        self
+    }
 }
 
 impl<'a> Serialize for ListSerializer<'a> {
@@ -507,17 +995,122 @@ impl<'a> Serialize for ListSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        let list_info = match self.list.get_represented_type_info() {
+            Some(TypeInfo::List(list_info)) => Some(list_info),
+            _ => None,
+        };
+        let dynamic_items = list_info.is_some_and(ListInfo::has_dynamic_items);
+
+        // Lists of bytes are serialized as a single contiguous value in formats that support it
+        if list_info.is_some_and(|info| !info.has_dynamic_items() && info.item_is::<u8>()) {
+            if let Some(bytes) = self.list.as_any().downcast_ref::<Vec<u8>>() {
+                return serializer.serialize_bytes(bytes);
+            }
+
+            // Other lists need to be copied into a contiguous buffer first
+            let mut scratch = self
+                .scratch
+                .and_then(|scratch| scratch.bytes.try_borrow_mut().ok());
+            let mut owned = Vec::new();
+            let bytes = match scratch.as_deref_mut() {
+                Some(scratch) => scratch,
+                None => &mut owned,
+            };
+            if collect_bytes(self.list, bytes) {
+                return serializer.serialize_bytes(bytes);
+            }
+        }
+
+        // Other lists of primitives skip reflection and serialize their items directly
+        if let Some(serializable) = primitive_list_serializable(self.list) {
+            return serializable.serialize(serializer);
+        }
+
         let mut state = serializer.serialize_seq(Some(self.list.len()))?;
         for value in self.list.iter() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            if dynamic_items {
+                // The item type can't be known ahead of time, so it has to be included
+                state.serialize_element(
+                    &ReflectSerializer::new(value, self.registry)
+                        .with_options(self.redaction, self.scratch),
+                )?;
+            } else {
+                state.serialize_element(
+                    &TypedReflectSerializer::new(value, self.registry)
+                        .with_options(self.redaction, self.scratch),
+                )?;
+            }
         }
         state.end()
     }
 }
 
+/// Copies the items of the given list into `bytes`, replacing its contents.
+///
+/// Returns `false` if any item is not a `u8`.
+fn collect_bytes(list: &dyn List, bytes: &mut Vec<u8>) -> bool {
+    bytes.clear();
+    bytes.reserve(list.len());
+    for item in list.iter() {
+        match item.downcast_ref::<u8>() {
+            Some(byte) => bytes.push(*byte),
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Returns the given list as a natively serializable value if it is a `Vec` of primitives.
+///
+/// The output is identical to serializing each item through reflection,
+/// but avoids the dynamic dispatch per item.
+fn primitive_list_serializable(list: &dyn List) -> Option<&dyn erased_serde::Serialize> {
+    macro_rules! downcast_primitives {
+        ($($ty:ty),* $(,)?) => {
+            $(
+                if let Some(list) = list.as_any().downcast_ref::<Vec<$ty>>() {
+                    return Some(list);
+                }
+            )*
+        };
+    }
+
+    downcast_primitives!(
+        u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char,
+    );
+    None
+}
+
 pub struct ArraySerializer<'a> {
     pub array: &'a dyn Array,
     pub registry: &'a TypeRegistry,
+    redaction: RedactionMode,
+    scratch: Option<&'a SerializerScratch>,
+}
+
+impl<'a> ArraySerializer<'a> {
+    pub fn new(array: &'a dyn Array, registry: &'a TypeRegistry) -> Self {
+        ArraySerializer {
+            array,
+            registry,
+            redaction: RedactionMode::default(),
+            scratch: None,
+        }
+    }
+
+    /// Sets how fields marked as `#[reflect(redact)]` should be serialized.
+    ///
+    /// By default, redacted fields are replaced with [`RedactionMode::DEFAULT_PLACEHOLDER`].
+    pub fn with_redaction(mut self, redaction: RedactionMode) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Sets the [`SerializerScratch`] used for values that need a temporary buffer.
+    pub fn with_scratch(mut self, scratch: &'a SerializerScratch) -> Self {
+        self.scratch = Some(scratch);
+        self
+    }
 }
 
 impl<'a> Serialize for ArraySerializer<'a> {
@@ -527,7 +1120,10 @@ impl<'a> Serialize for ArraySerializer<'a> {
     {
         let mut state = serializer.serialize_tuple(self.array.len())?;
         for value in self.array.iter() {
-            state.serialize_element(&TypedReflectSerializer::new(value, self.registry))?;
+            state.serialize_element(
+                &TypedReflectSerializer::new(value, self.registry)
+                    .with_options(self.redaction, self.scratch),
+            )?;
         }
         state.end()
     }
@@ -535,13 +1131,17 @@ impl<'a> Serialize for ArraySerializer<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::serde::ReflectSerializer;
+    use crate::serde::{
+        ListSerializer, RedactionMode, ReflectSerializer, SerializerScratch, StructSerializer,
+        TupleStructSerializer, TypedReflectSerializer,
+    };
     use crate::{self as bevy_reflect, Struct};
-    use crate::{Reflect, ReflectSerialize, TypeRegistry};
+    use crate::{List, Reflect, ReflectSerialize, TypeRegistry};
     use bevy_utils::HashMap;
     use ron::extensions::Extensions;
     use ron::ser::PrettyConfig;
     use serde::Serialize;
+    use std::collections::VecDeque;
     use std::f32::consts::PI;
 
     #[derive(Reflect, Debug, PartialEq)]
@@ -932,4 +1532,146 @@ mod tests {
 
         assert_eq!(expected, output);
     }
+
+    #[test]
+    fn should_redact_fields() {
+        #[derive(Reflect)]
+        struct Account {
+            name: String,
+            #[reflect(redact)]
+            token: String,
+        }
+
+        #[derive(Reflect)]
+        struct Pin(u8, #[reflect(redact)] u32);
+
+        let mut registry = get_registry();
+        registry.register::<Account>();
+        registry.register::<Pin>();
+
+        let account = Account {
+            name: String::from("Bevy"),
+            token: String::from("hunter2"),
+        };
+        let pin = Pin(1, 1234);
+
+        // === Placeholder === //
+        let serializer = ReflectSerializer::new(&account, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        let expected =
+            r#"{"bevy_reflect::serde::ser::tests::Account":(name:"Bevy",token:"<redacted>")}"#;
+        assert_eq!(expected, output);
+
+        let serializer = ReflectSerializer::new(&pin, &registry)
+            .with_redaction(RedactionMode::Placeholder("***"));
+        let output = ron::ser::to_string(&serializer).unwrap();
+        let expected = r#"{"bevy_reflect::serde::ser::tests::Pin":(1,"***")}"#;
+        assert_eq!(expected, output);
+
+        // === Skip === //
+        let serializer =
+            ReflectSerializer::new(&account, &registry).with_redaction(RedactionMode::Skip);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        let expected = r#"{"bevy_reflect::serde::ser::tests::Account":(name:"Bevy")}"#;
+        assert_eq!(expected, output);
+
+        // Tuple struct fields are positional, so they can't be left out
+        let serializer =
+            ReflectSerializer::new(&pin, &registry).with_redaction(RedactionMode::Skip);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        let expected = r#"{"bevy_reflect::serde::ser::tests::Pin":(1,"<redacted>")}"#;
+        assert_eq!(expected, output);
+
+        // === Sub-serializers === //
+        let serializer =
+            StructSerializer::new(&account, &registry).with_redaction(RedactionMode::Skip);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(r#"(name:"Bevy")"#, output);
+
+        let serializer = TupleStructSerializer::new(&pin, &registry)
+            .with_redaction(RedactionMode::Placeholder("***"));
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(r#"(1,"***")"#, output);
+    }
+
+    #[test]
+    fn should_serialize_byte_lists_contiguously() {
+        let registry = get_registry();
+
+        let input: Vec<u8> = vec![0, 1, 2, 254, 255];
+        let serializer = TypedReflectSerializer::new(&input, &registry);
+
+        // RON encodes bytes as a base64 string
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(r#""AAEC/v8=""#, output);
+
+        // MessagePack uses its native binary type rather than an array of integers
+        let output = rmp_serde::to_vec(&serializer).unwrap();
+        assert_eq!(vec![0xc4, 5, 0, 1, 2, 254, 255], output);
+
+        // Dynamic lists representing a list of bytes use the same representation
+        let dynamic = List::clone_dynamic(&input);
+        let serializer = TypedReflectSerializer::new(&dynamic, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(r#""AAEC/v8=""#, output);
+
+        // Other primitives are still serialized as a sequence
+        let input: Vec<f32> = vec![1.0, 2.5];
+        let serializer = TypedReflectSerializer::new(&input, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!("[1.0,2.5]", output);
+
+        // Non-primitive lists are unaffected
+        let input: Vec<String> = vec![String::from("a"), String::from("b")];
+        let serializer = TypedReflectSerializer::new(&input, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(r#"["a","b"]"#, output);
+    }
+
+    #[test]
+    fn should_serialize_with_scratch() {
+        let registry = get_registry();
+        let scratch = SerializerScratch::with_capacity(8);
+
+        let input: VecDeque<u8> = VecDeque::from([0, 1, 2, 254, 255]);
+        let expected =
+            ron::ser::to_string(&TypedReflectSerializer::new(&input, &registry)).unwrap();
+
+        let serializer = TypedReflectSerializer::new(&input, &registry).with_scratch(&scratch);
+        assert_eq!(expected, ron::ser::to_string(&serializer).unwrap());
+
+        let serializer = ListSerializer::new(&input, &registry).with_scratch(&scratch);
+        assert_eq!(expected, ron::ser::to_string(&serializer).unwrap());
+    }
+
+    #[test]
+    fn should_serialize_compact_names() {
+        #[derive(Reflect)]
+        struct Stat<T>(T);
+
+        type Health = Stat<f32>;
+
+        let mut registry = get_registry();
+        registry.register::<Health>();
+
+        let input: Health = Stat(10.0);
+        let serializer = ReflectSerializer::new(&input, &registry).with_compact_names(true);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(
+            r#"{"bevy_reflect::serde::ser::tests::Stat<f32>":(10.0)}"#,
+            output
+        );
+
+        registry.register_alias::<Health>("Health").unwrap();
+        let serializer = ReflectSerializer::new(&input, &registry).with_compact_names(true);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(r#"{"Health":(10.0)}"#, output);
+
+        let serializer = ReflectSerializer::new(&input, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(
+            r#"{"bevy_reflect::serde::ser::tests::Stat<f32>":(10.0)}"#,
+            output
+        );
+    }
 }
@@ -113,6 +113,109 @@ impl SerializationData {
     }
 }
 
+/// Contains data about the fields of a type marked as `#[reflect(redact)]`.
+///
+/// Redacted fields are never written out by the reflection serializers.
+/// Depending on the [`RedactionMode`], they are either replaced by a placeholder or skipped entirely.
+/// When deserializing, any value found for a redacted field is discarded
+/// and the field is instead populated with its default value.
+#[derive(Debug, Clone)]
+pub struct RedactionData {
+    redacted_fields: HashMap<usize, SkippedField>,
+}
+
+impl RedactionData {
+    /// Creates a new `RedactionData` instance with the given redacted fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `redacted_iter`: The iterator of redacted field indices along with the data used to generate their default values.
+    ///   Indices are assigned only to reflected fields.
+    pub fn new<I: Iterator<Item = (usize, SkippedField)>>(redacted_iter: I) -> Self {
+        Self {
+            redacted_fields: redacted_iter.collect(),
+        }
+    }
+
+    /// Returns true if the given index corresponds to a redacted field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::any::TypeId;
+    /// # use bevy_reflect::{Reflect, TypeRegistry, serde::RedactionData};
+    /// #[derive(Reflect)]
+    /// struct Account {
+    ///   name: String,
+    ///   #[reflect(redact)]
+    ///   token: String,
+    /// }
+    ///
+    /// let mut registry = TypeRegistry::new();
+    /// registry.register::<Account>();
+    ///
+    /// let redaction_data = registry.get_type_data::<RedactionData>(TypeId::of::<Account>()).unwrap();
+    /// assert!(!redaction_data.is_field_redacted(0));
+    /// assert!(redaction_data.is_field_redacted(1));
+    /// ```
+    pub fn is_field_redacted(&self, index: usize) -> bool {
+        self.redacted_fields.contains_key(&index)
+    }
+
+    /// Generates a default instance of the redacted field at the given index.
+    ///
+    /// Returns `None` if the field is not redacted.
+    pub fn generate_default(&self, index: usize) -> Option<Box<dyn Reflect>> {
+        self.redacted_fields
+            .get(&index)
+            .map(|field| field.generate_default())
+    }
+
+    /// Returns the number of redacted fields.
+    pub fn len(&self) -> usize {
+        self.redacted_fields.len()
+    }
+
+    /// Returns true if there are no redacted fields.
+    pub fn is_empty(&self) -> bool {
+        self.redacted_fields.is_empty()
+    }
+
+    /// Returns an iterator over the redacted fields.
+    ///
+    /// Each item in the iterator is a tuple containing:
+    /// 1. The reflected index of the field
+    /// 2. The deserialization metadata of the field
+    pub fn iter_redacted(&self) -> Iter<'_, usize, SkippedField> {
+        self.redacted_fields.iter()
+    }
+}
+
+/// Determines how the reflection serializers output fields marked as `#[reflect(redact)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedactionMode {
+    /// Replace the value of the field with the given string.
+    Placeholder(&'static str),
+    /// Leave the field out of the output entirely.
+    ///
+    /// Fields which are serialized positionally (tuple struct fields,
+    /// or any struct field in a non-human-readable format) can't be left out
+    /// without shifting the fields after them, so they are replaced with
+    /// [`RedactionMode::DEFAULT_PLACEHOLDER`] instead.
+    Skip,
+}
+
+impl RedactionMode {
+    /// The placeholder used by [`RedactionMode::default`].
+    pub const DEFAULT_PLACEHOLDER: &'static str = "<redacted>";
+}
+
+impl Default for RedactionMode {
+    fn default() -> Self {
+        Self::Placeholder(Self::DEFAULT_PLACEHOLDER)
+    }
+}
+
 /// Data needed for (de)serialization of a skipped field.
 #[derive(Debug, Clone)]
 pub struct SkippedField {
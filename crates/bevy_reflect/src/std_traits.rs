@@ -1,4 +1,5 @@
 use crate::{FromType, Reflect};
+use std::str::FromStr;
 
 /// A struct used to provide the default value of a type.
 ///
@@ -21,3 +22,28 @@ impl<T: Reflect + Default> FromType<T> for ReflectDefault {
         }
     }
 }
+
+/// A struct used to parse a value of a type from a string using its [`FromStr`] implementation.
+///
+/// A [`ReflectFromStr`] for type `T` can be obtained via [`FromType::from_type`].
+#[derive(Clone)]
+pub struct ReflectFromStr {
+    from_str: fn(&str) -> Option<Box<dyn Reflect>>,
+}
+
+impl ReflectFromStr {
+    /// Parses a value from the given string.
+    ///
+    /// Returns `None` if the string could not be parsed.
+    pub fn from_str(&self, s: &str) -> Option<Box<dyn Reflect>> {
+        (self.from_str)(s)
+    }
+}
+
+impl<T: Reflect + FromStr> FromType<T> for ReflectFromStr {
+    fn from_type() -> Self {
+        ReflectFromStr {
+            from_str: |s| Some(Box::new(T::from_str(s).ok()?)),
+        }
+    }
+}
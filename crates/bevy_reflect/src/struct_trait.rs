@@ -1,8 +1,10 @@
 use crate::attributes::{impl_custom_attribute_methods, CustomAttributes};
+use crate::fields::field_ptr;
 use crate::{
     self as bevy_reflect, ApplyError, NamedField, Reflect, ReflectKind, ReflectMut, ReflectOwned,
     ReflectRef, TypeInfo, TypePath, TypePathTable,
 };
+use bevy_ptr::Ptr;
 use bevy_reflect_derive::impl_type_path;
 use bevy_utils::HashMap;
 use std::fmt::{Debug, Formatter};
@@ -68,11 +70,42 @@ pub trait Struct: Reflect {
     /// Returns the number of fields in the struct.
     fn field_len(&self) -> usize;
 
+    /// Returns a pointer to the field with index `index`, computed from the field's [offset].
+    ///
+    /// This allows reading fields without dynamic dispatch per field,
+    /// such as with [`ReflectFromPtr`].
+    ///
+    /// Returns `None` if the field has no known offset, which is the case unless the type
+    /// derives `Reflect` with `#[reflect(field_offsets)]`, or if this is a dynamic struct.
+    ///
+    /// [offset]: NamedField::offset
+    /// [`ReflectFromPtr`]: crate::ReflectFromPtr
+    fn field_ptr(&self, index: usize) -> Option<Ptr<'_>> {
+        let Some(TypeInfo::Struct(info)) = self.get_represented_type_info() else {
+            return None;
+        };
+        let offset = info.field_at(index)?.offset()?;
+        field_ptr(self.as_any(), info.type_id(), offset)
+    }
+
     /// Returns an iterator over the values of the reflectable fields for this struct.
     fn iter_fields(&self) -> FieldIter;
 
     /// Clones the struct into a [`DynamicStruct`].
     fn clone_dynamic(&self) -> DynamicStruct;
+
+    /// Clones the struct into an existing [`DynamicStruct`].
+    ///
+    /// The `target` is cleared before being filled with the struct's fields,
+    /// which allows its allocations to be reused across calls.
+    /// The result is equivalent to calling [`Struct::clone_dynamic`].
+    fn clone_into_dynamic(&self, target: &mut DynamicStruct) {
+        target.clear();
+        target.set_represented_type(self.get_represented_type_info());
+        for (index, value) in self.iter_fields().enumerate() {
+            target.insert_boxed(self.name_at(index).unwrap().to_owned(), value.clone_value());
+        }
+    }
 }
 
 /// A container for compile-time named struct info.
@@ -83,6 +116,8 @@ pub struct StructInfo {
     fields: Box<[NamedField]>,
     field_names: Box<[&'static str]>,
     field_indices: HashMap<&'static str, usize>,
+    stable_indices: Option<Box<[usize]>>,
+    stable_order: Option<Box<[usize]>>,
     custom_attributes: Arc<CustomAttributes>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
@@ -104,12 +139,21 @@ impl StructInfo {
 
         let field_names = fields.iter().map(|field| field.name()).collect();
 
+        let stable_indices = Self::assign_stable_indices(fields);
+        let stable_order = stable_indices.as_ref().map(|stable_indices| {
+            let mut order = (0..fields.len()).collect::<Vec<_>>();
+            order.sort_by_key(|index| stable_indices[*index]);
+            order.into_boxed_slice()
+        });
+
         Self {
             type_path: TypePathTable::of::<T>(),
             type_id: TypeId::of::<T>(),
             fields: fields.to_vec().into_boxed_slice(),
             field_names,
             field_indices,
+            stable_indices,
+            stable_order,
             custom_attributes: Arc::new(CustomAttributes::default()),
             #[cfg(feature = "documentation")]
             docs: None,
@@ -152,6 +196,54 @@ impl StructInfo {
         self.field_indices.get(name).copied()
     }
 
+    /// Get the stable index of the field with the given name.
+    ///
+    /// Stable indices determine the order in which fields are written by
+    /// positional (non-self-describing) serializers, such as binary formats.
+    /// They can be assigned explicitly with the `#[reflect(index = N)]` attribute
+    /// so that reordering fields in source code does not break previously serialized data.
+    ///
+    /// Fields without an explicit index are assigned one in declaration order,
+    /// starting after the highest explicit index.
+    /// If no field has an explicit index, this is the same as [`StructInfo::index_of`].
+    ///
+    /// Stable indices are unique but may be sparse;
+    /// gaps only affect ordering and are not encoded in the serialized output.
+    pub fn stable_index_of(&self, name: &str) -> Option<usize> {
+        let index = self.index_of(name)?;
+        match &self.stable_indices {
+            Some(stable_indices) => Some(stable_indices[index]),
+            None => Some(index),
+        }
+    }
+
+    /// The field indices of this struct sorted by their [stable index],
+    /// or `None` if no field has an explicit stable index.
+    ///
+    /// [stable index]: StructInfo::stable_index_of
+    pub(crate) fn stable_order(&self) -> Option<&[usize]> {
+        self.stable_order.as_deref()
+    }
+
+    /// Computes the stable index of each field,
+    /// returning `None` if no field has an explicit stable index.
+    fn assign_stable_indices(fields: &[NamedField]) -> Option<Box<[usize]>> {
+        let max_explicit = fields.iter().filter_map(NamedField::stable_index).max()?;
+
+        let mut next_implicit = max_explicit + 1;
+        let stable_indices = fields
+            .iter()
+            .map(|field| {
+                field.stable_index().unwrap_or_else(|| {
+                    next_implicit += 1;
+                    next_implicit - 1
+                })
+            })
+            .collect();
+
+        Some(stable_indices)
+    }
+
     /// Iterate over the fields of this struct.
     pub fn iter(&self) -> Iter<'_, NamedField> {
         self.fields.iter()
@@ -282,6 +374,37 @@ impl GetField for dyn Struct {
     }
 }
 
+/// Creates a [`DynamicStruct`] from a list of `name => value` pairs.
+///
+/// Values can be any expression implementing [`Reflect`], including nested dynamic values.
+/// Since each field may have a different type,
+/// integer literals without a suffix are inferred as `i32`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{dyn_list, dyn_struct, GetField, Struct};
+/// let patch = dyn_struct! {
+///     "a" => 2u32,
+///     "c" => dyn_list![3isize, 4, 5],
+///     "e" => dyn_struct! { "x" => 2u32 },
+/// };
+///
+/// assert_eq!(3, patch.field_len());
+/// assert_eq!(Some(&2u32), patch.get_field::<u32>("a"));
+/// ```
+#[macro_export]
+macro_rules! dyn_struct {
+    () => {
+        $crate::DynamicStruct::default()
+    };
+    ($($name:expr => $value:expr),+ $(,)?) => {{
+        let mut dynamic_struct = $crate::DynamicStruct::default();
+        $(dynamic_struct.insert($name, $value);)+
+        dynamic_struct
+    }};
+}
+
 /// A struct type which allows fields to be added at runtime.
 #[derive(Default)]
 pub struct DynamicStruct {
@@ -337,6 +460,36 @@ impl DynamicStruct {
     pub fn index_of(&self, name: &str) -> Option<usize> {
         self.field_indices.get(name).copied()
     }
+
+    /// Removes the field with the given name, returning its value.
+    ///
+    /// The order of the remaining fields is preserved.
+    ///
+    /// Returns `None` if the field does not exist.
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn Reflect>> {
+        let index = self.field_indices.remove(name)?;
+        self.field_names.remove(index);
+        for field_index in self.field_indices.values_mut() {
+            if *field_index > index {
+                *field_index -= 1;
+            }
+        }
+        Some(self.fields.remove(index))
+    }
+
+    /// Removes all fields from the struct, keeping the allocated memory for reuse.
+    ///
+    /// The represented type is left unchanged.
+    pub fn clear(&mut self) {
+        self.fields.clear();
+        self.field_names.clear();
+        self.field_indices.clear();
+    }
+
+    /// Returns the number of fields the struct can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.fields.capacity()
+    }
 }
 
 impl Struct for DynamicStruct {
@@ -540,6 +693,47 @@ pub fn struct_partial_eq<S: Struct>(a: &S, b: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// Clones a [`Struct`] into an existing [`DynamicStruct`], reusing its field values where possible.
+///
+/// If `target` already contains the same fields in the same order,
+/// each field is updated in place:
+/// values of the same type are [applied] and nested dynamic structs are filled recursively.
+/// Otherwise, `target` is cleared and rebuilt from scratch.
+///
+/// This is used as the [`Struct::clone_into_dynamic`] implementation for derived types.
+///
+/// [applied]: Reflect::apply
+pub fn struct_clone_into_dynamic<S: Struct>(a: &S, target: &mut DynamicStruct) {
+    let same_layout = target.field_len() == a.field_len()
+        && (0..a.field_len()).all(|index| target.name_at(index) == a.name_at(index));
+
+    if !same_layout {
+        target.clear();
+        for (index, value) in a.iter_fields().enumerate() {
+            target.insert_boxed(a.name_at(index).unwrap().to_owned(), value.clone_value());
+        }
+    } else {
+        for (value, slot) in a.iter_fields().zip(target.fields.iter_mut()) {
+            clone_value_into(value, slot);
+        }
+    }
+
+    target.set_represented_type(a.get_represented_type_info());
+}
+
+/// Overwrites `slot` with a clone of `value`, reusing the existing box when possible.
+fn clone_value_into(value: &dyn Reflect, slot: &mut Box<dyn Reflect>) {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) if slot.is::<DynamicStruct>() => {
+            value.clone_into_dynamic(slot.downcast_mut::<DynamicStruct>().unwrap());
+        }
+        ReflectRef::Value(value) if slot.as_any().type_id() == value.as_any().type_id() => {
+            slot.apply(value);
+        }
+        _ => *slot = value.clone_value(),
+    }
+}
+
 /// The default debug formatter for [`Struct`] types.
 ///
 /// # Example
@@ -567,12 +761,21 @@ pub fn struct_debug(dyn_struct: &dyn Struct, f: &mut Formatter<'_>) -> std::fmt:
             .map(|s| s.type_path())
             .unwrap_or("_"),
     );
+    let struct_info = match dyn_struct.get_represented_type_info() {
+        Some(TypeInfo::Struct(info)) => Some(info),
+        _ => None,
+    };
     for field_index in 0..dyn_struct.field_len() {
         let field = dyn_struct.field_at(field_index).unwrap();
-        debug.field(
-            dyn_struct.name_at(field_index).unwrap(),
-            &field as &dyn Debug,
-        );
+        let name = dyn_struct.name_at(field_index).unwrap();
+        if struct_info
+            .and_then(|info| info.field(name))
+            .is_some_and(NamedField::is_redacted)
+        {
+            debug.field(name, &format_args!("<redacted>"));
+        } else {
+            debug.field(name, &field as &dyn Debug);
+        }
     }
     debug.finish()
 }
@@ -603,4 +806,228 @@ mod tests {
         assert!(iter.next().is_none());
         assert_eq!(prev_index, iter.index);
     }
+
+    #[derive(Reflect)]
+    struct Inner {
+        value: f32,
+    }
+
+    #[derive(Reflect)]
+    struct Outer {
+        id: usize,
+        name: String,
+        inner: Inner,
+    }
+
+    #[test]
+    fn should_clone_into_dynamic() {
+        let mut target = DynamicStruct::default();
+
+        for id in 0..3 {
+            let value = Outer {
+                id,
+                name: format!("outer_{id}"),
+                inner: Inner { value: id as f32 },
+            };
+
+            value.clone_into_dynamic(&mut target);
+
+            assert_eq!(target.field_len(), 3);
+            assert_eq!(target.get_field::<usize>("id"), Some(&id));
+            assert_eq!(
+                target.get_field::<String>("name"),
+                Some(&format!("outer_{id}"))
+            );
+            assert!(target
+                .reflect_partial_eq(&value.clone_dynamic())
+                .unwrap_or_default());
+            assert!(target.reflect_partial_eq(&value).unwrap_or_default());
+            assert_eq!(
+                target.get_represented_type_info().unwrap().type_id(),
+                std::any::TypeId::of::<Outer>()
+            );
+        }
+    }
+
+    #[test]
+    fn should_assign_stable_indices() {
+        #[derive(Reflect)]
+        struct Explicit {
+            a: u8,
+            #[reflect(index = 5)]
+            b: u8,
+            c: u8,
+            #[reflect(index = 2)]
+            d: u8,
+        }
+
+        #[derive(Reflect)]
+        struct Implicit {
+            a: u8,
+            b: u8,
+        }
+
+        let TypeInfo::Struct(info) = Explicit::type_info() else {
+            panic!("expected struct info");
+        };
+        assert_eq!(info.stable_index_of("a"), Some(6));
+        assert_eq!(info.stable_index_of("b"), Some(5));
+        assert_eq!(info.stable_index_of("c"), Some(7));
+        assert_eq!(info.stable_index_of("d"), Some(2));
+        assert_eq!(info.stable_index_of("e"), None);
+        assert_eq!(info.stable_order(), Some([3, 1, 0, 2].as_slice()));
+
+        let TypeInfo::Struct(info) = Implicit::type_info() else {
+            panic!("expected struct info");
+        };
+        assert_eq!(info.stable_index_of("a"), Some(0));
+        assert_eq!(info.stable_index_of("b"), Some(1));
+        assert_eq!(info.stable_order(), None);
+    }
+
+    #[test]
+    fn should_retain_capacity_when_cleared() {
+        let mut target = DynamicStruct::default();
+        target.insert("a", 1_usize);
+        target.insert("b", 2_usize);
+        target.insert("c", 3_usize);
+
+        let capacity = target.capacity();
+        target.clear();
+
+        assert_eq!(target.field_len(), 0);
+        assert_eq!(target.index_of("a"), None);
+        assert_eq!(target.capacity(), capacity);
+
+        MyStruct::default().clone_into_dynamic(&mut target);
+        assert_eq!(target.field_len(), 3);
+        assert_eq!(target.capacity(), capacity);
+    }
+
+    #[test]
+    fn should_rebuild_mismatched_dynamic_struct() {
+        let mut target = DynamicStruct::default();
+        target.insert("z", 123_i32);
+
+        let value = Inner { value: 1.5 };
+        value.clone_into_dynamic(&mut target);
+
+        assert_eq!(target.field_len(), 1);
+        assert_eq!(target.index_of("z"), None);
+        assert_eq!(target.get_field::<f32>("value"), Some(&1.5));
+    }
+
+    #[test]
+    fn should_expose_field_offsets() {
+        #[derive(Reflect)]
+        #[reflect(field_offsets)]
+        struct Foo {
+            a: u8,
+            #[reflect(ignore)]
+            _b: u64,
+            c: String,
+            d: u32,
+        }
+
+        #[derive(Reflect)]
+        #[reflect(field_offsets)]
+        struct Bar(u16, #[reflect(ignore)] (), f64);
+
+        let TypeInfo::Struct(info) = Foo::type_info() else {
+            panic!("expected struct info");
+        };
+        assert_eq!(
+            Some(std::mem::offset_of!(Foo, a)),
+            info.field("a").unwrap().offset()
+        );
+        assert_eq!(
+            Some(std::mem::offset_of!(Foo, c)),
+            info.field("c").unwrap().offset()
+        );
+        assert_eq!(
+            Some(std::mem::offset_of!(Foo, d)),
+            info.field("d").unwrap().offset()
+        );
+        assert!(info.field("_b").is_none());
+
+        let TypeInfo::TupleStruct(info) = Bar::type_info() else {
+            panic!("expected tuple struct info");
+        };
+        assert_eq!(
+            Some(std::mem::offset_of!(Bar, 0)),
+            info.field_at(0).unwrap().offset()
+        );
+        assert_eq!(
+            Some(std::mem::offset_of!(Bar, 2)),
+            info.field_at(1).unwrap().offset()
+        );
+    }
+
+    #[test]
+    fn should_read_fields_through_offsets() {
+        #[derive(Reflect)]
+        #[reflect(field_offsets)]
+        struct Foo {
+            a: u8,
+            b: String,
+            c: u32,
+        }
+
+        #[derive(Reflect)]
+        #[reflect(field_offsets)]
+        struct Bar(u16, f64);
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Foo>();
+        registry.register::<Bar>();
+
+        let foo = Foo {
+            a: 1,
+            b: String::from("hello"),
+            c: 123,
+        };
+        for index in 0..foo.field_len() {
+            let ptr = foo.field_ptr(index).unwrap();
+            let field = foo.field_at(index).unwrap();
+            let from_ptr = registry
+                .get_type_data::<ReflectFromPtr>(field.type_id())
+                .unwrap();
+            // SAFETY: The pointer points to the field whose type `from_ptr` was created for
+            #[allow(unsafe_code)]
+            let value = unsafe { from_ptr.as_reflect(ptr) };
+            assert_eq!(Some(true), value.reflect_partial_eq(field));
+        }
+        assert!(foo.field_ptr(3).is_none());
+
+        let bar = Bar(7, 1.5);
+        let ptr = TupleStruct::field_ptr(&bar, 1).unwrap();
+        // SAFETY: The second field of `Bar` is an `f64`
+        #[allow(unsafe_code)]
+        let value = unsafe { ptr.deref::<f64>() };
+        assert_eq!(1.5, *value);
+    }
+
+    #[test]
+    fn should_not_expose_field_offsets_without_attribute() {
+        #[derive(Reflect)]
+        struct Foo {
+            a: u8,
+        }
+
+        #[derive(Reflect)]
+        #[reflect(field_offsets)]
+        struct Bar {
+            a: u8,
+        }
+
+        let TypeInfo::Struct(info) = Foo::type_info() else {
+            panic!("expected struct info");
+        };
+        assert_eq!(None, info.field("a").unwrap().offset());
+        assert!(Foo { a: 1 }.field_ptr(0).is_none());
+
+        // Dynamic structs have no offsets, even when representing a type with them
+        let dynamic = Bar { a: 1 }.clone_dynamic();
+        assert!(dynamic.field_ptr(0).is_none());
+    }
 }
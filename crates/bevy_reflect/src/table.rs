@@ -0,0 +1,496 @@
+//! Columnar access to lists of reflected structs.
+//!
+//! Debug overlays and editor tables often need every value of a single field across a list,
+//! such as the `hp` of every enemy in a `Vec<Enemy>`.
+//! Rather than performing a separate [path] lookup per row,
+//! the functions in this module resolve the field index once
+//! and then access each row by index.
+//!
+//! [path]: crate::GetPath
+
+use crate::{List, Reflect, ReflectMut, ReflectRef, Struct, TypeInfo};
+use std::any::TypeId;
+use thiserror::Error;
+
+/// An error returned when projecting a column out of a [`List`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ProjectError {
+    #[error("row {row} is a `{type_path}`, which is not a struct")]
+    /// The element at the given row is not a struct.
+    NotAStruct { row: usize, type_path: String },
+
+    #[error("row {row} is a `{found}`, but the first row is a `{expected}`")]
+    /// The element at the given row does not have the same type as the first row.
+    Heterogeneous {
+        row: usize,
+        expected: String,
+        found: String,
+    },
+
+    #[error("row {row} has no field named `{field}`")]
+    /// The element at the given row has no field with the given name.
+    MissingField { row: usize, field: String },
+
+    #[error("row {row} has no field at index {index}")]
+    /// The element at the given row has no field at the given index.
+    MissingFieldIndex { row: usize, index: usize },
+}
+
+/// Returns the values of the field with the given name for every element of the list, in order.
+///
+/// The field index is resolved once against the first element's [`StructInfo`],
+/// and every subsequent element is accessed by index.
+/// An empty list results in an empty column.
+///
+/// # Errors
+///
+/// Returns an error containing the index of the failing row if an element is not a struct,
+/// does not have the same type as the first element, or does not contain the field.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::Reflect;
+/// # use bevy_reflect::table::project;
+/// #[derive(Reflect)]
+/// struct Enemy {
+///     hp: u32,
+/// }
+///
+/// let enemies = vec![Enemy { hp: 10 }, Enemy { hp: 20 }];
+/// let column = project(&enemies, "hp").unwrap();
+///
+/// let hp = column.iter().map(|hp| *hp.downcast_ref::<u32>().unwrap());
+/// assert_eq!(vec![10, 20], hp.collect::<Vec<_>>());
+/// ```
+///
+/// [`StructInfo`]: crate::StructInfo
+pub fn project<'a>(list: &'a dyn List, field: &str) -> Result<Vec<&'a dyn Reflect>, ProjectError> {
+    let Some(first) = list.get(0) else {
+        return Ok(Vec::new());
+    };
+    let column = Column::named(first, field)?;
+    list.iter()
+        .enumerate()
+        .map(|(row, element)| column.field(row, element))
+        .collect()
+}
+
+/// Returns the values of the field at the given index for every element of the list, in order.
+///
+/// This is the index-based counterpart to [`project`].
+///
+/// # Errors
+///
+/// Returns an error containing the index of the failing row if an element is not a struct,
+/// does not have the same type as the first element, or does not contain the field.
+pub fn project_at(list: &dyn List, index: usize) -> Result<Vec<&dyn Reflect>, ProjectError> {
+    let Some(first) = list.get(0) else {
+        return Ok(Vec::new());
+    };
+    let column = Column::indexed(first, index)?;
+    list.iter()
+        .enumerate()
+        .map(|(row, element)| column.field(row, element))
+        .collect()
+}
+
+/// Returns the values of each of the given fields for every element of the list.
+///
+/// The result contains one column per field, in the order the fields were given,
+/// and each column contains one value per element, in list order.
+/// All field indices are resolved once, and the list is traversed a single time.
+///
+/// # Errors
+///
+/// Returns an error containing the index of the failing row if an element is not a struct,
+/// does not have the same type as the first element, or does not contain one of the fields.
+pub fn project_columns<'a>(
+    list: &'a dyn List,
+    fields: &[&str],
+) -> Result<Vec<Vec<&'a dyn Reflect>>, ProjectError> {
+    let mut columns = fields
+        .iter()
+        .map(|_| Vec::with_capacity(list.len()))
+        .collect::<Vec<_>>();
+
+    let Some(first) = list.get(0) else {
+        return Ok(columns);
+    };
+    let resolved = fields
+        .iter()
+        .map(|field| Column::named(first, field))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (row, element) in list.iter().enumerate() {
+        for (column, values) in resolved.iter().zip(&mut columns) {
+            values.push(column.field(row, element)?);
+        }
+    }
+
+    Ok(columns)
+}
+
+/// Calls `f` with the row index and a mutable reference to the field with the given name
+/// for every element of the list, in order.
+///
+/// A mutable projection cannot be returned as a collection,
+/// since the list would have to be borrowed mutably once per row,
+/// so this visits each value with a callback instead.
+///
+/// Every row is validated before `f` is first called,
+/// so on error the list is left unmodified.
+///
+/// # Errors
+///
+/// Returns an error containing the index of the failing row if an element is not a struct,
+/// does not have the same type as the first element, or does not contain the field.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::Reflect;
+/// # use bevy_reflect::table::project_mut_for_each;
+/// #[derive(Reflect)]
+/// struct Enemy {
+///     hp: u32,
+/// }
+///
+/// let mut enemies = vec![Enemy { hp: 10 }, Enemy { hp: 20 }];
+/// project_mut_for_each(&mut enemies, "hp", |_, hp| {
+///     *hp.downcast_mut::<u32>().unwrap() += 5;
+/// })
+/// .unwrap();
+///
+/// assert_eq!(15, enemies[0].hp);
+/// assert_eq!(25, enemies[1].hp);
+/// ```
+pub fn project_mut_for_each(
+    list: &mut dyn List,
+    field: &str,
+    mut f: impl FnMut(usize, &mut dyn Reflect),
+) -> Result<(), ProjectError> {
+    let Some(first) = list.get(0) else {
+        return Ok(());
+    };
+    let column = Column::named(first, field)?;
+    for (row, element) in list.iter().enumerate() {
+        column.field(row, element)?;
+    }
+
+    for row in 0..list.len() {
+        let value = list
+            .get_mut(row)
+            .and_then(|element| match element.reflect_mut() {
+                ReflectMut::Struct(element) => {
+                    let index = column.index_in(element)?;
+                    element.field_at_mut(index)
+                }
+                _ => None,
+            })
+            .expect("row should have been validated");
+        f(row, value);
+    }
+
+    Ok(())
+}
+
+/// A field resolved against the first row of a list.
+struct Column<'a> {
+    index: usize,
+    /// The name of the field, if it was resolved by name.
+    name: Option<&'a str>,
+    /// The represented type of the first row, if any.
+    type_id: Option<TypeId>,
+    type_path: String,
+}
+
+impl<'a> Column<'a> {
+    fn named(first: &dyn Reflect, name: &'a str) -> Result<Self, ProjectError> {
+        let first_struct = as_struct(0, first)?;
+        let index = match first.get_represented_type_info() {
+            Some(TypeInfo::Struct(info)) => info.index_of(name),
+            _ => (0..first_struct.field_len()).find(|&i| first_struct.name_at(i) == Some(name)),
+        }
+        .ok_or_else(|| ProjectError::MissingField {
+            row: 0,
+            field: name.to_string(),
+        })?;
+
+        Ok(Self {
+            index,
+            name: Some(name),
+            type_id: first.get_represented_type_info().map(TypeInfo::type_id),
+            type_path: first.reflect_type_path().to_string(),
+        })
+    }
+
+    fn indexed(first: &dyn Reflect, index: usize) -> Result<Self, ProjectError> {
+        let column = Self {
+            index,
+            name: None,
+            type_id: first.get_represented_type_info().map(TypeInfo::type_id),
+            type_path: first.reflect_type_path().to_string(),
+        };
+        column.field(0, first)?;
+        Ok(column)
+    }
+
+    /// Returns the index of this column in the given row.
+    ///
+    /// Dynamic rows may not share the layout of the represented type,
+    /// so columns resolved by name are looked up by name in those rows.
+    fn index_in(&self, element: &dyn Struct) -> Option<usize> {
+        match self.name {
+            Some(name) if element.is_dynamic() => {
+                (0..element.field_len()).find(|&i| element.name_at(i) == Some(name))
+            }
+            _ => Some(self.index),
+        }
+    }
+
+    /// Returns the value of this column for the given row.
+    fn field<'b>(
+        &self,
+        row: usize,
+        element: &'b dyn Reflect,
+    ) -> Result<&'b dyn Reflect, ProjectError> {
+        let element_struct = as_struct(row, element)?;
+
+        let type_id = element.get_represented_type_info().map(TypeInfo::type_id);
+        if type_id != self.type_id {
+            return Err(ProjectError::Heterogeneous {
+                row,
+                expected: self.type_path.clone(),
+                found: element.reflect_type_path().to_string(),
+            });
+        }
+
+        self.index_in(element_struct)
+            .and_then(|index| element_struct.field_at(index))
+            .ok_or_else(|| match self.name {
+                Some(name) => ProjectError::MissingField {
+                    row,
+                    field: name.to_string(),
+                },
+                None => ProjectError::MissingFieldIndex {
+                    row,
+                    index: self.index,
+                },
+            })
+    }
+}
+
+fn as_struct(row: usize, element: &dyn Reflect) -> Result<&dyn Struct, ProjectError> {
+    match element.reflect_ref() {
+        ReflectRef::Struct(element) => Ok(element),
+        _ => Err(ProjectError::NotAStruct {
+            row,
+            type_path: element.reflect_type_path().to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{DynamicList, DynamicStruct, TypePath, Typed};
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Enemy {
+        name: String,
+        hp: u32,
+        speed: f32,
+    }
+
+    #[derive(Reflect)]
+    struct Ally {
+        hp: u32,
+    }
+
+    fn enemies(count: u32) -> Vec<Enemy> {
+        (0..count)
+            .map(|i| Enemy {
+                name: format!("enemy_{i}"),
+                hp: i * 2,
+                speed: i as f32,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_project_column() {
+        let enemies = enemies(1000);
+
+        let column = project(&enemies, "hp").unwrap();
+        assert_eq!(1000, column.len());
+        for (i, hp) in column.iter().enumerate() {
+            assert_eq!(Some(&(i as u32 * 2)), hp.downcast_ref::<u32>());
+        }
+
+        let column = project_at(&enemies, 0).unwrap();
+        assert_eq!(Some(&String::from("enemy_999")), column[999].downcast_ref());
+
+        assert_eq!(
+            ProjectError::MissingField {
+                row: 0,
+                field: String::from("mana"),
+            },
+            project(&enemies, "mana").unwrap_err()
+        );
+        assert_eq!(
+            ProjectError::MissingFieldIndex { row: 0, index: 3 },
+            project_at(&enemies, 3).unwrap_err()
+        );
+        assert!(project(&Vec::<Enemy>::new(), "mana").unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_fail_for_heterogeneous_list() {
+        let mut list = DynamicList::default();
+        list.push(Ally { hp: 1 });
+        list.push(Ally { hp: 2 });
+        list.push(Enemy {
+            name: String::from("enemy"),
+            hp: 3,
+            speed: 0.0,
+        });
+        list.push(4_u32);
+
+        assert_eq!(
+            ProjectError::Heterogeneous {
+                row: 2,
+                expected: Ally::type_path().to_string(),
+                found: Enemy::type_path().to_string(),
+            },
+            project(&list, "hp").unwrap_err()
+        );
+
+        list.remove(2);
+        assert_eq!(
+            ProjectError::NotAStruct {
+                row: 2,
+                type_path: String::from("u32"),
+            },
+            project(&list, "hp").unwrap_err()
+        );
+
+        // Dynamic rows are checked against the resolved field name
+        let mut list = DynamicList::default();
+        let mut row = DynamicStruct::default();
+        row.insert("hp", 1_u32);
+        list.push(row);
+        let mut row = DynamicStruct::default();
+        row.insert("mana", 2_u32);
+        list.push(row);
+        assert_eq!(
+            ProjectError::MissingField {
+                row: 1,
+                field: String::from("hp"),
+            },
+            project(&list, "hp").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn should_project_reordered_dynamic_rows() {
+        let mut list = DynamicList::default();
+        list.push(Enemy {
+            name: String::from("enemy"),
+            hp: 1,
+            speed: 0.5,
+        });
+        let mut row = DynamicStruct::default();
+        row.insert("speed", 1.5_f32);
+        row.insert("hp", 2_u32);
+        row.insert("name", String::from("reordered"));
+        row.set_represented_type(Some(Enemy::type_info()));
+        list.push(row);
+
+        let hp = project(&list, "hp")
+            .unwrap()
+            .iter()
+            .map(|hp| *hp.downcast_ref::<u32>().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 2], hp);
+
+        project_mut_for_each(&mut list, "speed", |_, speed| {
+            *speed.downcast_mut::<f32>().unwrap() *= 2.0;
+        })
+        .unwrap();
+        let speed = project(&list, "speed")
+            .unwrap()
+            .iter()
+            .map(|speed| *speed.downcast_ref::<f32>().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1.0, 3.0], speed);
+
+        // A dynamic row missing the field is reported rather than returning another column
+        let mut row = DynamicStruct::default();
+        row.insert("name", String::from("missing"));
+        row.insert("speed", 0.0_f32);
+        row.set_represented_type(Some(Enemy::type_info()));
+        list.push(row);
+        assert_eq!(
+            ProjectError::MissingField {
+                row: 2,
+                field: String::from("hp"),
+            },
+            project(&list, "hp").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn should_project_multiple_columns() {
+        let enemies = enemies(3);
+
+        let columns = project_columns(&enemies, &["speed", "name"]).unwrap();
+        assert_eq!(2, columns.len());
+
+        let speed = columns[0]
+            .iter()
+            .map(|speed| *speed.downcast_ref::<f32>().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![0.0, 1.0, 2.0], speed);
+
+        let names = columns[1]
+            .iter()
+            .map(|name| name.downcast_ref::<String>().unwrap().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["enemy_0", "enemy_1", "enemy_2"], names);
+
+        assert!(matches!(
+            project_columns(&enemies, &["hp", "mana"]),
+            Err(ProjectError::MissingField { row: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn should_visit_every_row_mutably() {
+        let mut enemies = enemies(1000);
+
+        let mut visited = Vec::new();
+        project_mut_for_each(&mut enemies, "hp", |row, hp| {
+            visited.push(row);
+            *hp.downcast_mut::<u32>().unwrap() += 1;
+        })
+        .unwrap();
+
+        assert_eq!((0..1000).collect::<Vec<_>>(), visited);
+        for (i, enemy) in enemies.into_iter().enumerate() {
+            assert_eq!(i as u32 * 2 + 1, enemy.hp);
+        }
+
+        let mut list = DynamicList::default();
+        list.push(Ally { hp: 1 });
+        list.push(4_u32);
+        let result = project_mut_for_each(&mut list, "hp", |_, _| {
+            panic!("no row should be visited");
+        });
+        assert!(matches!(
+            result,
+            Err(ProjectError::NotAStruct { row: 1, .. })
+        ));
+    }
+}
@@ -0,0 +1,593 @@
+//! Assertions for checking that [`Reflect`] implementations uphold the contracts
+//! the rest of the crate relies on.
+//!
+//! Crates that implement [`Reflect`], [`List`], [`Map`], or [`Struct`] by hand
+//! can call these functions from their own tests to catch subtle mistakes,
+//! such as a [`Map::get_at`] that disagrees with [`Map::iter`]
+//! or a [`Reflect::reflect_partial_eq`] that is not symmetric with its dynamic representation.
+//!
+//! Each function takes a `make` closure that returns a fresh, equal value every time it is called.
+//! For the checks to be meaningful, the value should not be empty:
+//! lists and maps should contain at least two entries.
+//!
+//! Every violation panics with a message naming the type and the property it broke.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy_reflect::test_suite::{assert_from_reflect_contract, assert_list_contract};
+//! assert_list_contract(|| vec![1, 2, 3]);
+//! assert_from_reflect_contract(|| vec![1, 2, 3]);
+//! ```
+
+use crate::{FromReflect, List, Map, Reflect, Struct, TypeInfo};
+use std::fmt::Debug;
+
+macro_rules! check {
+    ($value:expr, $cond:expr, $($msg:tt)*) => {
+        assert!(
+            $cond,
+            "`{}` violates the reflection contract: {}",
+            $value.reflect_type_path(),
+            format_args!($($msg)*)
+        );
+    };
+}
+
+/// Asserts that the given value upholds the contracts shared by all [`Reflect`] implementations.
+///
+/// This checks that:
+/// * [`Reflect::reflect_kind`] agrees with [`Reflect::reflect_ref`] and [`Reflect::reflect_mut`].
+/// * [`Reflect::clone_value`] represents the same type, has the same kind, and is equal to the original.
+/// * [`Reflect::reflect_partial_eq`] is reflexive and symmetric between the value and its [`Reflect::clone_value`].
+/// * [`Reflect::reflect_hash`] is deterministic and agrees with the hash of the value's [`Reflect::clone_value`].
+/// * [`Reflect::apply`] of the value's [`Reflect::clone_value`] is idempotent.
+pub fn assert_reflect_contract<T: Reflect>(make: impl Fn() -> T) {
+    let value = make();
+    let mut other = make();
+
+    let kind = value.reflect_kind();
+    check!(
+        value,
+        kind == value.reflect_ref().kind(),
+        "`reflect_kind` returned {kind}, but `reflect_ref` returned a {}",
+        value.reflect_ref().kind()
+    );
+    check!(
+        value,
+        kind == other.reflect_mut().kind(),
+        "`reflect_kind` returned {kind}, but `reflect_mut` returned a {}",
+        other.reflect_mut().kind()
+    );
+
+    let dynamic = value.clone_value();
+    check!(
+        value,
+        dynamic.get_represented_type_info().map(TypeInfo::type_path)
+            == value.get_represented_type_info().map(TypeInfo::type_path),
+        "`clone_value` returned a value representing `{:?}`",
+        dynamic.get_represented_type_info().map(TypeInfo::type_path)
+    );
+    check!(
+        value,
+        kind == dynamic.reflect_kind(),
+        "`clone_value` returned a {}, but the value is a {kind}",
+        dynamic.reflect_kind()
+    );
+
+    let reflexive = value.reflect_partial_eq(&other);
+    check!(
+        value,
+        reflexive != Some(false),
+        "`reflect_partial_eq` returned false for two values returned by `make`"
+    );
+    let forward = value.reflect_partial_eq(dynamic.as_ref());
+    let backward = dynamic.reflect_partial_eq(&value);
+    check!(
+        value,
+        forward == backward,
+        "`reflect_partial_eq` is not symmetric with `clone_value`: \
+         comparing the value to its clone returned {forward:?}, \
+         but comparing the clone to the value returned {backward:?}"
+    );
+    check!(
+        value,
+        forward != Some(false),
+        "`reflect_partial_eq` returned false when comparing the value to its `clone_value`"
+    );
+
+    let hash = value.reflect_hash();
+    check!(
+        value,
+        hash == other.reflect_hash(),
+        "`reflect_hash` returned different hashes for two values returned by `make`"
+    );
+    if let (Some(hash), Some(dynamic_hash)) = (hash, dynamic.reflect_hash()) {
+        check!(
+            value,
+            hash == dynamic_hash,
+            "`reflect_hash` returned a different hash for the value and its `clone_value`"
+        );
+    }
+
+    other.apply(dynamic.as_ref());
+    check!(
+        value,
+        other.reflect_partial_eq(&value) != Some(false),
+        "applying the value's `clone_value` changed its value"
+    );
+    other.apply(dynamic.as_ref());
+    check!(
+        value,
+        other.reflect_partial_eq(&value) != Some(false),
+        "applying the value's `clone_value` a second time changed its value"
+    );
+}
+
+/// Asserts that the given value's [`FromReflect`] implementation inverts [`Reflect::clone_value`]
+/// and agrees with its [`PartialEq`] implementation.
+pub fn assert_from_reflect_contract<T: FromReflect + PartialEq + Debug>(make: impl Fn() -> T) {
+    let value = make();
+
+    let from_concrete = T::from_reflect(&value);
+    check!(
+        value,
+        from_concrete.as_ref() == Some(&value),
+        "`from_reflect` of the value returned {from_concrete:?}"
+    );
+
+    let dynamic = value.clone_value();
+    let from_dynamic = T::from_reflect(dynamic.as_ref());
+    check!(
+        value,
+        from_dynamic.as_ref() == Some(&value),
+        "`from_reflect` of the value's `clone_value` returned {from_dynamic:?}"
+    );
+
+    let mut other = make();
+    other.apply(dynamic.as_ref());
+    check!(
+        value,
+        other == value,
+        "applying the value's `clone_value` produced {other:?}"
+    );
+}
+
+/// Asserts that the given value upholds the contracts of [`List`]
+/// in addition to those checked by [`assert_reflect_contract`].
+///
+/// This checks that:
+/// * [`List::len`] agrees with [`List::iter`], [`List::get`], and [`List::drain`].
+/// * [`List::clone_dynamic`] contains equal elements in the same order.
+/// * [`List::push`] followed by [`List::pop`] restores the original list.
+/// * [`List::apply`] restores elements that were removed from the end of the list.
+pub fn assert_list_contract<T: List>(make: impl Fn() -> T) {
+    assert_reflect_contract(&make);
+
+    let value = make();
+    let len = value.len();
+    check!(
+        value,
+        len == value.iter().count(),
+        "`len` returned {len}, but `iter` yielded {} elements",
+        value.iter().count()
+    );
+    check!(
+        value,
+        value.iter().len() == len,
+        "`iter` reported {} remaining elements, but `len` returned {len}",
+        value.iter().len()
+    );
+    for (index, element) in value.iter().enumerate() {
+        let got = value.get(index);
+        check!(
+            value,
+            got.is_some_and(|got| got.reflect_partial_eq(element) != Some(false)),
+            "`get({index})` disagrees with the element yielded by `iter`"
+        );
+    }
+    check!(
+        value,
+        value.get(len).is_none(),
+        "`get({len})` returned an element past the end of the list"
+    );
+
+    let dynamic = value.clone_dynamic();
+    check!(
+        value,
+        dynamic.len() == len,
+        "`clone_dynamic` returned a list of length {}, but `len` returned {len}",
+        dynamic.len()
+    );
+    for (index, (element, cloned)) in value.iter().zip(dynamic.iter()).enumerate() {
+        check!(
+            value,
+            element.reflect_partial_eq(cloned) != Some(false),
+            "element {index} of `clone_dynamic` is not equal to the original"
+        );
+    }
+
+    let mut pushed = make();
+    if let Some(first) = value.get(0) {
+        pushed.push(first.clone_value());
+        check!(
+            value,
+            pushed.len() == len + 1,
+            "`push` did not increase `len` by one"
+        );
+        check!(
+            value,
+            pushed.pop().is_some() && pushed.len() == len,
+            "`pop` after `push` did not restore the original length"
+        );
+        check!(
+            value,
+            pushed.reflect_partial_eq(&value) != Some(false),
+            "`pop` after `push` did not restore the original list"
+        );
+
+        let mut shortened = make();
+        shortened.pop();
+        shortened.apply(&value);
+        check!(
+            value,
+            shortened.len() == len && shortened.reflect_partial_eq(&value) != Some(false),
+            "`apply` did not restore an element removed by `pop`"
+        );
+    }
+
+    let drained = Box::new(make()).drain();
+    check!(
+        value,
+        drained.len() == len,
+        "`drain` returned {} elements, but `len` returned {len}",
+        drained.len()
+    );
+}
+
+/// Asserts that the given value upholds the contracts of [`Map`]
+/// in addition to those checked by [`assert_reflect_contract`].
+///
+/// This checks that:
+/// * [`Map::len`] agrees with [`Map::iter`], [`Map::get_at`], and [`Map::drain`].
+/// * [`Map::get`] finds every entry yielded by [`Map::iter`].
+/// * [`Map::clone_dynamic`] contains equal entries.
+/// * [`Map::remove`] followed by [`Map::insert_boxed`] restores the original map.
+/// * [`Map::apply`] restores entries that were removed.
+pub fn assert_map_contract<T: Map>(make: impl Fn() -> T) {
+    assert_reflect_contract(&make);
+
+    let value = make();
+    let len = value.len();
+    check!(
+        value,
+        len == value.iter().count(),
+        "`len` returned {len}, but `iter` yielded {} entries",
+        value.iter().count()
+    );
+    check!(
+        value,
+        value.iter().len() == len,
+        "`iter` reported {} remaining entries, but `len` returned {len}",
+        value.iter().len()
+    );
+    for (index, (key, entry)) in value.iter().enumerate() {
+        let at = value.get_at(index);
+        check!(
+            value,
+            at.is_some_and(|(at_key, at_entry)| {
+                at_key.reflect_partial_eq(key) != Some(false)
+                    && at_entry.reflect_partial_eq(entry) != Some(false)
+            }),
+            "`get_at({index})` disagrees with the entry yielded by `iter`"
+        );
+        check!(
+            value,
+            value
+                .get(key)
+                .is_some_and(|got| got.reflect_partial_eq(entry) != Some(false)),
+            "`get` did not find the entry for key {key:?} yielded by `iter`"
+        );
+    }
+    check!(
+        value,
+        value.get_at(len).is_none(),
+        "`get_at({len})` returned an entry past the end of the map"
+    );
+
+    let dynamic = value.clone_dynamic();
+    check!(
+        value,
+        dynamic.len() == len,
+        "`clone_dynamic` returned a map of length {}, but `len` returned {len}",
+        dynamic.len()
+    );
+    for (key, entry) in value.iter() {
+        check!(
+            value,
+            dynamic
+                .get(key)
+                .is_some_and(|got| got.reflect_partial_eq(entry) != Some(false)),
+            "`clone_dynamic` is missing the entry for key {key:?}"
+        );
+    }
+
+    if let Some((key, _)) = value.get_at(0) {
+        let mut removed = make();
+        let entry = removed.remove(key);
+        check!(
+            value,
+            entry.is_some() && removed.len() == len - 1,
+            "`remove` of key {key:?} did not remove exactly one entry"
+        );
+        check!(
+            value,
+            removed.get(key).is_none(),
+            "`get` found key {key:?} after it was removed"
+        );
+
+        let previous = removed.insert_boxed(key.clone_value(), entry.unwrap());
+        check!(
+            value,
+            previous.is_none() && removed.len() == len,
+            "`insert_boxed` after `remove` did not restore the original length"
+        );
+        check!(
+            value,
+            removed.reflect_partial_eq(&value) != Some(false),
+            "`insert_boxed` after `remove` did not restore the original map"
+        );
+
+        let mut patched = make();
+        patched.remove(key);
+        patched.apply(&value);
+        check!(
+            value,
+            patched.len() == len && patched.reflect_partial_eq(&value) != Some(false),
+            "`apply` did not restore the entry for key {key:?} removed by `remove`"
+        );
+    }
+
+    let drained = Box::new(make()).drain();
+    check!(
+        value,
+        drained.len() == len,
+        "`drain` returned {} entries, but `len` returned {len}",
+        drained.len()
+    );
+}
+
+/// Asserts that the given value upholds the contracts of [`Struct`]
+/// in addition to those checked by [`assert_reflect_contract`].
+///
+/// This checks that:
+/// * [`Struct::field_len`] agrees with [`Struct::iter_fields`].
+/// * [`Struct::field`], [`Struct::field_at`], and [`Struct::name_at`] agree with each other.
+/// * [`Struct::clone_dynamic`] contains equal fields with the same names.
+pub fn assert_struct_contract<T: Struct>(make: impl Fn() -> T) {
+    assert_reflect_contract(&make);
+
+    let value = make();
+    let len = value.field_len();
+    check!(
+        value,
+        len == value.iter_fields().count(),
+        "`field_len` returned {len}, but `iter_fields` yielded {} fields",
+        value.iter_fields().count()
+    );
+
+    let dynamic = value.clone_dynamic();
+    check!(
+        value,
+        dynamic.field_len() == len,
+        "`clone_dynamic` returned a struct with {} fields, but `field_len` returned {len}",
+        dynamic.field_len()
+    );
+
+    for (index, field) in value.iter_fields().enumerate() {
+        let Some(name) = value.name_at(index) else {
+            check!(value, false, "`name_at({index})` returned `None`");
+            continue;
+        };
+        check!(
+            value,
+            value
+                .field_at(index)
+                .is_some_and(|at| at.reflect_partial_eq(field) != Some(false)),
+            "`field_at({index})` disagrees with the field yielded by `iter_fields`"
+        );
+        check!(
+            value,
+            value
+                .field(name)
+                .is_some_and(|named| named.reflect_partial_eq(field) != Some(false)),
+            "`field({name:?})` disagrees with `field_at({index})`"
+        );
+        check!(
+            value,
+            dynamic
+                .field(name)
+                .is_some_and(|cloned| cloned.reflect_partial_eq(field) != Some(false)),
+            "`clone_dynamic` is missing or changed the field {name:?}"
+        );
+    }
+    check!(
+        value,
+        value.field_at(len).is_none() && value.name_at(len).is_none(),
+        "`field_at({len})` or `name_at({len})` returned a field past the end of the struct"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{
+        list_partial_eq, list_try_apply, ApplyError, DynamicList, DynamicMap, DynamicStruct,
+        ListIter, ReflectMut, ReflectOwned, ReflectRef, TypePath,
+    };
+    use bevy_utils::HashMap;
+    use std::any::Any;
+    use std::collections::VecDeque;
+
+    fn map() -> HashMap<u32, String> {
+        [(1, "one".to_string()), (2, "two".to_string())]
+            .into_iter()
+            .collect()
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        level: u32,
+        items: Vec<String>,
+    }
+
+    fn player() -> Player {
+        Player {
+            name: "Ferris".to_string(),
+            level: 3,
+            items: vec!["sword".to_string(), "shield".to_string()],
+        }
+    }
+
+    #[test]
+    fn should_pass_for_lists() {
+        assert_list_contract(|| vec![1, 2, 3]);
+        assert_from_reflect_contract(|| vec![1, 2, 3]);
+        assert_list_contract(|| VecDeque::from([1.0, 2.0]));
+        assert_from_reflect_contract(|| VecDeque::from([1.0, 2.0]));
+        assert_list_contract(|| vec![1, 2, 3].clone_dynamic());
+        assert_list_contract(DynamicList::default);
+    }
+
+    #[test]
+    #[cfg(feature = "smallvec")]
+    fn should_pass_for_smallvec() {
+        assert_list_contract(|| smallvec::SmallVec::<[u32; 2]>::from_vec(vec![1, 2, 3]));
+        assert_from_reflect_contract(|| smallvec::SmallVec::<[u32; 2]>::from_vec(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn should_pass_for_maps() {
+        assert_map_contract(map);
+        assert_from_reflect_contract(map);
+        assert_map_contract(|| map().clone_dynamic());
+        assert_map_contract(DynamicMap::default);
+    }
+
+    #[test]
+    fn should_pass_for_structs() {
+        assert_struct_contract(player);
+        assert_from_reflect_contract(player);
+        assert_struct_contract(|| player().clone_dynamic());
+        assert_struct_contract(DynamicStruct::default);
+    }
+
+    /// A deliberately broken list whose `get` is off by one.
+    #[derive(TypePath)]
+    struct Shifted(Vec<u32>);
+
+    impl List for Shifted {
+        fn get(&self, index: usize) -> Option<&dyn Reflect> {
+            self.0.get(index + 1).map(|value| value as &dyn Reflect)
+        }
+
+        fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+            self.0
+                .get_mut(index + 1)
+                .map(|value| value as &mut dyn Reflect)
+        }
+
+        fn insert(&mut self, index: usize, element: Box<dyn Reflect>) {
+            self.0.insert(index, element.take().unwrap());
+        }
+
+        fn remove(&mut self, index: usize) -> Box<dyn Reflect> {
+            Box::new(self.0.remove(index))
+        }
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn iter(&self) -> ListIter<'_> {
+            ListIter::new(self)
+        }
+
+        fn drain(self: Box<Self>) -> Vec<Box<dyn Reflect>> {
+            self.0
+                .into_iter()
+                .map(|value| Box::new(value) as Box<dyn Reflect>)
+                .collect()
+        }
+    }
+
+    impl Reflect for Shifted {
+        fn get_represented_type_info(&self) -> Option<&'static TypeInfo> {
+            None
+        }
+
+        fn into_any(self: Box<Self>) -> Box<dyn Any> {
+            self
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+
+        fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+            self
+        }
+
+        fn as_reflect(&self) -> &dyn Reflect {
+            self
+        }
+
+        fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+            self
+        }
+
+        fn try_apply(&mut self, value: &dyn Reflect) -> Result<(), ApplyError> {
+            list_try_apply(self, value)
+        }
+
+        fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+            *self = value.take()?;
+            Ok(())
+        }
+
+        fn reflect_ref(&self) -> ReflectRef<'_> {
+            ReflectRef::List(self)
+        }
+
+        fn reflect_mut(&mut self) -> ReflectMut<'_> {
+            ReflectMut::List(self)
+        }
+
+        fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+            ReflectOwned::List(self)
+        }
+
+        fn clone_value(&self) -> Box<dyn Reflect> {
+            Box::new(self.clone_dynamic())
+        }
+
+        fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
+            list_partial_eq(self, value)
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "`bevy_reflect::test_suite::tests::Shifted` violates the reflection contract: \
+                    `reflect_partial_eq` returned false when comparing the value to its `clone_value`"
+    )]
+    fn should_catch_violation() {
+        assert_list_contract(|| Shifted(vec![1, 2, 3]));
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{FromReflect, FromType, Reflect, TypePath};
+use std::any::TypeId;
+
+/// A reflected value type that wraps a single inner value.
+///
+/// This trait is automatically implemented when using `#[reflect_value(transparent)]`
+/// on a single-field tuple struct.
+///
+/// Such types are reflected as [values] with their own type identity,
+/// but are serialized as their inner value and can be created from it via [`FromReflect`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{FromReflect, Reflect, TransparentValue};
+/// #[derive(Reflect, Clone, Debug, PartialEq)]
+/// #[reflect_value(transparent)]
+/// struct Meters(f32);
+///
+/// assert_eq!(Meters(1.5).inner(), &1.5);
+/// assert_eq!(Meters::from_reflect(&1.5_f32), Some(Meters(1.5)));
+/// ```
+///
+/// [values]: crate::ReflectRef::Value
+pub trait TransparentValue: Reflect {
+    /// The type of the wrapped value.
+    type Inner: FromReflect + TypePath;
+
+    /// Returns a reference to the wrapped value.
+    fn inner(&self) -> &Self::Inner;
+
+    /// Creates the wrapper from its inner value.
+    fn from_inner(inner: Self::Inner) -> Self;
+}
+
+/// Type data for [`TransparentValue`] types.
+///
+/// This allows tooling and the reflection serializers to access the inner value
+/// of a transparent wrapper without knowing its concrete type.
+///
+/// A [`ReflectTransparent`] for type `T` can be obtained via [`FromType::from_type`].
+#[derive(Clone)]
+pub struct ReflectTransparent {
+    inner_type_id: TypeId,
+    inner_type_path: &'static str,
+    get_inner: fn(&dyn Reflect) -> Option<&dyn Reflect>,
+    from_inner: fn(&dyn Reflect) -> Option<Box<dyn Reflect>>,
+}
+
+impl ReflectTransparent {
+    /// The [`TypeId`] of the wrapped type.
+    pub fn inner_type_id(&self) -> TypeId {
+        self.inner_type_id
+    }
+
+    /// The [type path] of the wrapped type.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn inner_type_path(&self) -> &'static str {
+        self.inner_type_path
+    }
+
+    /// Returns the inner value of the given wrapper.
+    ///
+    /// Returns `None` if `value` is not of the type this data was created for.
+    pub fn inner<'a>(&self, value: &'a dyn Reflect) -> Option<&'a dyn Reflect> {
+        (self.get_inner)(value)
+    }
+
+    /// Creates the wrapper from the given inner value.
+    ///
+    /// Returns `None` if the inner type could not be created from `inner` via [`FromReflect`].
+    pub fn from_inner(&self, inner: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        (self.from_inner)(inner)
+    }
+}
+
+impl<T: TransparentValue> FromType<T> for ReflectTransparent {
+    fn from_type() -> Self {
+        ReflectTransparent {
+            inner_type_id: TypeId::of::<T::Inner>(),
+            inner_type_path: T::Inner::type_path(),
+            get_inner: |value| {
+                value
+                    .downcast_ref::<T>()
+                    .map(|value| value.inner() as &dyn Reflect)
+            },
+            from_inner: |inner| {
+                T::Inner::from_reflect(inner)
+                    .map(|inner| Box::new(T::from_inner(inner)) as Box<dyn Reflect>)
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+    use crate::{TypeInfo, TypeRegistry, Typed};
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    #[reflect_value(transparent)]
+    struct Meters(f32);
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Meters>();
+        registry
+    }
+
+    #[test]
+    fn should_reflect_as_value() {
+        assert!(matches!(Meters::type_info(), TypeInfo::Value(_)));
+        assert_eq!(Meters(1.5).inner(), &1.5);
+    }
+
+    #[test]
+    fn should_serialize_as_inner_value() {
+        let registry = get_registry();
+
+        let value = Meters(1.5);
+        let serializer = TypedReflectSerializer::new(&value, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!("1.5", output);
+
+        let registration = registry.get(TypeId::of::<Meters>()).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let output = TypedReflectDeserializer::new(registration, &registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(Some(&value), output.downcast_ref::<Meters>());
+    }
+
+    #[test]
+    fn should_create_from_inner_or_wrapper() {
+        assert_eq!(Some(Meters(1.5)), Meters::from_reflect(&1.5_f32));
+        assert_eq!(Some(Meters(1.5)), Meters::from_reflect(&Meters(1.5)));
+        assert_eq!(None, Meters::from_reflect(&1.5_f64));
+    }
+
+    #[test]
+    fn should_compare_against_both_forms() {
+        let value = Meters(1.5);
+        assert_eq!(Some(true), value.reflect_partial_eq(&Meters(1.5)));
+        assert_eq!(Some(false), value.reflect_partial_eq(&Meters(2.0)));
+        assert_eq!(Some(true), value.reflect_partial_eq(&1.5_f32));
+        assert_eq!(Some(false), value.reflect_partial_eq(&2.0_f32));
+    }
+
+    #[test]
+    fn should_apply_inner_value() {
+        let mut value = Meters(1.5);
+        value.apply(&2.0_f32);
+        assert_eq!(Meters(2.0), value);
+    }
+
+    #[test]
+    fn should_register_inner_type() {
+        let registry = get_registry();
+
+        let transparent = registry
+            .get_type_data::<ReflectTransparent>(TypeId::of::<Meters>())
+            .unwrap();
+        assert_eq!(TypeId::of::<f32>(), transparent.inner_type_id());
+        assert_eq!("f32", transparent.inner_type_path());
+        assert_eq!(
+            Some(&1.5_f32),
+            transparent
+                .inner(&Meters(1.5))
+                .and_then(|inner| inner.downcast_ref::<f32>()),
+        );
+
+        // The inner type should be registered as a dependency
+        assert!(registry.contains(TypeId::of::<f32>()));
+    }
+}
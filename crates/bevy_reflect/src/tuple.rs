@@ -8,6 +8,7 @@ use crate::{
 };
 use crate::{ReflectKind, TypePathTable};
 use std::any::{Any, TypeId};
+use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter};
 use std::slice::Iter;
 
@@ -218,6 +219,34 @@ impl TupleInfo {
     }
 }
 
+/// Creates a [`DynamicTuple`] from a list of values.
+///
+/// Values can be any expression implementing [`Reflect`], including nested dynamic values.
+/// Since each field may have a different type,
+/// integer literals without a suffix are inferred as `i32`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{dyn_tuple, Reflect, Tuple};
+/// let tuple = dyn_tuple!(2i32, String::from("hello"), 1.5f32);
+/// assert_eq!(
+///     Some(true),
+///     tuple.reflect_partial_eq(&(2i32, String::from("hello"), 1.5f32))
+/// );
+/// ```
+#[macro_export]
+macro_rules! dyn_tuple {
+    () => {
+        $crate::DynamicTuple::default()
+    };
+    ($($value:expr),+ $(,)?) => {{
+        let mut dynamic_tuple = $crate::DynamicTuple::default();
+        $(dynamic_tuple.insert($value);)+
+        dynamic_tuple
+    }};
+}
+
 /// A tuple which allows fields to be added at runtime.
 #[derive(Default, Debug)]
 pub struct DynamicTuple {
@@ -377,6 +406,10 @@ impl Reflect for DynamicTuple {
         tuple_partial_eq(self, value)
     }
 
+    fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+        tuple_partial_cmp(self, value)
+    }
+
     fn debug(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "DynamicTuple(")?;
         tuple_debug(self, f)?;
@@ -455,6 +488,33 @@ pub fn tuple_partial_eq<T: Tuple>(a: &T, b: &dyn Reflect) -> Option<bool> {
     Some(true)
 }
 
+/// Lexicographically compares a [`Tuple`] with a [`Reflect`] value.
+///
+/// Elements are compared pairwise with [`Reflect::reflect_partial_cmp`],
+/// returning the first result which is not [`Ordering::Equal`].
+///
+/// Returns [`None`] if `b` is not a tuple with the same number of elements as `a`,
+/// or if any pair of elements could not be compared.
+#[inline]
+pub fn tuple_partial_cmp<T: Tuple>(a: &T, b: &dyn Reflect) -> Option<Ordering> {
+    let ReflectRef::Tuple(b) = b.reflect_ref() else {
+        return None;
+    };
+
+    if a.field_len() != b.field_len() {
+        return None;
+    }
+
+    for (a_field, b_field) in a.iter_fields().zip(b.iter_fields()) {
+        match a_field.reflect_partial_cmp(b_field)? {
+            Ordering::Equal => {}
+            ordering => return Some(ordering),
+        }
+    }
+
+    Some(Ordering::Equal)
+}
+
 /// The default debug formatter for [`Tuple`] types.
 ///
 /// # Example
@@ -599,6 +659,10 @@ macro_rules! impl_reflect_tuple {
             fn reflect_partial_eq(&self, value: &dyn Reflect) -> Option<bool> {
                 crate::tuple_partial_eq(self, value)
             }
+
+            fn reflect_partial_cmp(&self, value: &dyn Reflect) -> Option<Ordering> {
+                crate::tuple_partial_cmp(self, value)
+            }
         }
 
         impl <$($name: Reflect + TypePath + GetTypeRegistration),*> Typed for ($($name,)*) {
@@ -1,6 +1,8 @@
+use bevy_ptr::Ptr;
 use bevy_reflect_derive::impl_type_path;
 
 use crate::attributes::{impl_custom_attribute_methods, CustomAttributes};
+use crate::fields::field_ptr;
 use crate::{
     self as bevy_reflect, ApplyError, DynamicTuple, Reflect, ReflectKind, ReflectMut, ReflectOwned,
     ReflectRef, Tuple, TypeInfo, TypePath, TypePathTable, UnnamedField,
@@ -48,6 +50,19 @@ pub trait TupleStruct: Reflect {
     /// Returns the number of fields in the tuple struct.
     fn field_len(&self) -> usize;
 
+    /// Returns a pointer to the field with index `index`, computed from the field's [offset].
+    ///
+    /// See [`Struct::field_ptr`](crate::Struct::field_ptr) for details.
+    ///
+    /// [offset]: UnnamedField::offset
+    fn field_ptr(&self, index: usize) -> Option<Ptr<'_>> {
+        let Some(TypeInfo::TupleStruct(info)) = self.get_represented_type_info() else {
+            return None;
+        };
+        let offset = info.field_at(index)?.offset()?;
+        field_ptr(self.as_any(), info.type_id(), offset)
+    }
+
     /// Returns an iterator over the values of the tuple struct's fields.
     fn iter_fields(&self) -> TupleStructFieldIter;
 
@@ -484,8 +499,19 @@ pub fn tuple_struct_debug(
             .map(|s| s.type_path())
             .unwrap_or("_"),
     );
-    for field in dyn_tuple_struct.iter_fields() {
-        debug.field(&field as &dyn Debug);
+    let tuple_struct_info = match dyn_tuple_struct.get_represented_type_info() {
+        Some(TypeInfo::TupleStruct(info)) => Some(info),
+        _ => None,
+    };
+    for (index, field) in dyn_tuple_struct.iter_fields().enumerate() {
+        if tuple_struct_info
+            .and_then(|info| info.field_at(index))
+            .is_some_and(UnnamedField::is_redacted)
+        {
+            debug.field(&format_args!("<redacted>"));
+        } else {
+            debug.field(&field as &dyn Debug);
+        }
     }
     debug.finish()
 }
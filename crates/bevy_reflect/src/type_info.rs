@@ -188,6 +188,7 @@ impl TypeInfo {
 pub struct ValueInfo {
     type_path: TypePathTable,
     type_id: TypeId,
+    opaque: bool,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -197,11 +198,31 @@ impl ValueInfo {
         Self {
             type_path: TypePathTable::of::<T>(),
             type_id: TypeId::of::<T>(),
+            opaque: false,
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Marks this value as opaque.
+    ///
+    /// This corresponds to the `#[reflect_value(opaque)]` attribute.
+    pub fn with_opaque(self) -> Self {
+        Self {
+            opaque: true,
+            ..self
+        }
+    }
+
+    /// Returns true if this value is opaque.
+    ///
+    /// Opaque values intentionally support only identity-level reflection:
+    /// they can be downcast and cloned, but cannot be hashed, compared, or serialized.
+    /// Operations which require any of these will fail with an error naming the type.
+    pub fn is_opaque(&self) -> bool {
+        self.opaque
+    }
+
     /// Sets the docstring for this value.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, doc: Option<&'static str>) -> Self {
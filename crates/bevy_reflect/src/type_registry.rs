@@ -1,13 +1,18 @@
-use crate::{serde::Serializable, FromReflect, Reflect, TypeInfo, TypePath, Typed};
+use crate::{
+    serde::Serializable, CloneError, FromReflect, Reflect, ReflectFromReflect, TypeInfo, TypePath,
+    Typed,
+};
 use bevy_ptr::{Ptr, PtrMut};
 use bevy_utils::{HashMap, HashSet, TypeIdMap};
 use downcast_rs::{impl_downcast, Downcast};
 use serde::Deserialize;
 use std::{
     any::TypeId,
-    fmt::Debug,
+    borrow::Cow,
+    fmt::{Debug, Display},
     sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
+use thiserror::Error;
 
 /// A registry of [reflected] types.
 ///
@@ -26,6 +31,10 @@ pub struct TypeRegistry {
     short_path_to_id: HashMap<&'static str, TypeId>,
     type_path_to_id: HashMap<&'static str, TypeId>,
     ambiguous_names: HashSet<&'static str>,
+    alias_to_id: HashMap<Box<str>, TypeId>,
+    id_to_aliases: TypeIdMap<Vec<Box<str>>>,
+    conflicts: Vec<RegistrationConflict>,
+    next_ordinal: usize,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -85,6 +94,10 @@ impl TypeRegistry {
             short_path_to_id: Default::default(),
             type_path_to_id: Default::default(),
             ambiguous_names: Default::default(),
+            alias_to_id: Default::default(),
+            id_to_aliases: Default::default(),
+            conflicts: Default::default(),
+            next_ordinal: 0,
         }
     }
 
@@ -182,7 +195,11 @@ impl TypeRegistry {
     ///
     /// This method will _not_ register type dependencies.
     /// Use [`register`](Self::register) to register a type with its dependencies.
-    pub fn overwrite_registration(&mut self, registration: TypeRegistration) {
+    pub fn overwrite_registration(&mut self, mut registration: TypeRegistration) {
+        Self::assign_ordinal(&mut registration, &mut self.next_ordinal);
+        if let Some(conflict) = Self::find_conflict(&registration, &self.type_path_to_id) {
+            self.record_conflict(conflict);
+        }
         Self::update_registration_indices(
             &registration,
             &mut self.short_path_to_id,
@@ -205,10 +222,12 @@ impl TypeRegistry {
         type_id: TypeId,
         get_registration: impl FnOnce() -> TypeRegistration,
     ) -> bool {
-        match self.registrations.entry(type_id) {
-            bevy_utils::Entry::Occupied(_) => false,
+        let conflict = match self.registrations.entry(type_id) {
+            bevy_utils::Entry::Occupied(_) => return false,
             bevy_utils::Entry::Vacant(entry) => {
-                let registration = get_registration();
+                let mut registration = get_registration();
+                Self::assign_ordinal(&mut registration, &mut self.next_ordinal);
+                let conflict = Self::find_conflict(&registration, &self.type_path_to_id);
                 Self::update_registration_indices(
                     &registration,
                     &mut self.short_path_to_id,
@@ -216,11 +235,52 @@ impl TypeRegistry {
                     &mut self.ambiguous_names,
                 );
                 entry.insert(registration);
-                true
+                conflict
             }
+        };
+
+        if let Some(conflict) = conflict {
+            self.record_conflict(conflict);
+        }
+        true
+    }
+
+    /// Internal method to assign the [`RegistrationSource`] ordinal of a new registration.
+    fn assign_ordinal(registration: &mut TypeRegistration, next_ordinal: &mut usize) {
+        if let Some(source) = &mut registration.source {
+            source.ordinal = Some(*next_ordinal);
+            *next_ordinal += 1;
         }
     }
 
+    /// Internal method to find a [conflict](RegistrationConflict) between a new registration
+    /// and an existing registration with the same type path.
+    ///
+    /// The source of the existing registration is filled in by [`Self::record_conflict`].
+    fn find_conflict(
+        registration: &TypeRegistration,
+        type_path_to_id: &HashMap<&'static str, TypeId>,
+    ) -> Option<RegistrationConflict> {
+        let type_path = registration.type_info().type_path();
+        let existing_id = *type_path_to_id.get(type_path)?;
+        (existing_id != registration.type_id()).then(|| RegistrationConflict {
+            type_path,
+            existing_type_id: existing_id,
+            existing_source: None,
+            new_type_id: registration.type_id(),
+            new_source: registration.source.clone(),
+        })
+    }
+
+    /// Internal method to record a conflict found by [`Self::find_conflict`].
+    fn record_conflict(&mut self, mut conflict: RegistrationConflict) {
+        conflict.existing_source = self
+            .registrations
+            .get(&conflict.existing_type_id)
+            .and_then(|existing| existing.source.clone());
+        self.conflicts.push(conflict);
+    }
+
     /// Internal method to register additional lookups for a given [`TypeRegistration`].
     fn update_registration_indices(
         registration: &TypeRegistration,
@@ -239,6 +299,62 @@ impl TypeRegistry {
         type_path_to_id.insert(registration.type_info().type_path(), registration.type_id());
     }
 
+    /// Returns the [conflicts](RegistrationConflict) recorded when registering types
+    /// whose type path was already registered with a different [`TypeId`].
+    ///
+    /// When this happens, lookups by type path resolve to the most recent registration.
+    pub fn conflicts(&self) -> &[RegistrationConflict] {
+        &self.conflicts
+    }
+
+    /// Describes why no registration could be found for the given [`TypeId`] and type path.
+    ///
+    /// This is intended to be used in error messages.
+    /// If the type path is registered under a different [`TypeId`]
+    /// (such as when the same type is loaded from two separate libraries),
+    /// the message will mention the [sources](RegistrationSource) of the conflicting registrations.
+    pub fn describe_missing_registration(&self, type_id: TypeId, type_path: &str) -> String {
+        let mut message = format!("no registration found for type `{type_path}`");
+
+        let Some(registered_id) = self.type_path_to_id.get(type_path) else {
+            return message;
+        };
+
+        if *registered_id == type_id {
+            return message;
+        }
+
+        message.push_str(", but a type with the same path is registered with a different `TypeId`");
+
+        let conflicts = self
+            .conflicts
+            .iter()
+            .filter(|conflict| conflict.type_path == type_path)
+            .flat_map(|conflict| [&conflict.existing_source, &conflict.new_source])
+            .map(|source| match source {
+                Some(source) => source.to_string(),
+                None => String::from("<unknown source>"),
+            })
+            .collect::<Vec<_>>();
+
+        if conflicts.is_empty() {
+            let source = self
+                .registrations
+                .get(registered_id)
+                .and_then(TypeRegistration::source);
+            if let Some(source) = source {
+                message.push_str(&format!(" (registered by {source})"));
+            }
+        } else {
+            message.push_str(&format!(
+                " (conflicting registrations from {})",
+                conflicts.join(", ")
+            ));
+        }
+
+        message
+    }
+
     /// Registers the type data `D` for type `T`.
     ///
     /// Most of the time [`TypeRegistry::register`] can be used instead to register a type you derived [`Reflect`] for.
@@ -342,6 +458,147 @@ impl TypeRegistry {
             .and_then(|id| self.registrations.get_mut(id))
     }
 
+    /// Registers `alias` as an additional name for the type `T`.
+    ///
+    /// Rust type aliases are invisible to reflection, so types like `type Health = Stat<f32>`
+    /// are otherwise only known by the path of the underlying type.
+    /// Once registered, the alias is accepted by [`get_with_name`](Self::get_with_name)
+    /// and when deserializing with [`ReflectDeserializer`],
+    /// and is preferred by [`ReflectSerializer::with_compact_names`].
+    ///
+    /// Registering the same alias for the same type more than once has no effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `T` has not been registered,
+    /// or if the alias already names a different type, either as an alias, a [type path],
+    /// or a [short type path].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{Reflect, TypeRegistry};
+    /// #[derive(Reflect)]
+    /// struct Stat<T>(T);
+    ///
+    /// type Health = Stat<f32>;
+    ///
+    /// let mut type_registry = TypeRegistry::default();
+    /// type_registry.register::<Health>();
+    /// type_registry.register_alias::<Health>("Health").unwrap();
+    ///
+    /// let registration = type_registry.get_with_name("Health").unwrap();
+    /// assert_eq!(std::any::TypeId::of::<Stat<f32>>(), registration.type_id());
+    /// ```
+    ///
+    /// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
+    /// [`ReflectSerializer::with_compact_names`]: crate::serde::ReflectSerializer::with_compact_names
+    /// [type path]: TypePath::type_path
+    /// [short type path]: TypePath::short_type_path
+    pub fn register_alias<T: TypePath + 'static>(&mut self, alias: &str) -> Result<(), AliasError> {
+        let type_id = TypeId::of::<T>();
+        if !self.contains(type_id) {
+            return Err(AliasError::Unregistered {
+                type_path: T::type_path(),
+            });
+        }
+
+        // Aliases take precedence over short type paths, so they must not shadow those of other types
+        let existing_id = self
+            .alias_to_id
+            .get(alias)
+            .or_else(|| self.type_path_to_id.get(alias))
+            .or_else(|| self.short_path_to_id.get(alias))
+            .copied()
+            .or_else(|| {
+                if !self.ambiguous_names.contains(alias) {
+                    return None;
+                }
+                self.registrations
+                    .values()
+                    .map(TypeRegistration::type_id)
+                    .find(|id| {
+                        *id != type_id
+                            && self.registrations[id]
+                                .type_info()
+                                .type_path_table()
+                                .short_path()
+                                == alias
+                    })
+            });
+        match existing_id {
+            Some(existing_id) if existing_id == type_id => {}
+            Some(existing_id) => {
+                return Err(AliasError::Conflict {
+                    alias: alias.into(),
+                    existing_type_path: self.registrations[&existing_id].type_info().type_path(),
+                    new_type_path: T::type_path(),
+                });
+            }
+            None => {
+                self.alias_to_id.insert(alias.into(), type_id);
+                self.id_to_aliases
+                    .entry(type_id)
+                    .or_default()
+                    .push(alias.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a reference to the [`TypeRegistration`] of the type with the given alias.
+    ///
+    /// If no type has been registered with the given alias, returns `None`.
+    ///
+    /// See [`register_alias`](Self::register_alias) for more information on aliases.
+    pub fn get_with_alias(&self, alias: &str) -> Option<&TypeRegistration> {
+        self.alias_to_id
+            .get(alias)
+            .and_then(|id| self.registrations.get(id))
+    }
+
+    /// Returns the aliases registered for the type with the given [`TypeId`],
+    /// in the order they were registered.
+    pub fn aliases(&self, type_id: TypeId) -> impl Iterator<Item = &str> {
+        self.id_to_aliases
+            .get(&type_id)
+            .into_iter()
+            .flatten()
+            .map(AsRef::as_ref)
+    }
+
+    /// Returns a reference to the [`TypeRegistration`] of the type with the given name.
+    ///
+    /// The name is resolved as a [type path], then as an [alias].
+    /// Short type paths are not accepted, since they may become ambiguous as more types are registered.
+    ///
+    /// If no type matches the given name, returns `None`.
+    ///
+    /// [type path]: TypePath::type_path
+    /// [alias]: Self::register_alias
+    pub fn get_with_name(&self, name: &str) -> Option<&TypeRegistration> {
+        self.get_with_type_path(name)
+            .or_else(|| self.get_with_alias(name))
+    }
+
+    /// Returns the most compact name which [`get_with_name`](Self::get_with_name)
+    /// resolves to the type with the given [`TypeId`].
+    ///
+    /// This is the type's alias if it has exactly one, falling back to its full [type path].
+    ///
+    /// If the specified type has not been registered, returns `None`.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn compact_name(&self, type_id: TypeId) -> Option<&str> {
+        let type_path = self.get(type_id)?.type_info().type_path();
+
+        match self.id_to_aliases.get(&type_id).map(Vec::as_slice) {
+            Some([alias]) => Some(alias.as_ref()),
+            _ => Some(type_path),
+        }
+    }
+
     /// Returns `true` if the given [short type path] is ambiguous, that is, it matches multiple registered types.
     ///
     /// # Example
@@ -463,6 +720,7 @@ impl TypeRegistryArc {
 pub struct TypeRegistration {
     data: TypeIdMap<Box<dyn TypeData>>,
     type_info: &'static TypeInfo,
+    source: Option<RegistrationSource>,
 }
 
 impl Debug for TypeRegistration {
@@ -481,6 +739,25 @@ impl TypeRegistration {
         self.type_info.type_id()
     }
 
+    /// Clones the given value into an instance of this registration's type
+    /// using its [`ReflectFromReflect`] type data.
+    ///
+    /// See [`deep_clone_concrete`] for details.
+    ///
+    /// [`ReflectFromReflect`]: crate::ReflectFromReflect
+    /// [`deep_clone_concrete`]: crate::deep_clone_concrete
+    pub fn clone_concrete(&self, value: &dyn Reflect) -> Result<Box<dyn Reflect>, CloneError> {
+        let type_path = self.type_info.type_path();
+        self.data::<ReflectFromReflect>()
+            .ok_or_else(|| CloneError::MissingFromReflect {
+                type_path: type_path.into(),
+            })?
+            .from_reflect(value)
+            .ok_or_else(|| CloneError::FromReflectFailed {
+                type_path: type_path.into(),
+            })
+    }
+
     /// Returns a reference to the value of type `T` in this registration's type
     /// data.
     ///
@@ -513,11 +790,24 @@ impl TypeRegistration {
         self.data.insert(TypeId::of::<T>(), Box::new(data));
     }
 
+    /// Sets the [source](RegistrationSource) of this registration.
+    ///
+    /// The source's ordinal is assigned by the [`TypeRegistry`] when the registration is added.
+    pub fn set_source(&mut self, source: RegistrationSource) {
+        self.source = Some(source);
+    }
+
+    /// Returns the [source](RegistrationSource) of this registration, if one was set.
+    pub fn source(&self) -> Option<&RegistrationSource> {
+        self.source.as_ref()
+    }
+
     /// Creates type registration information for `T`.
     pub fn of<T: Reflect + Typed + TypePath>() -> Self {
         Self {
             data: Default::default(),
             type_info: T::type_info(),
+            source: None,
         }
     }
 }
@@ -532,10 +822,126 @@ impl Clone for TypeRegistration {
         TypeRegistration {
             data,
             type_info: self.type_info,
+            source: self.source.clone(),
         }
     }
 }
 
+/// Provenance information for a [`TypeRegistration`].
+///
+/// This can be used to track which crate or plugin registered a type,
+/// which helps diagnose [conflicting registrations](RegistrationConflict)
+/// when the same type is loaded from multiple dynamic libraries.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{RegistrationSource, TypeRegistration, TypeRegistry};
+/// let mut registration = TypeRegistration::of::<String>();
+/// registration.set_source(RegistrationSource::new("my_plugin"));
+///
+/// let mut registry = TypeRegistry::empty();
+/// registry.add_registration(registration);
+///
+/// let source = registry.get(std::any::TypeId::of::<String>()).unwrap().source().unwrap();
+/// assert_eq!("my_plugin", source.label());
+/// assert_eq!(Some(0), source.ordinal());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegistrationSource {
+    label: Cow<'static, str>,
+    ordinal: Option<usize>,
+}
+
+impl RegistrationSource {
+    /// Creates a new source with the given label, such as a crate or plugin name.
+    pub fn new(label: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            label: label.into(),
+            ordinal: None,
+        }
+    }
+
+    /// Returns the label of this source.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the order in which the registration was added to its [`TypeRegistry`].
+    ///
+    /// Ordinals increase monotonically across all sourced registrations within a registry.
+    /// Returns `None` if the registration has not been added to a registry.
+    pub fn ordinal(&self) -> Option<usize> {
+        self.ordinal
+    }
+}
+
+impl Display for RegistrationSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}`", self.label)?;
+        if let Some(ordinal) = self.ordinal {
+            write!(f, " (#{ordinal})")?;
+        }
+        Ok(())
+    }
+}
+
+/// A conflict between two [registrations](TypeRegistration) sharing a type path
+/// but with different [`TypeId`]s.
+///
+/// These are recorded by the [`TypeRegistry`] and can be retrieved with [`TypeRegistry::conflicts`].
+#[derive(Clone, Debug)]
+pub struct RegistrationConflict {
+    type_path: &'static str,
+    existing_type_id: TypeId,
+    existing_source: Option<RegistrationSource>,
+    new_type_id: TypeId,
+    new_source: Option<RegistrationSource>,
+}
+
+impl RegistrationConflict {
+    /// The type path shared by both registrations.
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
+    /// The [`TypeId`] of the registration that was already present.
+    pub fn existing_type_id(&self) -> TypeId {
+        self.existing_type_id
+    }
+
+    /// The [source](RegistrationSource) of the registration that was already present.
+    pub fn existing_source(&self) -> Option<&RegistrationSource> {
+        self.existing_source.as_ref()
+    }
+
+    /// The [`TypeId`] of the newly added registration.
+    pub fn new_type_id(&self) -> TypeId {
+        self.new_type_id
+    }
+
+    /// The [source](RegistrationSource) of the newly added registration.
+    pub fn new_source(&self) -> Option<&RegistrationSource> {
+        self.new_source.as_ref()
+    }
+}
+
+/// An error returned by [`TypeRegistry::register_alias`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AliasError {
+    #[error("cannot alias `{type_path}` because it has not been registered")]
+    /// The aliased type has not been registered.
+    Unregistered { type_path: &'static str },
+
+    #[error("alias `{alias}` for `{new_type_path}` already names `{existing_type_path}`")]
+    /// The alias already names a different type.
+    Conflict {
+        alias: Box<str>,
+        existing_type_path: &'static str,
+        new_type_path: &'static str,
+    },
+}
+
 /// A trait used to type-erase type metadata.
 ///
 /// Type data can be registered to the [`TypeRegistry`] and stored on a type's [`TypeRegistration`].
@@ -750,8 +1156,10 @@ impl<T: Reflect> FromType<T> for ReflectFromPtr {
 #[cfg(test)]
 #[allow(unsafe_code)]
 mod test {
-    use crate::{GetTypeRegistration, ReflectFromPtr};
+    use super::{AliasError, RegistrationSource, TypeRegistration, TypeRegistry};
+    use crate::{GetTypeRegistration, ReflectFromPtr, TypePath};
     use bevy_ptr::{Ptr, PtrMut};
+    use std::any::TypeId;
 
     use crate as bevy_reflect;
     use crate::Reflect;
@@ -796,4 +1204,153 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn should_track_registration_sources_and_conflicts() {
+        mod plugin_a {
+            use crate as bevy_reflect;
+            use crate::Reflect;
+
+            #[derive(Reflect)]
+            #[type_path = "shared"]
+            pub struct Health(pub u32);
+        }
+
+        mod plugin_b {
+            use crate as bevy_reflect;
+            use crate::Reflect;
+
+            #[derive(Reflect)]
+            #[type_path = "shared"]
+            pub struct Health(pub u32);
+        }
+
+        let mut registry = TypeRegistry::empty();
+        assert!(registry.add_registration(TypeRegistration::of::<u32>()));
+
+        let mut registration = plugin_a::Health::get_type_registration();
+        registration.set_source(RegistrationSource::new("plugin_a"));
+        assert!(registry.add_registration(registration));
+
+        let mut registration = plugin_b::Health::get_type_registration();
+        registration.set_source(RegistrationSource::new("plugin_b"));
+        assert!(registry.add_registration(registration));
+
+        // Source metadata
+        let registration = registry.get(TypeId::of::<plugin_a::Health>()).unwrap();
+        let source = registration.source().unwrap();
+        assert_eq!("plugin_a", source.label());
+        assert_eq!(Some(0), source.ordinal());
+        assert!(registry
+            .get(TypeId::of::<u32>())
+            .unwrap()
+            .source()
+            .is_none());
+
+        let source = registry
+            .get(TypeId::of::<plugin_b::Health>())
+            .unwrap()
+            .source()
+            .unwrap();
+        assert_eq!(Some(1), source.ordinal());
+
+        // Conflict detection
+        let [conflict] = registry.conflicts() else {
+            panic!("expected exactly one conflict");
+        };
+        assert_eq!("shared::Health", conflict.type_path());
+        assert_eq!(
+            TypeId::of::<plugin_a::Health>(),
+            conflict.existing_type_id()
+        );
+        assert_eq!(TypeId::of::<plugin_b::Health>(), conflict.new_type_id());
+        assert_eq!("plugin_a", conflict.existing_source().unwrap().label());
+        assert_eq!("plugin_b", conflict.new_source().unwrap().label());
+
+        // Re-registering the same type is not a conflict
+        registry.add_registration(plugin_a::Health::get_type_registration());
+        assert_eq!(1, registry.conflicts().len());
+
+        // Error messages mention both sources
+        let path = plugin_a::Health::type_path();
+        let message =
+            registry.describe_missing_registration(TypeId::of::<plugin_a::Health>(), path);
+        assert!(message.contains("different `TypeId`"), "{message}");
+        assert!(message.contains("`plugin_a` (#0)"), "{message}");
+        assert!(message.contains("`plugin_b` (#1)"), "{message}");
+
+        let message = registry.describe_missing_registration(TypeId::of::<u64>(), "u64");
+        assert_eq!("no registration found for type `u64`", message);
+    }
+
+    #[test]
+    fn should_register_aliases() {
+        #[derive(Reflect)]
+        struct Stat<T>(T);
+
+        type Health = Stat<f32>;
+        type Mana = Stat<u32>;
+
+        let mut registry = TypeRegistry::new();
+        registry.register::<Health>();
+        registry.register::<Mana>();
+
+        assert_eq!(Ok(()), registry.register_alias::<Health>("Health"));
+        assert_eq!(Ok(()), registry.register_alias::<Health>("Health"));
+        assert_eq!(Ok(()), registry.register_alias::<Mana>("Mana"));
+
+        let registration = registry.get_with_name("Health").unwrap();
+        assert_eq!(TypeId::of::<Health>(), registration.type_id());
+        let registration = registry.get_with_alias("Mana").unwrap();
+        assert_eq!(TypeId::of::<Mana>(), registration.type_id());
+        assert_eq!(
+            vec!["Health"],
+            registry.aliases(TypeId::of::<Health>()).collect::<Vec<_>>()
+        );
+
+        // Compact names prefer a single alias
+        assert_eq!(
+            Some("Health"),
+            registry.compact_name(TypeId::of::<Health>())
+        );
+        assert_eq!(Ok(()), registry.register_alias::<Health>("Hp"));
+        assert_eq!(
+            Some(Health::type_path()),
+            registry.compact_name(TypeId::of::<Health>())
+        );
+        assert_eq!(Some("u32"), registry.compact_name(TypeId::of::<u32>()));
+
+        // Conflicts
+        assert_eq!(
+            Err(AliasError::Conflict {
+                alias: "Health".into(),
+                existing_type_path: Health::type_path(),
+                new_type_path: Mana::type_path(),
+            }),
+            registry.register_alias::<Mana>("Health")
+        );
+        assert!(matches!(
+            registry.register_alias::<Mana>("f32"),
+            Err(AliasError::Conflict { .. })
+        ));
+        assert_eq!(
+            Err(AliasError::Conflict {
+                alias: "Stat<f32>".into(),
+                existing_type_path: Health::type_path(),
+                new_type_path: Mana::type_path(),
+            }),
+            registry.register_alias::<Mana>("Stat<f32>")
+        );
+        assert_eq!(Ok(()), registry.register_alias::<Mana>("Stat<u32>"));
+
+        // Short type paths are not accepted as names
+        assert!(registry.get_with_name("Stat<f32>").is_none());
+        assert_eq!(
+            Err(AliasError::Unregistered {
+                type_path: Stat::<bool>::type_path(),
+            }),
+            registry.register_alias::<Stat<bool>>("Flag")
+        );
+        assert!(registry.get_with_name("Flag").is_none());
+    }
 }
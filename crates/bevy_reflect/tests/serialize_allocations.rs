@@ -0,0 +1,168 @@
+//! Checks how many allocations the reflection serializers perform.
+//!
+//! This lives in its own test binary so that it can install a counting global allocator.
+
+use bevy_reflect::serde::{
+    ReflectDeserializer, ReflectSerializer, SerializerScratch, TypedReflectSerializer,
+};
+use bevy_reflect::{FromReflect, Reflect, TypeRegistry};
+use serde::de::DeserializeSeed;
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::VecDeque;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: All allocation is delegated to the system allocator.
+#[allow(unsafe_code)]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        // SAFETY: The caller upholds the contract of `GlobalAlloc::alloc`.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: `ptr` was allocated by `System` with this layout.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        // SAFETY: `ptr` was allocated by `System` with this layout,
+        // and the caller upholds the contract of `GlobalAlloc::realloc`.
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the number of allocations made on the current thread while running `f`.
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let start = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - start
+}
+
+#[derive(Reflect, Debug, PartialEq)]
+struct Scene {
+    name: String,
+    entities: Vec<Entity>,
+    tags: Vec<u32>,
+    payload: VecDeque<u8>,
+}
+
+#[derive(Reflect, Debug, PartialEq)]
+struct Entity {
+    id: u64,
+    position: (f32, f32, f32),
+    parent: Option<u64>,
+    shape: Shape,
+}
+
+#[derive(Reflect, Debug, PartialEq)]
+enum Shape {
+    Point,
+    Circle(f32),
+    Rect { width: f32, height: f32 },
+}
+
+fn scene() -> Scene {
+    Scene {
+        name: "level_1".to_string(),
+        entities: (0..32)
+            .map(|i| Entity {
+                id: i,
+                position: (i as f32, 0.0, -(i as f32)),
+                parent: i.checked_sub(1),
+                shape: match i % 3 {
+                    0 => Shape::Point,
+                    1 => Shape::Circle(i as f32),
+                    _ => Shape::Rect {
+                        width: 1.0,
+                        height: 2.0,
+                    },
+                },
+            })
+            .collect(),
+        tags: vec![1, 2, 3],
+        payload: (0..=255).collect(),
+    }
+}
+
+fn registry() -> TypeRegistry {
+    let mut registry = TypeRegistry::default();
+    registry.register::<Scene>();
+    registry
+}
+
+/// Serializes into a buffer that is large enough to never reallocate.
+fn serialize_into(output: &mut Vec<u8>, value: &impl Serialize) {
+    output.clear();
+    serde_json::to_writer(output, value).unwrap();
+}
+
+#[test]
+fn should_not_allocate_while_serializing() {
+    let registry = registry();
+    let value = scene();
+    let scratch = SerializerScratch::with_capacity(256);
+    let mut output = Vec::with_capacity(16 * 1024);
+
+    let serializer = ReflectSerializer::new(&value, &registry).with_scratch(&scratch);
+    let allocations = count_allocations(|| serialize_into(&mut output, &serializer));
+    assert_eq!(0, allocations);
+
+    // Without a scratch, only the non-contiguous byte list needs a temporary buffer
+    let serializer = ReflectSerializer::new(&value, &registry);
+    let allocations = count_allocations(|| serialize_into(&mut output, &serializer));
+    assert_eq!(1, allocations);
+}
+
+#[test]
+fn should_reuse_scratch_across_calls() {
+    let registry = registry();
+    let scratch = SerializerScratch::default();
+    let mut output = Vec::with_capacity(1024);
+
+    let payloads = (0..8_u8)
+        .map(|len| (0..len * 16).collect::<VecDeque<u8>>())
+        .collect::<Vec<_>>();
+
+    // The first call sizes the scratch buffer for the largest payload
+    let largest = payloads.last().unwrap();
+    serialize_into(
+        &mut output,
+        &TypedReflectSerializer::new(largest, &registry).with_scratch(&scratch),
+    );
+
+    for payload in &payloads {
+        let serializer = TypedReflectSerializer::new(payload, &registry).with_scratch(&scratch);
+        let allocations = count_allocations(|| serialize_into(&mut output, &serializer));
+        assert_eq!(0, allocations);
+    }
+}
+
+#[test]
+fn should_produce_same_output_with_scratch() {
+    let registry = registry();
+    let value = scene();
+    let scratch = SerializerScratch::default();
+
+    let without = ron::to_string(&ReflectSerializer::new(&value, &registry)).unwrap();
+    let with =
+        ron::to_string(&ReflectSerializer::new(&value, &registry).with_scratch(&scratch)).unwrap();
+    assert_eq!(without, with);
+
+    let mut deserializer = ron::de::Deserializer::from_str(&with).unwrap();
+    let output = ReflectDeserializer::new(&registry)
+        .deserialize(&mut deserializer)
+        .unwrap();
+    assert_eq!(Some(value), Scene::from_reflect(output.as_ref()));
+}